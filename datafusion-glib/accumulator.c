@@ -0,0 +1,175 @@
+/*
+ * Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#include <datafusion-glib/accumulator.h>
+#include <datafusion-glib/error.h>
+
+G_BEGIN_DECLS
+
+/**
+ * SECTION: accumulator
+ * @include: datafusion-glib/datafusion-glib.h
+ *
+ * #GDFAccumulator is an abstract class to implement an aggregate
+ * user-defined function. You can use this by
+ * gdf_session_context_register_aggregate_udf().
+ *
+ * A new accumulator is created for each group. Its methods are
+ * called in a thread of the #GDFTokioRuntime that executes the
+ * query.
+ */
+
+G_DEFINE_ABSTRACT_TYPE(GDFAccumulator, gdf_accumulator, G_TYPE_OBJECT)
+
+static void
+gdf_accumulator_init(GDFAccumulator *object)
+{
+}
+
+static void
+gdf_accumulator_class_init(GDFAccumulatorClass *klass)
+{
+}
+
+/**
+ * gdf_accumulator_update_batch:
+ * @accumulator: A #GDFAccumulator.
+ * @values: A #GArrowRecordBatch that has arguments as its columns.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: %TRUE on success, %FALSE otherwise.
+ *
+ * Since: 21.0.0
+ */
+gboolean
+gdf_accumulator_update_batch(GDFAccumulator *accumulator,
+                             GArrowRecordBatch *values,
+                             GError **error)
+{
+  GDFAccumulatorClass *klass = GDF_ACCUMULATOR_GET_CLASS(accumulator);
+  if (!klass->update_batch) {
+    g_set_error(error,
+                GDF_ERROR,
+                GDF_ERROR_NOT_IMPLEMENTED,
+                "[accumulator][update-batch] not implemented: %s",
+                G_OBJECT_TYPE_NAME(accumulator));
+    return FALSE;
+  }
+  return klass->update_batch(accumulator, values, error);
+}
+
+/**
+ * gdf_accumulator_merge_batch:
+ * @accumulator: A #GDFAccumulator.
+ * @states: A #GArrowRecordBatch that has states computed by other
+ *   accumulators as its columns.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: %TRUE on success, %FALSE otherwise.
+ *
+ * Since: 21.0.0
+ */
+gboolean
+gdf_accumulator_merge_batch(GDFAccumulator *accumulator,
+                            GArrowRecordBatch *states,
+                            GError **error)
+{
+  GDFAccumulatorClass *klass = GDF_ACCUMULATOR_GET_CLASS(accumulator);
+  if (!klass->merge_batch) {
+    g_set_error(error,
+                GDF_ERROR,
+                GDF_ERROR_NOT_IMPLEMENTED,
+                "[accumulator][merge-batch] not implemented: %s",
+                G_OBJECT_TYPE_NAME(accumulator));
+    return FALSE;
+  }
+  return klass->merge_batch(accumulator, states, error);
+}
+
+/**
+ * gdf_accumulator_get_state:
+ * @accumulator: A #GDFAccumulator.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (transfer full) (nullable): The current state as a
+ *   #GArrowRecordBatch that has one row. Its columns must have the
+ *   state types passed to gdf_session_context_register_aggregate_udf().
+ *   %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GArrowRecordBatch *
+gdf_accumulator_get_state(GDFAccumulator *accumulator,
+                          GError **error)
+{
+  GDFAccumulatorClass *klass = GDF_ACCUMULATOR_GET_CLASS(accumulator);
+  if (!klass->get_state) {
+    g_set_error(error,
+                GDF_ERROR,
+                GDF_ERROR_NOT_IMPLEMENTED,
+                "[accumulator][get-state] not implemented: %s",
+                G_OBJECT_TYPE_NAME(accumulator));
+    return NULL;
+  }
+  return klass->get_state(accumulator, error);
+}
+
+/**
+ * gdf_accumulator_evaluate:
+ * @accumulator: A #GDFAccumulator.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (transfer full) (nullable): The aggregated value as a
+ *   #GArrowArray that has one row and the return type of the UDF.
+ *   %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GArrowArray *
+gdf_accumulator_evaluate(GDFAccumulator *accumulator,
+                         GError **error)
+{
+  GDFAccumulatorClass *klass = GDF_ACCUMULATOR_GET_CLASS(accumulator);
+  if (!klass->evaluate) {
+    g_set_error(error,
+                GDF_ERROR,
+                GDF_ERROR_NOT_IMPLEMENTED,
+                "[accumulator][evaluate] not implemented: %s",
+                G_OBJECT_TYPE_NAME(accumulator));
+    return NULL;
+  }
+  return klass->evaluate(accumulator, error);
+}
+
+/**
+ * gdf_accumulator_get_size:
+ * @accumulator: A #GDFAccumulator.
+ *
+ * Returns: The number of bytes allocated by @accumulator. This is
+ *   used for memory accounting. If the class doesn't implement this,
+ *   this returns 0.
+ *
+ * Since: 21.0.0
+ */
+gsize
+gdf_accumulator_get_size(GDFAccumulator *accumulator)
+{
+  GDFAccumulatorClass *klass = GDF_ACCUMULATOR_GET_CLASS(accumulator);
+  if (!klass->get_size) {
+    return 0;
+  }
+  return klass->get_size(accumulator);
+}
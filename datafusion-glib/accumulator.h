@@ -0,0 +1,84 @@
+/*
+ * Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#pragma once
+
+#include <arrow-glib/arrow-glib.h>
+
+#include <datafusion-glib/version.h>
+
+G_BEGIN_DECLS
+
+#define GDF_TYPE_ACCUMULATOR (gdf_accumulator_get_type())
+G_DECLARE_DERIVABLE_TYPE(GDFAccumulator,
+                         gdf_accumulator,
+                         GDF,
+                         ACCUMULATOR,
+                         GObject)
+/**
+ * GDFAccumulatorClass:
+ * @update_batch: Updates the accumulator with a batch of arguments.
+ * @merge_batch: Merges a batch of states computed by other
+ *   accumulators.
+ * @get_state: Returns the current state as a record batch that has
+ *   one row.
+ * @evaluate: Returns the aggregated value as an array that has one
+ *   row.
+ * @get_size: Returns the number of bytes allocated by the
+ *   accumulator. This is optional.
+ *
+ * Since: 21.0.0
+ */
+struct _GDFAccumulatorClass
+{
+  GObjectClass parent_class;
+
+  gboolean (*update_batch)(GDFAccumulator *accumulator,
+                           GArrowRecordBatch *values,
+                           GError **error);
+  gboolean (*merge_batch)(GDFAccumulator *accumulator,
+                          GArrowRecordBatch *states,
+                          GError **error);
+  GArrowRecordBatch *(*get_state)(GDFAccumulator *accumulator,
+                                  GError **error);
+  GArrowArray *(*evaluate)(GDFAccumulator *accumulator,
+                           GError **error);
+  gsize (*get_size)(GDFAccumulator *accumulator);
+};
+
+GDF_AVAILABLE_IN_21_0
+gboolean
+gdf_accumulator_update_batch(GDFAccumulator *accumulator,
+                             GArrowRecordBatch *values,
+                             GError **error);
+GDF_AVAILABLE_IN_21_0
+gboolean
+gdf_accumulator_merge_batch(GDFAccumulator *accumulator,
+                            GArrowRecordBatch *states,
+                            GError **error);
+GDF_AVAILABLE_IN_21_0
+GArrowRecordBatch *
+gdf_accumulator_get_state(GDFAccumulator *accumulator,
+                          GError **error);
+GDF_AVAILABLE_IN_21_0
+GArrowArray *
+gdf_accumulator_evaluate(GDFAccumulator *accumulator,
+                         GError **error);
+GDF_AVAILABLE_IN_21_0
+gsize
+gdf_accumulator_get_size(GDFAccumulator *accumulator);
+
+G_END_DECLS
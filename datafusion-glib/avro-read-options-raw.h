@@ -0,0 +1,29 @@
+/*
+ * Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#pragma once
+
+#include <datafusion-glib/avro-read-options.h>
+
+#include <datafusion.h>
+
+G_BEGIN_DECLS
+
+GDF_AVAILABLE_IN_21_0
+DFAvroReadOptions *
+gdf_avro_read_options_get_raw(GDFAvroReadOptions *options);
+
+G_END_DECLS
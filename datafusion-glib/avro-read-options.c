@@ -0,0 +1,305 @@
+/*
+ * Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#include <datafusion-glib/avro-read-options-raw.h>
+#include <datafusion-glib/error.h>
+
+G_BEGIN_DECLS
+
+/**
+ * SECTION: avro-read-options
+ * @include: datafusion-glib/datafusion-glib.h
+ *
+ * #GDFAvroReadOptions is a class for options on reading
+ * Apache Avro files.
+ */
+
+typedef struct GDFAvroReadOptionsPrivate_ {
+  DFAvroReadOptions *options;
+  GArrowSchema *schema;
+  GArrowSchema *table_partition_columns;
+  gchar *file_extension;
+} GDFAvroReadOptionsPrivate;
+
+G_DEFINE_TYPE_WITH_PRIVATE(GDFAvroReadOptions,
+                           gdf_avro_read_options,
+                           G_TYPE_OBJECT)
+
+static void
+gdf_avro_read_options_finalize(GObject *object)
+{
+  GDFAvroReadOptionsPrivate *priv =
+    gdf_avro_read_options_get_instance_private(GDF_AVRO_READ_OPTIONS(object));
+  df_avro_read_options_free(priv->options);
+  g_free(priv->file_extension);
+  G_OBJECT_CLASS(gdf_avro_read_options_parent_class)->finalize(object);
+}
+
+static void
+gdf_avro_read_options_dispose(GObject *object)
+{
+  GDFAvroReadOptionsPrivate *priv =
+    gdf_avro_read_options_get_instance_private(GDF_AVRO_READ_OPTIONS(object));
+  if (priv->schema) {
+    g_object_unref(priv->schema);
+    priv->schema = NULL;
+  }
+  if (priv->table_partition_columns) {
+    g_object_unref(priv->table_partition_columns);
+    priv->table_partition_columns = NULL;
+  }
+  G_OBJECT_CLASS(gdf_avro_read_options_parent_class)->dispose(object);
+}
+
+static void
+gdf_avro_read_options_init(GDFAvroReadOptions *object)
+{
+  GDFAvroReadOptionsPrivate *priv =
+    gdf_avro_read_options_get_instance_private(GDF_AVRO_READ_OPTIONS(object));
+  priv->options = df_avro_read_options_new();
+  priv->schema = NULL;
+  char *extension = df_avro_read_options_get_file_extension(priv->options);
+  priv->file_extension = g_strdup(extension);
+  free(extension);
+}
+
+static void
+gdf_avro_read_options_class_init(GDFAvroReadOptionsClass *klass)
+{
+  GObjectClass *gobject_class = G_OBJECT_CLASS(klass);
+  gobject_class->finalize = gdf_avro_read_options_finalize;
+  gobject_class->dispose = gdf_avro_read_options_dispose;
+}
+
+/**
+ * gdf_avro_read_options_new:
+ *
+ * Returns: A new Apache Avro read options.
+ *
+ * Since: 21.0.0
+ */
+GDFAvroReadOptions *
+gdf_avro_read_options_new(void)
+{
+  return g_object_new(GDF_TYPE_AVRO_READ_OPTIONS, NULL);
+}
+
+/**
+ * gdf_avro_read_options_set_schema:
+ * @options: A #GDFAvroReadOptions.
+ * @schema: (nullable): A #GArrowSchema for read Apache Avro.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: %TRUE on success, %FALSE otherwise.
+ *
+ * Since: 21.0.0
+ */
+gboolean
+gdf_avro_read_options_set_schema(GDFAvroReadOptions *options,
+                                 GArrowSchema *schema,
+                                 GError **error)
+{
+  GDFAvroReadOptionsPrivate *priv =
+    gdf_avro_read_options_get_instance_private(options);
+  if (priv->schema == schema) {
+    return TRUE;
+  }
+  gpointer c_abi_schema = NULL;
+  if (schema) {
+    c_abi_schema = garrow_schema_export(schema, error);
+    if (!c_abi_schema) {
+      return FALSE;
+    }
+  }
+  DFError *df_error = NULL;
+  bool success =
+    df_avro_read_options_set_schema(priv->options, c_abi_schema, &df_error);
+  if (success) {
+    if (priv->schema) {
+      g_object_unref(priv->schema);
+    }
+    if (schema) {
+      priv->schema = g_object_ref(schema);
+    } else {
+      priv->schema = NULL;
+    }
+  } else {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[avro-read-options][set-schema] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+  }
+  return success;
+}
+
+/**
+ * gdf_avro_read_options_get_schema:
+ * @options: A #GDFAvroReadOptions.
+ *
+ * Returns: (transfer none) (nullable): A #GArrowSchema to be used on
+ *   reading Apache Avro.
+ *
+ * Since: 21.0.0
+ */
+GArrowSchema *
+gdf_avro_read_options_get_schema(GDFAvroReadOptions *options)
+{
+  GDFAvroReadOptionsPrivate *priv =
+    gdf_avro_read_options_get_instance_private(options);
+  return priv->schema;
+}
+
+/**
+ * gdf_avro_read_options_set_file_extension:
+ * @options: A #GDFAvroReadOptions.
+ * @extension: An extension for Apache Avro file.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: %TRUE on success, %FALSE otherwise.
+ *
+ * Since: 21.0.0
+ */
+gboolean
+gdf_avro_read_options_set_file_extension(GDFAvroReadOptions *options,
+                                         const gchar *extension,
+                                         GError **error)
+{
+  GDFAvroReadOptionsPrivate *priv =
+    gdf_avro_read_options_get_instance_private(options);
+  if (!extension) {
+    g_set_error(error,
+                GDF_ERROR,
+                GDF_ERROR_ARROW,
+                "[avro-read-options][set-file-extension] must not NULL");
+    return FALSE;
+  }
+  if (strcmp(priv->file_extension, extension) == 0) {
+    return TRUE;
+  }
+  DFError *df_error = NULL;
+  bool success =
+    df_avro_read_options_set_file_extension(priv->options,
+                                            extension,
+                                            &df_error);
+  if (success) {
+    g_free(priv->file_extension);
+    priv->file_extension = g_strdup(extension);
+  } else {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[avro-read-options][set-file-extension] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+  }
+  return success;
+}
+
+/**
+ * gdf_avro_read_options_get_file_extension:
+ * @options: A #GDFAvroReadOptions.
+ *
+ * Returns: An extension for Apache Avro to be read.
+ *
+ * Since: 21.0.0
+ */
+const gchar *
+gdf_avro_read_options_get_file_extension(GDFAvroReadOptions *options)
+{
+  GDFAvroReadOptionsPrivate *priv =
+    gdf_avro_read_options_get_instance_private(options);
+  return priv->file_extension;
+}
+
+/**
+ * gdf_avro_read_options_set_table_partition_columns:
+ * @options: A #GDFAvroReadOptions.
+ * @schema: Column names and their types that are used for table
+ *   partition.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: %TRUE on success, %FALSE otherwise.
+ *
+ * Since: 21.0.0
+ */
+gboolean
+gdf_avro_read_options_set_table_partition_columns(GDFAvroReadOptions *options,
+                                                  GArrowSchema *schema,
+                                                  GError **error)
+{
+  GDFAvroReadOptionsPrivate *priv =
+    gdf_avro_read_options_get_instance_private(options);
+  if (priv->table_partition_columns == schema) {
+    return TRUE;
+  }
+  gpointer c_abi_schema = NULL;
+  if (schema) {
+    c_abi_schema = garrow_schema_export(schema, error);
+    if (!c_abi_schema) {
+      return FALSE;
+    }
+  }
+  DFError *df_error = NULL;
+  bool success =
+    df_avro_read_options_set_table_partition_columns(priv->options,
+                                                     c_abi_schema,
+                                                     &df_error);
+  if (success) {
+    if (priv->table_partition_columns) {
+      g_object_unref(priv->table_partition_columns);
+    }
+    if (schema) {
+      priv->table_partition_columns = g_object_ref(schema);
+    } else {
+      priv->table_partition_columns = NULL;
+    }
+  } else {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[avro-read-options][set-table-partition-columns] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+  }
+  return success;
+}
+
+/**
+ * gdf_avro_read_options_get_table_partition_columns:
+ * @options: A #GDFAvroReadOptions.
+ *
+ * Returns: (transfer none) (nullable): The column names and their
+ *   types that are used for table partition.
+ *
+ * Since: 21.0.0
+ */
+GArrowSchema *
+gdf_avro_read_options_get_table_partition_columns(GDFAvroReadOptions *options)
+{
+  GDFAvroReadOptionsPrivate *priv =
+    gdf_avro_read_options_get_instance_private(options);
+  return priv->table_partition_columns;
+}
+
+DFAvroReadOptions *
+gdf_avro_read_options_get_raw(GDFAvroReadOptions *options)
+{
+  GDFAvroReadOptionsPrivate *priv =
+    gdf_avro_read_options_get_instance_private(options);
+  return priv->options;
+}
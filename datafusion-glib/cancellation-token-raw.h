@@ -0,0 +1,51 @@
+/*
+ * Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#pragma once
+
+#include <datafusion-glib/cancellation-token.h>
+
+#include <gio/gio.h>
+
+#include <datafusion.h>
+
+G_BEGIN_DECLS
+
+GDF_AVAILABLE_IN_21_0
+GDFCancellationToken *
+gdf_cancellation_token_new_raw(DFCancellationToken *raw_token);
+GDF_AVAILABLE_IN_21_0
+DFCancellationToken *
+gdf_cancellation_token_get_raw(GDFCancellationToken *token);
+
+/* A DFCancellationToken that is cancelled by a GCancellable. */
+typedef struct GDFCancellableLink_ {
+  GCancellable *cancellable;
+  gulong handler_id;
+  DFCancellationToken *token;
+} GDFCancellableLink;
+
+GDF_AVAILABLE_IN_21_0
+GDFCancellableLink *
+gdf_cancellable_link_new(GCancellable *cancellable);
+GDF_AVAILABLE_IN_21_0
+void
+gdf_cancellable_link_disconnect(GDFCancellableLink *link);
+GDF_AVAILABLE_IN_21_0
+void
+gdf_cancellable_link_free(GDFCancellableLink *link);
+
+G_END_DECLS
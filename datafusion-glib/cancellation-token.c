@@ -0,0 +1,201 @@
+/*
+ * Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#include <datafusion-glib/cancellation-token-raw.h>
+
+G_BEGIN_DECLS
+
+/**
+ * SECTION: cancellation-token
+ * @include: datafusion-glib/datafusion-glib.h
+ *
+ * #GDFCancellationToken is a class to cancel running queries from
+ * another thread. You can use this by
+ * gdf_session_context_set_cancellation_token() or
+ * gdf_data_frame_set_cancellation_token().
+ *
+ * Cancelled queries report %GDF_ERROR_CANCELLED.
+ */
+
+typedef struct GDFCancellationTokenPrivate_ {
+  DFCancellationToken *token;
+} GDFCancellationTokenPrivate;
+
+enum {
+  PROP_TOKEN = 1,
+};
+
+G_DEFINE_TYPE_WITH_PRIVATE(GDFCancellationToken,
+                           gdf_cancellation_token,
+                           G_TYPE_OBJECT)
+
+static void
+gdf_cancellation_token_finalize(GObject *object)
+{
+  GDFCancellationTokenPrivate *priv =
+    gdf_cancellation_token_get_instance_private(
+      GDF_CANCELLATION_TOKEN(object));
+  df_cancellation_token_free(priv->token);
+  G_OBJECT_CLASS(gdf_cancellation_token_parent_class)->finalize(object);
+}
+
+static void
+gdf_cancellation_token_set_property(GObject *object,
+                                    guint prop_id,
+                                    const GValue *value,
+                                    GParamSpec *pspec)
+{
+  GDFCancellationTokenPrivate *priv =
+    gdf_cancellation_token_get_instance_private(
+      GDF_CANCELLATION_TOKEN(object));
+
+  switch (prop_id) {
+  case PROP_TOKEN:
+    priv->token = g_value_get_pointer(value);
+    break;
+  default:
+    G_OBJECT_WARN_INVALID_PROPERTY_ID(object, prop_id, pspec);
+    break;
+  }
+}
+
+static void
+gdf_cancellation_token_init(GDFCancellationToken *object)
+{
+}
+
+static void
+gdf_cancellation_token_class_init(GDFCancellationTokenClass *klass)
+{
+  GObjectClass *gobject_class = G_OBJECT_CLASS(klass);
+  gobject_class->finalize = gdf_cancellation_token_finalize;
+  gobject_class->set_property = gdf_cancellation_token_set_property;
+
+  GParamSpec *spec;
+  spec = g_param_spec_pointer("token",
+                              "Token",
+                              "The raw DFCancellationToken *",
+                              G_PARAM_WRITABLE | G_PARAM_CONSTRUCT_ONLY);
+  g_object_class_install_property(gobject_class, PROP_TOKEN, spec);
+}
+
+/**
+ * gdf_cancellation_token_new:
+ *
+ * Returns: A newly created #GDFCancellationToken.
+ *
+ * Since: 21.0.0
+ */
+GDFCancellationToken *
+gdf_cancellation_token_new(void)
+{
+  return gdf_cancellation_token_new_raw(df_cancellation_token_new());
+}
+
+/**
+ * gdf_cancellation_token_cancel:
+ * @token: A #GDFCancellationToken.
+ *
+ * Cancels queries that use @token. This can be called from any
+ * thread. Queries that use @token after this call are also
+ * cancelled.
+ *
+ * Since: 21.0.0
+ */
+void
+gdf_cancellation_token_cancel(GDFCancellationToken *token)
+{
+  GDFCancellationTokenPrivate *priv =
+    gdf_cancellation_token_get_instance_private(token);
+  df_cancellation_token_cancel(priv->token);
+}
+
+/**
+ * gdf_cancellation_token_is_cancelled:
+ * @token: A #GDFCancellationToken.
+ *
+ * Returns: %TRUE if @token is cancelled, %FALSE otherwise.
+ *
+ * Since: 21.0.0
+ */
+gboolean
+gdf_cancellation_token_is_cancelled(GDFCancellationToken *token)
+{
+  GDFCancellationTokenPrivate *priv =
+    gdf_cancellation_token_get_instance_private(token);
+  return df_cancellation_token_is_cancelled(priv->token);
+}
+
+GDFCancellationToken *
+gdf_cancellation_token_new_raw(DFCancellationToken *token)
+{
+  return g_object_new(GDF_TYPE_CANCELLATION_TOKEN,
+                      "token", token,
+                      NULL);
+}
+
+DFCancellationToken *
+gdf_cancellation_token_get_raw(GDFCancellationToken *token)
+{
+  GDFCancellationTokenPrivate *priv =
+    gdf_cancellation_token_get_instance_private(token);
+  return priv->token;
+}
+
+static void
+gdf_cancellable_link_cancelled(GCancellable *cancellable, gpointer user_data)
+{
+  DFCancellationToken *token = user_data;
+  df_cancellation_token_cancel(token);
+}
+
+GDFCancellableLink *
+gdf_cancellable_link_new(GCancellable *cancellable)
+{
+  GDFCancellableLink *link = g_new0(GDFCancellableLink, 1);
+  if (cancellable) {
+    link->cancellable = g_object_ref(cancellable);
+    link->token = df_cancellation_token_new();
+    /* This calls the handler immediately if cancellable is already
+     * cancelled. */
+    link->handler_id =
+      g_cancellable_connect(cancellable,
+                            G_CALLBACK(gdf_cancellable_link_cancelled),
+                            link->token,
+                            NULL);
+  }
+  return link;
+}
+
+void
+gdf_cancellable_link_disconnect(GDFCancellableLink *link)
+{
+  if (link->handler_id > 0) {
+    g_cancellable_disconnect(link->cancellable, link->handler_id);
+    link->handler_id = 0;
+  }
+}
+
+void
+gdf_cancellable_link_free(GDFCancellableLink *link)
+{
+  gdf_cancellable_link_disconnect(link);
+  g_clear_object(&link->cancellable);
+  if (link->token) {
+    df_cancellation_token_free(link->token);
+  }
+  g_free(link);
+}
@@ -0,0 +1,46 @@
+/*
+ * Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#pragma once
+
+#include <glib-object.h>
+
+#include <datafusion-glib/version.h>
+
+G_BEGIN_DECLS
+
+#define GDF_TYPE_CANCELLATION_TOKEN (gdf_cancellation_token_get_type())
+G_DECLARE_DERIVABLE_TYPE(GDFCancellationToken,
+                         gdf_cancellation_token,
+                         GDF,
+                         CANCELLATION_TOKEN,
+                         GObject)
+struct _GDFCancellationTokenClass
+{
+  GObjectClass parent_class;
+};
+
+GDF_AVAILABLE_IN_21_0
+GDFCancellationToken *
+gdf_cancellation_token_new(void);
+GDF_AVAILABLE_IN_21_0
+void
+gdf_cancellation_token_cancel(GDFCancellationToken *token);
+GDF_AVAILABLE_IN_21_0
+gboolean
+gdf_cancellation_token_is_cancelled(GDFCancellationToken *token);
+
+G_END_DECLS
@@ -24,6 +24,10 @@ G_BEGIN_DECLS
  * @include: datafusion-glib/datafusion-glib.h
  *
  * #GDFCSVReadOptions is a class for options on reading CSV.
+ *
+ * DataFusion 21 doesn't provide options for the quote character, the
+ * escape character and newlines in values. Values can be quoted only
+ * by `"`. A `"` in a quoted value must be written as `""`.
  */
 
 typedef struct GDFCSVReadOptionsPrivate_ {
@@ -408,6 +412,46 @@ gdf_csv_read_options_get_table_partition_columns(GDFCSVReadOptions *options)
   return priv->table_partition_columns;
 }
 
+/**
+ * gdf_csv_read_options_set_file_compression_type:
+ * @options: A #GDFCSVReadOptions.
+ * @type: A #GDFFileCompressionType of CSV files.
+ *
+ * The file extension isn't changed automatically. You may need to
+ * change it too by gdf_csv_read_options_set_file_extension() such as
+ * `.csv.gz`.
+ *
+ * Schema inference may fail for compressed CSV files. You can avoid
+ * it by specifying the schema explicitly by
+ * gdf_csv_read_options_set_schema().
+ *
+ * Since: 21.0.0
+ */
+void
+gdf_csv_read_options_set_file_compression_type(GDFCSVReadOptions *options,
+                                               GDFFileCompressionType type)
+{
+  GDFCSVReadOptionsPrivate *priv =
+    gdf_csv_read_options_get_instance_private(options);
+  df_csv_read_options_set_file_compression_type(priv->options, type);
+}
+
+/**
+ * gdf_csv_read_options_get_file_compression_type:
+ * @options: A #GDFCSVReadOptions.
+ *
+ * Returns: A #GDFFileCompressionType of CSV files to be read.
+ *
+ * Since: 21.0.0
+ */
+GDFFileCompressionType
+gdf_csv_read_options_get_file_compression_type(GDFCSVReadOptions *options)
+{
+  GDFCSVReadOptionsPrivate *priv =
+    gdf_csv_read_options_get_instance_private(options);
+  return df_csv_read_options_get_file_compression_type(priv->options);
+}
+
 DFCSVReadOptions *
 gdf_csv_read_options_get_raw(GDFCSVReadOptions *options)
 {
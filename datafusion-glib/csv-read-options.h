@@ -18,6 +18,7 @@
 
 #include <arrow-glib/arrow-glib.h>
 
+#include <datafusion-glib/file-compression-type.h>
 #include <datafusion-glib/version.h>
 
 G_BEGIN_DECLS
@@ -64,6 +65,14 @@ GDF_AVAILABLE_IN_10_0
 GArrowSchema *
 gdf_csv_read_options_get_table_partition_columns(GDFCSVReadOptions *options);
 
+GDF_AVAILABLE_IN_21_0
+void
+gdf_csv_read_options_set_file_compression_type(GDFCSVReadOptions *options,
+                                               GDFFileCompressionType type);
+GDF_AVAILABLE_IN_21_0
+GDFFileCompressionType
+gdf_csv_read_options_get_file_compression_type(GDFCSVReadOptions *options);
+
 
 
 G_END_DECLS
@@ -14,8 +14,11 @@
  * limitations under the License.
  */
 
+#include <datafusion-glib/cancellation-token-raw.h>
 #include <datafusion-glib/data-frame-raw.h>
 #include <datafusion-glib/error.h>
+#include <datafusion-glib/expr-raw.h>
+#include <datafusion-glib/physical-plan-raw.h>
 
 G_BEGIN_DECLS
 
@@ -160,6 +163,50 @@ gdf_data_frame_class_init(GDFDataFrameClass *klass)
   g_object_class_install_property(gobject_class, PROP_DATA_FRAME, spec);
 }
 
+/**
+ * gdf_data_frame_set_cancellation_token:
+ * @data_frame: A #GDFDataFrame.
+ * @token: (nullable): A #GDFCancellationToken or %NULL.
+ *
+ * Sets a token to cancel execution of @data_frame. Data frames
+ * created from @data_frame use @token too.
+ *
+ * If @token is %NULL, execution of @data_frame can't be cancelled.
+ *
+ * Since: 21.0.0
+ */
+void
+gdf_data_frame_set_cancellation_token(GDFDataFrame *data_frame,
+                                      GDFCancellationToken *token)
+{
+  GDFDataFramePrivate *priv = gdf_data_frame_get_instance_private(data_frame);
+  DFCancellationToken *raw_token = NULL;
+  if (token) {
+    raw_token = gdf_cancellation_token_get_raw(token);
+  }
+  df_data_frame_set_cancellation_token(priv->data_frame, raw_token);
+}
+
+/**
+ * gdf_data_frame_set_timeout:
+ * @data_frame: A #GDFDataFrame.
+ * @timeout_milliseconds: The timeout in milliseconds. `0` means no
+ *   timeout.
+ *
+ * Sets a timeout for each execution of @data_frame. Timed out
+ * executions report %GDF_ERROR_TIMEOUT. Data frames created from
+ * @data_frame use the timeout too.
+ *
+ * Since: 21.0.0
+ */
+void
+gdf_data_frame_set_timeout(GDFDataFrame *data_frame,
+                           guint64 timeout_milliseconds)
+{
+  GDFDataFramePrivate *priv = gdf_data_frame_get_instance_private(data_frame);
+  df_data_frame_set_timeout(priv->data_frame, timeout_milliseconds);
+}
+
 /**
  * gdf_data_frame_show:
  * @data_frame: A #GDFDataFrame.
@@ -188,6 +235,30 @@ gdf_data_frame_show(GDFDataFrame *data_frame, GError **error)
   }
 }
 
+static GArrowTable *
+gdf_data_frame_import_table(DFArrowSchema *c_abi_schema,
+                            DFArrowArray **c_abi_record_batches,
+                            gint64 n,
+                            GError **error)
+{
+  GArrowSchema *schema = garrow_schema_import(c_abi_schema, error);
+  GArrowRecordBatch **record_batches = g_newa(GArrowRecordBatch *, n);
+  gint64 i;
+  for (i = 0; i < n; i++) {
+    record_batches[i] = garrow_record_batch_import(c_abi_record_batches[i],
+                                                   schema,
+                                                   error);
+  }
+  free(c_abi_record_batches);
+  GArrowTable *table =
+    garrow_table_new_record_batches(schema, record_batches, n, error);
+  for (i = 0; i < n; i++) {
+    g_object_unref(record_batches[i]);
+  }
+  g_object_unref(schema);
+  return table;
+}
+
 /**
  * gdf_data_frame_to_table:
  * @data_frame: A #GDFDataFrame.
@@ -201,8 +272,8 @@ GArrowTable *
 gdf_data_frame_to_table(GDFDataFrame *data_frame, GError **error)
 {
   GDFDataFramePrivate *priv = gdf_data_frame_get_instance_private(data_frame);
-  DFArrowSchema *c_abi_schema;
-  DFArrowArray **c_abi_record_batches;
+  DFArrowSchema *c_abi_schema = NULL;
+  DFArrowArray **c_abi_record_batches = NULL;
   DFError *df_error = NULL;
   gint64 n = df_data_frame_export(priv->data_frame,
                                   &c_abi_schema,
@@ -218,22 +289,110 @@ gdf_data_frame_to_table(GDFDataFrame *data_frame, GError **error)
     return NULL;
   }
 
-  GArrowSchema *schema = garrow_schema_import(c_abi_schema, error);
-  GArrowRecordBatch **record_batches = g_newa(GArrowRecordBatch *, n);
-  gint64 i;
-  for (i = 0; i < n; i++) {
-    record_batches[i] = garrow_record_batch_import(c_abi_record_batches[i],
-                                                   schema,
-                                                   error);
+  return gdf_data_frame_import_table(c_abi_schema,
+                                     c_abi_record_batches,
+                                     n,
+                                     error);
+}
+
+static void
+gdf_data_frame_to_table_async_callback(DFArrowSchema *c_abi_schema,
+                                       DFArrowArray **c_abi_record_batches,
+                                       gint64 n,
+                                       DFError *df_error,
+                                       gpointer user_data)
+{
+  GTask *task = user_data;
+  gdf_cancellable_link_disconnect(g_task_get_task_data(task));
+  if (df_error) {
+    g_task_return_new_error(task,
+                            GDF_ERROR,
+                            df_error_get_code(df_error),
+                            "[data-frame][to-table-async] %s",
+                            df_error_get_message(df_error));
+    df_error_free(df_error);
+    return;
   }
-  free(c_abi_record_batches);
-  GArrowTable *table =
-    garrow_table_new_record_batches(schema, record_batches, n, error);
-  for (i = 0; i < n; i++) {
-    g_object_unref(record_batches[i]);
+
+  GError *error = NULL;
+  GArrowTable *table = gdf_data_frame_import_table(c_abi_schema,
+                                                   c_abi_record_batches,
+                                                   n,
+                                                   &error);
+  if (error) {
+    g_clear_object(&table);
+    g_task_return_error(task, error);
+  } else {
+    g_task_return_pointer(task, table, g_object_unref);
   }
-  g_object_unref(schema);
-  return table;
+}
+
+/**
+ * gdf_data_frame_to_table_async:
+ * @data_frame: A #GDFDataFrame.
+ * @cancellable: (nullable): A #GCancellable or %NULL.
+ * @callback: (scope async): A #GAsyncReadyCallback to call when
+ *   @data_frame is collected.
+ * @user_data: (closure): Data to pass to @callback.
+ *
+ * Collects @data_frame asynchronously without blocking the caller
+ * thread. Call gdf_data_frame_to_table_finish() in @callback to get
+ * the result.
+ *
+ * If @cancellable is cancelled, the running query is cancelled and
+ * gdf_data_frame_to_table_finish() reports a
+ * %GDF_ERROR_CANCELLED error.
+ *
+ * Since: 21.0.0
+ */
+void
+gdf_data_frame_to_table_async(GDFDataFrame *data_frame,
+                              GCancellable *cancellable,
+                              GAsyncReadyCallback callback,
+                              gpointer user_data)
+{
+  GDFDataFramePrivate *priv = gdf_data_frame_get_instance_private(data_frame);
+  GTask *task = g_task_new(data_frame, cancellable, callback, user_data);
+  /* Report GDF_ERROR_CANCELLED instead of G_IO_ERROR_CANCELLED. */
+  g_task_set_check_cancellable(task, FALSE);
+  GDFCancellableLink *link = gdf_cancellable_link_new(cancellable);
+  g_task_set_task_data(task, link, (GDestroyNotify)gdf_cancellable_link_free);
+  DFError *df_error = NULL;
+  if (!df_data_frame_collect_async(priv->data_frame,
+                                   link->token,
+                                   gdf_data_frame_to_table_async_callback,
+                                   g_object_ref(task),
+                                   g_object_unref,
+                                   &df_error)) {
+    g_task_return_new_error(task,
+                            GDF_ERROR,
+                            df_error_get_code(df_error),
+                            "[data-frame][to-table-async] %s",
+                            df_error_get_message(df_error));
+    df_error_free(df_error);
+  }
+  g_object_unref(task);
+}
+
+/**
+ * gdf_data_frame_to_table_finish:
+ * @data_frame: A #GDFDataFrame.
+ * @result: A #GAsyncResult passed to the callback of
+ *   gdf_data_frame_to_table_async().
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (transfer full) (nullable): A #GArrowTable of this data
+ *   frame, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GArrowTable *
+gdf_data_frame_to_table_finish(GDFDataFrame *data_frame,
+                               GAsyncResult *result,
+                               GError **error)
+{
+  g_return_val_if_fail(g_task_is_valid(result, data_frame), NULL);
+  return g_task_propagate_pointer(G_TASK(result), error);
 }
 
 /**
@@ -274,6 +433,847 @@ gdf_data_frame_write_parquet(GDFDataFrame *data_frame,
   return success;
 }
 
+/**
+ * gdf_data_frame_execute_stream:
+ * @data_frame: A #GDFDataFrame.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Executes @data_frame as a stream. Record batches are computed when
+ * they are read from the returned reader. So the whole result isn't
+ * loaded into memory.
+ *
+ * Returns: (transfer full) (nullable): A #GArrowRecordBatchReader to
+ *   read the result of this data frame, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GArrowRecordBatchReader *
+gdf_data_frame_execute_stream(GDFDataFrame *data_frame, GError **error)
+{
+  GDFDataFramePrivate *priv = gdf_data_frame_get_instance_private(data_frame);
+  DFArrowArrayStream c_abi_stream;
+  DFError *df_error = NULL;
+  if (!df_data_frame_execute_stream(priv->data_frame,
+                                    &c_abi_stream,
+                                    &df_error)) {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[data-frame][execute-stream] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+    return NULL;
+  }
+  return garrow_record_batch_reader_import(&c_abi_stream, error);
+}
+
+/**
+ * gdf_data_frame_get_schema:
+ * @data_frame: A #GDFDataFrame.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Gets the output schema of @data_frame. This doesn't execute
+ * @data_frame. Field nullability and metadata are also
+ * available. Note that DataFusion 21 doesn't keep metadata of
+ * top-level fields in projections such as `SELECT * FROM table`.
+ *
+ * Returns: (transfer full) (nullable): A #GArrowSchema of this data
+ *   frame, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GArrowSchema *
+gdf_data_frame_get_schema(GDFDataFrame *data_frame, GError **error)
+{
+  GDFDataFramePrivate *priv = gdf_data_frame_get_instance_private(data_frame);
+  DFError *df_error = NULL;
+  DFArrowSchema *c_abi_schema =
+    df_data_frame_get_schema(priv->data_frame, &df_error);
+  if (!c_abi_schema) {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[data-frame][get-schema] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+    return NULL;
+  }
+  GArrowSchema *schema = garrow_schema_import(c_abi_schema, error);
+  free(c_abi_schema);
+  return schema;
+}
+
+static GDFDataFrame *
+gdf_data_frame_new_result(DFDataFrame *data_frame,
+                          DFError *df_error,
+                          const gchar *tag,
+                          GError **error)
+{
+  if (!data_frame) {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[data-frame][%s] %s",
+                tag,
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+    return NULL;
+  }
+  return gdf_data_frame_new_raw(data_frame);
+}
+
+/**
+ * gdf_data_frame_select_columns:
+ * @data_frame: A #GDFDataFrame.
+ * @columns: (array length=n_columns): Column names to be selected.
+ * @n_columns: The number of @columns.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (transfer full) (nullable): A new #GDFDataFrame that has
+ *   only the selected columns, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFDataFrame *
+gdf_data_frame_select_columns(GDFDataFrame *data_frame,
+                              const gchar **columns,
+                              gsize n_columns,
+                              GError **error)
+{
+  GDFDataFramePrivate *priv = gdf_data_frame_get_instance_private(data_frame);
+  DFError *df_error = NULL;
+  DFDataFrame *selected = df_data_frame_select_columns(priv->data_frame,
+                                                       columns,
+                                                       n_columns,
+                                                       &df_error);
+  return gdf_data_frame_new_result(selected,
+                                   df_error,
+                                   "select-columns",
+                                   error);
+}
+
+/**
+ * gdf_data_frame_select:
+ * @data_frame: A #GDFDataFrame.
+ * @sql_exprs: (array length=n_sql_exprs): SQL expressions such as
+ *   `a + 1 AS b` to be selected.
+ * @n_sql_exprs: The number of @sql_exprs.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (transfer full) (nullable): A new #GDFDataFrame that has
+ *   the given expressions as columns, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFDataFrame *
+gdf_data_frame_select(GDFDataFrame *data_frame,
+                      const gchar **sql_exprs,
+                      gsize n_sql_exprs,
+                      GError **error)
+{
+  GDFDataFramePrivate *priv = gdf_data_frame_get_instance_private(data_frame);
+  DFError *df_error = NULL;
+  DFDataFrame *selected = df_data_frame_select(priv->data_frame,
+                                               sql_exprs,
+                                               n_sql_exprs,
+                                               &df_error);
+  return gdf_data_frame_new_result(selected, df_error, "select", error);
+}
+
+/**
+ * gdf_data_frame_filter:
+ * @data_frame: A #GDFDataFrame.
+ * @sql_predicate: A SQL expression such as `a > 10` that returns a
+ *   boolean.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (transfer full) (nullable): A new #GDFDataFrame that has
+ *   only rows that satisfy @sql_predicate, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFDataFrame *
+gdf_data_frame_filter(GDFDataFrame *data_frame,
+                      const gchar *sql_predicate,
+                      GError **error)
+{
+  GDFDataFramePrivate *priv = gdf_data_frame_get_instance_private(data_frame);
+  DFError *df_error = NULL;
+  DFDataFrame *filtered = df_data_frame_filter(priv->data_frame,
+                                               sql_predicate,
+                                               &df_error);
+  return gdf_data_frame_new_result(filtered, df_error, "filter", error);
+}
+
+/**
+ * gdf_data_frame_sort:
+ * @data_frame: A #GDFDataFrame.
+ * @sql_sort_exprs: (array length=n_sql_sort_exprs): SQL sort keys
+ *   such as `a DESC NULLS LAST`.
+ * @n_sql_sort_exprs: The number of @sql_sort_exprs.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (transfer full) (nullable): A new sorted #GDFDataFrame,
+ *   %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFDataFrame *
+gdf_data_frame_sort(GDFDataFrame *data_frame,
+                    const gchar **sql_sort_exprs,
+                    gsize n_sql_sort_exprs,
+                    GError **error)
+{
+  GDFDataFramePrivate *priv = gdf_data_frame_get_instance_private(data_frame);
+  DFError *df_error = NULL;
+  DFDataFrame *sorted = df_data_frame_sort(priv->data_frame,
+                                           sql_sort_exprs,
+                                           n_sql_sort_exprs,
+                                           &df_error);
+  return gdf_data_frame_new_result(sorted, df_error, "sort", error);
+}
+
+/**
+ * gdf_data_frame_limit:
+ * @data_frame: A #GDFDataFrame.
+ * @offset: The number of rows to be skipped.
+ * @fetch: The max number of rows to be returned after @offset.
+ *   Negative value means no limit.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (transfer full) (nullable): A new #GDFDataFrame that has
+ *   only the limited rows, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFDataFrame *
+gdf_data_frame_limit(GDFDataFrame *data_frame,
+                     gsize offset,
+                     gint64 fetch,
+                     GError **error)
+{
+  GDFDataFramePrivate *priv = gdf_data_frame_get_instance_private(data_frame);
+  DFError *df_error = NULL;
+  DFDataFrame *limited = df_data_frame_limit(priv->data_frame,
+                                             offset,
+                                             fetch,
+                                             &df_error);
+  return gdf_data_frame_new_result(limited, df_error, "limit", error);
+}
+
+static const DFExpr **
+gdf_data_frame_exprs_get_raw(GDFExpr **exprs, gsize n_exprs)
+{
+  const DFExpr **raw_exprs = g_new(const DFExpr *, n_exprs);
+  gsize i;
+  for (i = 0; i < n_exprs; i++) {
+    raw_exprs[i] = gdf_expr_get_raw(exprs[i]);
+  }
+  return raw_exprs;
+}
+
+/**
+ * gdf_data_frame_select_exprs:
+ * @data_frame: A #GDFDataFrame.
+ * @exprs: (array length=n_exprs): #GDFExpr<!-- -->s to be selected.
+ * @n_exprs: The number of @exprs.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (transfer full) (nullable): A new #GDFDataFrame that has
+ *   the given expressions as columns, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFDataFrame *
+gdf_data_frame_select_exprs(GDFDataFrame *data_frame,
+                            GDFExpr **exprs,
+                            gsize n_exprs,
+                            GError **error)
+{
+  GDFDataFramePrivate *priv = gdf_data_frame_get_instance_private(data_frame);
+  const DFExpr **df_exprs = gdf_data_frame_exprs_get_raw(exprs, n_exprs);
+  DFError *df_error = NULL;
+  DFDataFrame *selected = df_data_frame_select_exprs(priv->data_frame,
+                                                     df_exprs,
+                                                     n_exprs,
+                                                     &df_error);
+  g_free(df_exprs);
+  return gdf_data_frame_new_result(selected,
+                                   df_error,
+                                   "select-exprs",
+                                   error);
+}
+
+/**
+ * gdf_data_frame_filter_expr:
+ * @data_frame: A #GDFDataFrame.
+ * @predicate: A #GDFExpr that returns a boolean.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (transfer full) (nullable): A new #GDFDataFrame that has
+ *   only rows that satisfy @predicate, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFDataFrame *
+gdf_data_frame_filter_expr(GDFDataFrame *data_frame,
+                           GDFExpr *predicate,
+                           GError **error)
+{
+  GDFDataFramePrivate *priv = gdf_data_frame_get_instance_private(data_frame);
+  DFError *df_error = NULL;
+  DFDataFrame *filtered =
+    df_data_frame_filter_expr(priv->data_frame,
+                              gdf_expr_get_raw(predicate),
+                              &df_error);
+  return gdf_data_frame_new_result(filtered, df_error, "filter-expr", error);
+}
+
+/**
+ * gdf_data_frame_sort_exprs:
+ * @data_frame: A #GDFDataFrame.
+ * @sort_exprs: (array length=n_sort_exprs): Sort keys created by
+ *   gdf_expr_new_sort().
+ * @n_sort_exprs: The number of @sort_exprs.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (transfer full) (nullable): A new sorted #GDFDataFrame,
+ *   %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFDataFrame *
+gdf_data_frame_sort_exprs(GDFDataFrame *data_frame,
+                          GDFExpr **sort_exprs,
+                          gsize n_sort_exprs,
+                          GError **error)
+{
+  GDFDataFramePrivate *priv = gdf_data_frame_get_instance_private(data_frame);
+  const DFExpr **df_sort_exprs =
+    gdf_data_frame_exprs_get_raw(sort_exprs, n_sort_exprs);
+  DFError *df_error = NULL;
+  DFDataFrame *sorted = df_data_frame_sort_exprs(priv->data_frame,
+                                                 df_sort_exprs,
+                                                 n_sort_exprs,
+                                                 &df_error);
+  g_free(df_sort_exprs);
+  return gdf_data_frame_new_result(sorted, df_error, "sort-exprs", error);
+}
+
+/**
+ * gdf_data_frame_aggregate:
+ * @data_frame: A #GDFDataFrame.
+ * @group_exprs: (array length=n_group_exprs): #GDFExpr s to be
+ *   grouped by. You can use gdf_expr_new_cube(),
+ *   gdf_expr_new_rollup() and gdf_expr_new_grouping_sets() for
+ *   `CUBE`, `ROLLUP` and `GROUPING SETS`.
+ * @n_group_exprs: The number of @group_exprs. If this is `0`, all
+ *   rows are aggregated as one group.
+ * @aggr_exprs: (array length=n_aggr_exprs): Aggregate function call
+ *   #GDFExpr s created by gdf_expr_new_function().
+ * @n_aggr_exprs: The number of @aggr_exprs.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (transfer full) (nullable): A new aggregated
+ *   #GDFDataFrame, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFDataFrame *
+gdf_data_frame_aggregate(GDFDataFrame *data_frame,
+                         GDFExpr **group_exprs,
+                         gsize n_group_exprs,
+                         GDFExpr **aggr_exprs,
+                         gsize n_aggr_exprs,
+                         GError **error)
+{
+  GDFDataFramePrivate *priv = gdf_data_frame_get_instance_private(data_frame);
+  const DFExpr **df_group_exprs =
+    gdf_data_frame_exprs_get_raw(group_exprs, n_group_exprs);
+  const DFExpr **df_aggr_exprs =
+    gdf_data_frame_exprs_get_raw(aggr_exprs, n_aggr_exprs);
+  DFError *df_error = NULL;
+  DFDataFrame *aggregated = df_data_frame_aggregate(priv->data_frame,
+                                                    df_group_exprs,
+                                                    n_group_exprs,
+                                                    df_aggr_exprs,
+                                                    n_aggr_exprs,
+                                                    &df_error);
+  g_free(df_group_exprs);
+  g_free(df_aggr_exprs);
+  return gdf_data_frame_new_result(aggregated, df_error, "aggregate", error);
+}
+
+/**
+ * gdf_data_frame_join:
+ * @left: A left #GDFDataFrame.
+ * @right: A right #GDFDataFrame.
+ * @join_type: A #GDFJoinType.
+ * @left_keys: (array length=n_keys): Key column names in @left.
+ * @right_keys: (array length=n_keys): Key column names in @right.
+ * @n_keys: The number of @left_keys and @right_keys.
+ * @filter: (nullable): An additional join condition.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (transfer full) (nullable): A new joined #GDFDataFrame,
+ *   %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFDataFrame *
+gdf_data_frame_join(GDFDataFrame *left,
+                    GDFDataFrame *right,
+                    GDFJoinType join_type,
+                    const gchar **left_keys,
+                    const gchar **right_keys,
+                    gsize n_keys,
+                    GDFExpr *filter,
+                    GError **error)
+{
+  GDFDataFramePrivate *left_priv = gdf_data_frame_get_instance_private(left);
+  GDFDataFramePrivate *right_priv = gdf_data_frame_get_instance_private(right);
+  DFError *df_error = NULL;
+  DFDataFrame *joined = df_data_frame_join(left_priv->data_frame,
+                                           right_priv->data_frame,
+                                           join_type,
+                                           left_keys,
+                                           right_keys,
+                                           n_keys,
+                                           filter ? gdf_expr_get_raw(filter) : NULL,
+                                           &df_error);
+  return gdf_data_frame_new_result(joined, df_error, "join", error);
+}
+
+/**
+ * gdf_data_frame_join_on:
+ * @left: A left #GDFDataFrame.
+ * @right: A right #GDFDataFrame.
+ * @join_type: A #GDFJoinType.
+ * @on_exprs: (array length=n_on_exprs): Join conditions. They are
+ *   combined by `AND`.
+ * @n_on_exprs: The number of @on_exprs.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (transfer full) (nullable): A new joined #GDFDataFrame,
+ *   %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFDataFrame *
+gdf_data_frame_join_on(GDFDataFrame *left,
+                       GDFDataFrame *right,
+                       GDFJoinType join_type,
+                       GDFExpr **on_exprs,
+                       gsize n_on_exprs,
+                       GError **error)
+{
+  GDFDataFramePrivate *left_priv = gdf_data_frame_get_instance_private(left);
+  GDFDataFramePrivate *right_priv = gdf_data_frame_get_instance_private(right);
+  const DFExpr **df_on_exprs =
+    gdf_data_frame_exprs_get_raw(on_exprs, n_on_exprs);
+  DFError *df_error = NULL;
+  DFDataFrame *joined = df_data_frame_join_on(left_priv->data_frame,
+                                              right_priv->data_frame,
+                                              join_type,
+                                              df_on_exprs,
+                                              n_on_exprs,
+                                              &df_error);
+  g_free(df_on_exprs);
+  return gdf_data_frame_new_result(joined, df_error, "join-on", error);
+}
+
+/**
+ * gdf_data_frame_union:
+ * @left: A left #GDFDataFrame.
+ * @right: A right #GDFDataFrame. It must have the same number of
+ *   columns as @left and compatible column types.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Concatenates rows of two data frames. Duplicated rows are
+ * kept. This is `UNION ALL` in SQL.
+ *
+ * Returns: (transfer full) (nullable): A new #GDFDataFrame, %NULL on
+ *   error.
+ *
+ * Since: 21.0.0
+ */
+GDFDataFrame *
+gdf_data_frame_union(GDFDataFrame *left,
+                     GDFDataFrame *right,
+                     GError **error)
+{
+  GDFDataFramePrivate *left_priv = gdf_data_frame_get_instance_private(left);
+  GDFDataFramePrivate *right_priv = gdf_data_frame_get_instance_private(right);
+  DFError *df_error = NULL;
+  DFDataFrame *unioned = df_data_frame_union(left_priv->data_frame,
+                                             right_priv->data_frame,
+                                             &df_error);
+  return gdf_data_frame_new_result(unioned, df_error, "union", error);
+}
+
+/**
+ * gdf_data_frame_union_distinct:
+ * @left: A left #GDFDataFrame.
+ * @right: A right #GDFDataFrame. It must have the same number of
+ *   columns as @left and compatible column types.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Concatenates rows of two data frames and removes duplicated
+ * rows. This is `UNION` in SQL.
+ *
+ * Returns: (transfer full) (nullable): A new #GDFDataFrame, %NULL on
+ *   error.
+ *
+ * Since: 21.0.0
+ */
+GDFDataFrame *
+gdf_data_frame_union_distinct(GDFDataFrame *left,
+                              GDFDataFrame *right,
+                              GError **error)
+{
+  GDFDataFramePrivate *left_priv = gdf_data_frame_get_instance_private(left);
+  GDFDataFramePrivate *right_priv = gdf_data_frame_get_instance_private(right);
+  DFError *df_error = NULL;
+  DFDataFrame *unioned = df_data_frame_union_distinct(left_priv->data_frame,
+                                                      right_priv->data_frame,
+                                                      &df_error);
+  return gdf_data_frame_new_result(unioned, df_error, "union-distinct", error);
+}
+
+/**
+ * gdf_data_frame_intersect:
+ * @left: A left #GDFDataFrame.
+ * @right: A right #GDFDataFrame. It must have the same number of
+ *   columns as @left and compatible column types.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Computes rows in @left that also exist in @right. All matched rows
+ * in @left are kept even if they are duplicated. The number of
+ * matched rows in @right isn't considered. For example, `[1, 1, 2]`
+ * and `[1, 3]` returns `[1, 1]`. Note that this isn't the same as
+ * `INTERSECT ALL` in SQL that returns `[1]`.
+ *
+ * Returns: (transfer full) (nullable): A new #GDFDataFrame, %NULL on
+ *   error.
+ *
+ * Since: 21.0.0
+ */
+GDFDataFrame *
+gdf_data_frame_intersect(GDFDataFrame *left,
+                         GDFDataFrame *right,
+                         GError **error)
+{
+  GDFDataFramePrivate *left_priv = gdf_data_frame_get_instance_private(left);
+  GDFDataFramePrivate *right_priv = gdf_data_frame_get_instance_private(right);
+  DFError *df_error = NULL;
+  DFDataFrame *intersected = df_data_frame_intersect(left_priv->data_frame,
+                                                     right_priv->data_frame,
+                                                     &df_error);
+  return gdf_data_frame_new_result(intersected, df_error, "intersect", error);
+}
+
+/**
+ * gdf_data_frame_except:
+ * @left: A left #GDFDataFrame.
+ * @right: A right #GDFDataFrame. It must have the same number of
+ *   columns as @left and compatible column types.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Computes rows in @left that don't exist in @right. All rows in
+ * @left that match any row in @right are removed. Only duplicated
+ * rows that don't exist in @right are kept. For example, `[1, 1, 2]`
+ * and `[1, 3]` returns `[2]`. Note that this isn't the same as
+ * `EXCEPT ALL` in SQL that returns `[1, 2]`.
+ *
+ * Returns: (transfer full) (nullable): A new #GDFDataFrame, %NULL on
+ *   error.
+ *
+ * Since: 21.0.0
+ */
+GDFDataFrame *
+gdf_data_frame_except(GDFDataFrame *left,
+                      GDFDataFrame *right,
+                      GError **error)
+{
+  GDFDataFramePrivate *left_priv = gdf_data_frame_get_instance_private(left);
+  GDFDataFramePrivate *right_priv = gdf_data_frame_get_instance_private(right);
+  DFError *df_error = NULL;
+  DFDataFrame *excepted = df_data_frame_except(left_priv->data_frame,
+                                               right_priv->data_frame,
+                                               &df_error);
+  return gdf_data_frame_new_result(excepted, df_error, "except", error);
+}
+
+/**
+ * gdf_data_frame_distinct:
+ * @data_frame: A #GDFDataFrame.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (transfer full) (nullable): A new #GDFDataFrame that
+ *   doesn't have duplicated rows, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFDataFrame *
+gdf_data_frame_distinct(GDFDataFrame *data_frame, GError **error)
+{
+  GDFDataFramePrivate *priv = gdf_data_frame_get_instance_private(data_frame);
+  DFError *df_error = NULL;
+  DFDataFrame *distinct = df_data_frame_distinct(priv->data_frame, &df_error);
+  return gdf_data_frame_new_result(distinct, df_error, "distinct", error);
+}
+
+/**
+ * gdf_data_frame_with_column:
+ * @data_frame: A #GDFDataFrame.
+ * @name: A column name. If @data_frame already has a column with the
+ *   same name, the column is replaced. Otherwise, a new column is
+ *   added at the end.
+ * @expr: A #GDFExpr that computes the column values.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (transfer full) (nullable): A new #GDFDataFrame that has
+ *   the column, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFDataFrame *
+gdf_data_frame_with_column(GDFDataFrame *data_frame,
+                           const gchar *name,
+                           GDFExpr *expr,
+                           GError **error)
+{
+  GDFDataFramePrivate *priv = gdf_data_frame_get_instance_private(data_frame);
+  DFError *df_error = NULL;
+  DFDataFrame *added = df_data_frame_with_column(priv->data_frame,
+                                                 name,
+                                                 gdf_expr_get_raw(expr),
+                                                 &df_error);
+  return gdf_data_frame_new_result(added, df_error, "with-column", error);
+}
+
+/**
+ * gdf_data_frame_with_column_renamed:
+ * @data_frame: A #GDFDataFrame.
+ * @old_name: The current column name. It may be qualified by relation
+ *   name such as `table.column`.
+ * @new_name: A new column name.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (transfer full) (nullable): A new #GDFDataFrame that has
+ *   the renamed column, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFDataFrame *
+gdf_data_frame_with_column_renamed(GDFDataFrame *data_frame,
+                                   const gchar *old_name,
+                                   const gchar *new_name,
+                                   GError **error)
+{
+  GDFDataFramePrivate *priv = gdf_data_frame_get_instance_private(data_frame);
+  DFError *df_error = NULL;
+  DFDataFrame *renamed =
+    df_data_frame_with_column_renamed(priv->data_frame,
+                                      old_name,
+                                      new_name,
+                                      &df_error);
+  return gdf_data_frame_new_result(renamed,
+                                   df_error,
+                                   "with-column-renamed",
+                                   error);
+}
+
+/**
+ * gdf_data_frame_drop_columns:
+ * @data_frame: A #GDFDataFrame.
+ * @columns: (array length=n_columns): Column names to be removed.
+ * @n_columns: The number of @columns.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (transfer full) (nullable): A new #GDFDataFrame that
+ *   doesn't have the removed columns, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFDataFrame *
+gdf_data_frame_drop_columns(GDFDataFrame *data_frame,
+                            const gchar **columns,
+                            gsize n_columns,
+                            GError **error)
+{
+  GDFDataFramePrivate *priv = gdf_data_frame_get_instance_private(data_frame);
+  DFError *df_error = NULL;
+  DFDataFrame *dropped = df_data_frame_drop_columns(priv->data_frame,
+                                                    columns,
+                                                    n_columns,
+                                                    &df_error);
+  return gdf_data_frame_new_result(dropped, df_error, "drop-columns", error);
+}
+
+/**
+ * gdf_data_frame_create_physical_plan:
+ * @data_frame: A #GDFDataFrame.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Creates a physical plan of @data_frame. You can execute it and get
+ * execution metrics of each operator after the execution.
+ *
+ * Returns: (transfer full) (nullable): A new #GDFPhysicalPlan, %NULL
+ *   on error.
+ *
+ * Since: 21.0.0
+ */
+GDFPhysicalPlan *
+gdf_data_frame_create_physical_plan(GDFDataFrame *data_frame, GError **error)
+{
+  GDFDataFramePrivate *priv = gdf_data_frame_get_instance_private(data_frame);
+  DFError *df_error = NULL;
+  DFPhysicalPlan *plan =
+    df_data_frame_create_physical_plan(priv->data_frame, &df_error);
+  if (!plan) {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[data-frame][create-physical-plan] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+    return NULL;
+  }
+  return gdf_physical_plan_new_raw(plan);
+}
+
+/**
+ * gdf_data_frame_explain:
+ * @data_frame: A #GDFDataFrame.
+ * @verbose: Whether all intermediate plans are included or not.
+ * @analyze: Whether @data_frame is executed to collect execution
+ *   metrics or not. This is `EXPLAIN ANALYZE` in SQL.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (transfer full) (nullable): A new #GDFDataFrame that has
+ *   `plan_type` and `plan` columns, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFDataFrame *
+gdf_data_frame_explain(GDFDataFrame *data_frame,
+                       gboolean verbose,
+                       gboolean analyze,
+                       GError **error)
+{
+  GDFDataFramePrivate *priv = gdf_data_frame_get_instance_private(data_frame);
+  DFError *df_error = NULL;
+  DFDataFrame *explained = df_data_frame_explain(priv->data_frame,
+                                                 verbose,
+                                                 analyze,
+                                                 &df_error);
+  return gdf_data_frame_new_result(explained, df_error, "explain", error);
+}
+
+static gchar *
+gdf_data_frame_plan_string_new_result(char *df_plan,
+                                      DFError *df_error,
+                                      const gchar *tag,
+                                      GError **error)
+{
+  if (!df_plan) {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[data-frame][%s] %s",
+                tag,
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+    return NULL;
+  }
+  gchar *plan = g_strdup(df_plan);
+  free(df_plan);
+  return plan;
+}
+
+/**
+ * gdf_data_frame_format_logical_plan:
+ * @data_frame: A #GDFDataFrame.
+ * @format: A #GDFPlanFormat.
+ *
+ * Returns: A string representation of the logical plan of
+ *   @data_frame.
+ *
+ *   It should be freed with g_free() when no longer needed.
+ *
+ * Since: 21.0.0
+ */
+gchar *
+gdf_data_frame_format_logical_plan(GDFDataFrame *data_frame,
+                                   GDFPlanFormat format)
+{
+  GDFDataFramePrivate *priv = gdf_data_frame_get_instance_private(data_frame);
+  char *df_plan = df_data_frame_format_logical_plan(priv->data_frame, format);
+  gchar *plan = g_strdup(df_plan);
+  free(df_plan);
+  return plan;
+}
+
+/**
+ * gdf_data_frame_format_optimized_logical_plan:
+ * @data_frame: A #GDFDataFrame.
+ * @format: A #GDFPlanFormat.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (nullable): A string representation of the optimized
+ *   logical plan of @data_frame, %NULL on error.
+ *
+ *   It should be freed with g_free() when no longer needed.
+ *
+ * Since: 21.0.0
+ */
+gchar *
+gdf_data_frame_format_optimized_logical_plan(GDFDataFrame *data_frame,
+                                             GDFPlanFormat format,
+                                             GError **error)
+{
+  GDFDataFramePrivate *priv = gdf_data_frame_get_instance_private(data_frame);
+  DFError *df_error = NULL;
+  char *df_plan =
+    df_data_frame_format_optimized_logical_plan(priv->data_frame,
+                                                format,
+                                                &df_error);
+  return gdf_data_frame_plan_string_new_result(df_plan,
+                                               df_error,
+                                               "format-optimized-logical-plan",
+                                               error);
+}
+
+/**
+ * gdf_data_frame_format_physical_plan:
+ * @data_frame: A #GDFDataFrame.
+ * @format: A #GDFPlanFormat.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (nullable): A string representation of the physical plan
+ *   of @data_frame, %NULL on error.
+ *
+ *   It should be freed with g_free() when no longer needed.
+ *
+ * Since: 21.0.0
+ */
+gchar *
+gdf_data_frame_format_physical_plan(GDFDataFrame *data_frame,
+                                    GDFPlanFormat format,
+                                    GError **error)
+{
+  GDFDataFramePrivate *priv = gdf_data_frame_get_instance_private(data_frame);
+  DFError *df_error = NULL;
+  char *df_plan = df_data_frame_format_physical_plan(priv->data_frame,
+                                                     format,
+                                                     &df_error);
+  return gdf_data_frame_plan_string_new_result(df_plan,
+                                               df_error,
+                                               "format-physical-plan",
+                                               error);
+}
+
 GDFDataFrame *
 gdf_data_frame_new_raw(DFDataFrame *data_frame)
 {
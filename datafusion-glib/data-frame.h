@@ -16,8 +16,13 @@
 
 #pragma once
 
+#include <gio/gio.h>
+
 #include <arrow-glib/arrow-glib.h>
 
+#include <datafusion-glib/cancellation-token.h>
+#include <datafusion-glib/expr.h>
+#include <datafusion-glib/physical-plan.h>
 #include <datafusion-glib/version.h>
 
 G_BEGIN_DECLS
@@ -43,6 +48,54 @@ gdf_parquet_writer_properties_set_max_row_group_size(
   GDFParquetWriterProperties *properties,
   guint64 size);
 
+/**
+ * GDFJoinType:
+ * @GDF_JOIN_TYPE_INNER: Inner join.
+ * @GDF_JOIN_TYPE_LEFT: Left outer join.
+ * @GDF_JOIN_TYPE_RIGHT: Right outer join.
+ * @GDF_JOIN_TYPE_FULL: Full outer join.
+ * @GDF_JOIN_TYPE_LEFT_SEMI: Left semi join. Only left rows that have
+ *   matched right rows are returned.
+ * @GDF_JOIN_TYPE_RIGHT_SEMI: Right semi join. Only right rows that
+ *   have matched left rows are returned.
+ * @GDF_JOIN_TYPE_LEFT_ANTI: Left anti join. Only left rows that don't
+ *   have matched right rows are returned.
+ * @GDF_JOIN_TYPE_RIGHT_ANTI: Right anti join. Only right rows that
+ *   don't have matched left rows are returned.
+ *
+ * Join types for gdf_data_frame_join().
+ *
+ * They are corresponding to `DFJoinType` values.
+ *
+ * Since: 21.0.0
+ */
+typedef enum {
+  GDF_JOIN_TYPE_INNER,
+  GDF_JOIN_TYPE_LEFT,
+  GDF_JOIN_TYPE_RIGHT,
+  GDF_JOIN_TYPE_FULL,
+  GDF_JOIN_TYPE_LEFT_SEMI,
+  GDF_JOIN_TYPE_RIGHT_SEMI,
+  GDF_JOIN_TYPE_LEFT_ANTI,
+  GDF_JOIN_TYPE_RIGHT_ANTI,
+} GDFJoinType;
+
+/**
+ * GDFPlanFormat:
+ * @GDF_PLAN_FORMAT_INDENT: Indented text.
+ * @GDF_PLAN_FORMAT_GRAPHVIZ: The DOT language for Graphviz.
+ *
+ * Formats for gdf_data_frame_format_logical_plan() and so on.
+ *
+ * They are corresponding to `DFPlanFormat` values.
+ *
+ * Since: 21.0.0
+ */
+typedef enum {
+  GDF_PLAN_FORMAT_INDENT,
+  GDF_PLAN_FORMAT_GRAPHVIZ,
+} GDFPlanFormat;
+
 #define GDF_TYPE_DATA_FRAME (gdf_data_frame_get_type())
 G_DECLARE_DERIVABLE_TYPE(GDFDataFrame,
                          gdf_data_frame,
@@ -54,6 +107,14 @@ struct _GDFDataFrameClass
   GObjectClass parent_class;
 };
 
+GDF_AVAILABLE_IN_21_0
+void
+gdf_data_frame_set_cancellation_token(GDFDataFrame *data_frame,
+                                      GDFCancellationToken *token);
+GDF_AVAILABLE_IN_21_0
+void
+gdf_data_frame_set_timeout(GDFDataFrame *data_frame,
+                           guint64 timeout_milliseconds);
 GDF_AVAILABLE_IN_10_0
 gboolean
 gdf_data_frame_show(GDFDataFrame *data_frame, GError **error);
@@ -61,11 +122,164 @@ GDF_AVAILABLE_IN_10_0
 GArrowTable *
 gdf_data_frame_to_table(GDFDataFrame *data_frame, GError **error);
 GDF_AVAILABLE_IN_21_0
+void
+gdf_data_frame_to_table_async(GDFDataFrame *data_frame,
+                              GCancellable *cancellable,
+                              GAsyncReadyCallback callback,
+                              gpointer user_data);
+GDF_AVAILABLE_IN_21_0
+GArrowTable *
+gdf_data_frame_to_table_finish(GDFDataFrame *data_frame,
+                               GAsyncResult *result,
+                               GError **error);
+GDF_AVAILABLE_IN_21_0
 gboolean
 gdf_data_frame_write_parquet(GDFDataFrame *data_frame,
                              const gchar *path,
                              GDFParquetWriterProperties *properties,
                              GError **error);
+GDF_AVAILABLE_IN_21_0
+GArrowRecordBatchReader *
+gdf_data_frame_execute_stream(GDFDataFrame *data_frame, GError **error);
+GDF_AVAILABLE_IN_21_0
+GArrowSchema *
+gdf_data_frame_get_schema(GDFDataFrame *data_frame, GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFPhysicalPlan *
+gdf_data_frame_create_physical_plan(GDFDataFrame *data_frame, GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFDataFrame *
+gdf_data_frame_explain(GDFDataFrame *data_frame,
+                       gboolean verbose,
+                       gboolean analyze,
+                       GError **error);
+GDF_AVAILABLE_IN_21_0
+gchar *
+gdf_data_frame_format_logical_plan(GDFDataFrame *data_frame,
+                                   GDFPlanFormat format);
+GDF_AVAILABLE_IN_21_0
+gchar *
+gdf_data_frame_format_optimized_logical_plan(GDFDataFrame *data_frame,
+                                             GDFPlanFormat format,
+                                             GError **error);
+GDF_AVAILABLE_IN_21_0
+gchar *
+gdf_data_frame_format_physical_plan(GDFDataFrame *data_frame,
+                                    GDFPlanFormat format,
+                                    GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFDataFrame *
+gdf_data_frame_select_columns(GDFDataFrame *data_frame,
+                              const gchar **columns,
+                              gsize n_columns,
+                              GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFDataFrame *
+gdf_data_frame_select(GDFDataFrame *data_frame,
+                      const gchar **sql_exprs,
+                      gsize n_sql_exprs,
+                      GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFDataFrame *
+gdf_data_frame_filter(GDFDataFrame *data_frame,
+                      const gchar *sql_predicate,
+                      GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFDataFrame *
+gdf_data_frame_sort(GDFDataFrame *data_frame,
+                    const gchar **sql_sort_exprs,
+                    gsize n_sql_sort_exprs,
+                    GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFDataFrame *
+gdf_data_frame_limit(GDFDataFrame *data_frame,
+                     gsize offset,
+                     gint64 fetch,
+                     GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFDataFrame *
+gdf_data_frame_select_exprs(GDFDataFrame *data_frame,
+                            GDFExpr **exprs,
+                            gsize n_exprs,
+                            GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFDataFrame *
+gdf_data_frame_filter_expr(GDFDataFrame *data_frame,
+                           GDFExpr *predicate,
+                           GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFDataFrame *
+gdf_data_frame_sort_exprs(GDFDataFrame *data_frame,
+                          GDFExpr **sort_exprs,
+                          gsize n_sort_exprs,
+                          GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFDataFrame *
+gdf_data_frame_aggregate(GDFDataFrame *data_frame,
+                         GDFExpr **group_exprs,
+                         gsize n_group_exprs,
+                         GDFExpr **aggr_exprs,
+                         gsize n_aggr_exprs,
+                         GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFDataFrame *
+gdf_data_frame_join(GDFDataFrame *left,
+                    GDFDataFrame *right,
+                    GDFJoinType join_type,
+                    const gchar **left_keys,
+                    const gchar **right_keys,
+                    gsize n_keys,
+                    GDFExpr *filter,
+                    GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFDataFrame *
+gdf_data_frame_join_on(GDFDataFrame *left,
+                       GDFDataFrame *right,
+                       GDFJoinType join_type,
+                       GDFExpr **on_exprs,
+                       gsize n_on_exprs,
+                       GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFDataFrame *
+gdf_data_frame_union(GDFDataFrame *left,
+                     GDFDataFrame *right,
+                     GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFDataFrame *
+gdf_data_frame_union_distinct(GDFDataFrame *left,
+                              GDFDataFrame *right,
+                              GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFDataFrame *
+gdf_data_frame_intersect(GDFDataFrame *left,
+                         GDFDataFrame *right,
+                         GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFDataFrame *
+gdf_data_frame_except(GDFDataFrame *left,
+                      GDFDataFrame *right,
+                      GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFDataFrame *
+gdf_data_frame_distinct(GDFDataFrame *data_frame, GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFDataFrame *
+gdf_data_frame_with_column(GDFDataFrame *data_frame,
+                           const gchar *name,
+                           GDFExpr *expr,
+                           GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFDataFrame *
+gdf_data_frame_with_column_renamed(GDFDataFrame *data_frame,
+                                   const gchar *old_name,
+                                   const gchar *new_name,
+                                   GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFDataFrame *
+gdf_data_frame_drop_columns(GDFDataFrame *data_frame,
+                            const gchar **columns,
+                            gsize n_columns,
+                            GError **error);
 
 
 G_END_DECLS
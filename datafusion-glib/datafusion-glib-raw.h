@@ -16,6 +16,15 @@
 
 #pragma once
 
+#include <datafusion-glib/avro-read-options-raw.h>
+#include <datafusion-glib/cancellation-token-raw.h>
 #include <datafusion-glib/csv-read-options-raw.h>
 #include <datafusion-glib/data-frame-raw.h>
+#include <datafusion-glib/expr-raw.h>
+#include <datafusion-glib/json-read-options-raw.h>
+#include <datafusion-glib/physical-plan-raw.h>
+#include <datafusion-glib/runtime-config-raw.h>
+#include <datafusion-glib/session-config-raw.h>
 #include <datafusion-glib/session-context-raw.h>
+#include <datafusion-glib/signature-raw.h>
+#include <datafusion-glib/tokio-runtime-raw.h>
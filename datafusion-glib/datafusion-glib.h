@@ -16,8 +16,19 @@
 
 #pragma once
 
+#include <datafusion-glib/accumulator.h>
+#include <datafusion-glib/avro-read-options.h>
+#include <datafusion-glib/cancellation-token.h>
 #include <datafusion-glib/csv-read-options.h>
 #include <datafusion-glib/data-frame.h>
 #include <datafusion-glib/error.h>
+#include <datafusion-glib/expr.h>
+#include <datafusion-glib/file-compression-type.h>
+#include <datafusion-glib/json-read-options.h>
+#include <datafusion-glib/physical-plan.h>
+#include <datafusion-glib/runtime-config.h>
+#include <datafusion-glib/session-config.h>
 #include <datafusion-glib/session-context.h>
+#include <datafusion-glib/signature.h>
+#include <datafusion-glib/tokio-runtime.h>
 #include <datafusion-glib/version.h>
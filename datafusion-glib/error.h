@@ -47,6 +47,13 @@ G_BEGIN_DECLS
  * @GDF_ERROR_EXTERNAL: For example, a custom S3Error from the crate
  *   datafusion-objectstore-s3.
  * @GDF_ERROR_JIT: Error occurs during code generation.
+ * @GDF_ERROR_CONTEXT: Error with additional context.
+ * @GDF_ERROR_SUBSTRAIT: Error returned by substrait crate.
+ * @GDF_ERROR_PANIC: Panic in Rust code. This error should not happen in
+ *   normal usage of DataFusion. Since 21.0.0.
+ * @GDF_ERROR_CANCELLED: Query is cancelled by #GDFCancellationToken.
+ *   Since 21.0.0.
+ * @GDF_ERROR_TIMEOUT: Query is timed out. Since 21.0.0.
  *
  * The error codes are used by all datafusion-glib functions.
  *
@@ -69,6 +76,11 @@ typedef enum {
   GDF_ERROR_RESOURCES_EXHAUSTED,
   GDF_ERROR_EXTERNAL,
   GDF_ERROR_JIT,
+  GDF_ERROR_CONTEXT,
+  GDF_ERROR_SUBSTRAIT,
+  GDF_ERROR_PANIC,
+  GDF_ERROR_CANCELLED,
+  GDF_ERROR_TIMEOUT,
 } GDFError;
 
 #define GDF_ERROR gdf_error_quark()
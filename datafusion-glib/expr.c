@@ -0,0 +1,458 @@
+/*
+ * Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#include <datafusion-glib/error.h>
+#include <datafusion-glib/expr-raw.h>
+#include <datafusion-glib/session-context-raw.h>
+
+G_BEGIN_DECLS
+
+/**
+ * SECTION: expr
+ * @include: datafusion-glib/datafusion-glib.h
+ *
+ * #GDFExpr is a class for logical expression. You can use it
+ * instead of SQL expression strings to transform #GDFDataFrame.
+ */
+
+typedef struct GDFExprPrivate_ {
+  DFExpr *expr;
+} GDFExprPrivate;
+
+enum {
+  PROP_EXPR = 1,
+};
+
+G_DEFINE_TYPE_WITH_PRIVATE(GDFExpr,
+                           gdf_expr,
+                           G_TYPE_OBJECT)
+
+static void
+gdf_expr_finalize(GObject *object)
+{
+  GDFExprPrivate *priv = gdf_expr_get_instance_private(GDF_EXPR(object));
+  df_expr_free(priv->expr);
+  G_OBJECT_CLASS(gdf_expr_parent_class)->finalize(object);
+}
+
+static void
+gdf_expr_set_property(GObject *object,
+                      guint prop_id,
+                      const GValue *value,
+                      GParamSpec *pspec)
+{
+  GDFExprPrivate *priv = gdf_expr_get_instance_private(GDF_EXPR(object));
+
+  switch (prop_id) {
+  case PROP_EXPR:
+    priv->expr = g_value_get_pointer(value);
+    break;
+  default:
+    G_OBJECT_WARN_INVALID_PROPERTY_ID(object, prop_id, pspec);
+    break;
+  }
+}
+
+static void
+gdf_expr_init(GDFExpr *object)
+{
+}
+
+static void
+gdf_expr_class_init(GDFExprClass *klass)
+{
+  GObjectClass *gobject_class = G_OBJECT_CLASS(klass);
+  gobject_class->finalize = gdf_expr_finalize;
+  gobject_class->set_property = gdf_expr_set_property;
+
+  GParamSpec *spec;
+  spec = g_param_spec_pointer("expr",
+                              "Expression",
+                              "The raw DFExpr *",
+                              G_PARAM_WRITABLE | G_PARAM_CONSTRUCT_ONLY);
+  g_object_class_install_property(gobject_class, PROP_EXPR, spec);
+}
+
+static GDFExpr *
+gdf_expr_new_result(DFExpr *expr,
+                    DFError *df_error,
+                    const gchar *tag,
+                    GError **error)
+{
+  if (!expr) {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[expr][%s] %s",
+                tag,
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+    return NULL;
+  }
+  return gdf_expr_new_raw(expr);
+}
+
+static const DFExpr **
+gdf_exprs_get_raw(GDFExpr **exprs, gsize n_exprs)
+{
+  const DFExpr **raw_exprs = g_new(const DFExpr *, n_exprs);
+  gsize i;
+  for (i = 0; i < n_exprs; i++) {
+    raw_exprs[i] = gdf_expr_get_raw(exprs[i]);
+  }
+  return raw_exprs;
+}
+
+/**
+ * gdf_expr_new_column:
+ * @name: A column name. It may be qualified by relation name such as
+ *   `table.column`.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (nullable): A new column reference expression, %NULL on
+ *   error.
+ *
+ * Since: 21.0.0
+ */
+GDFExpr *
+gdf_expr_new_column(const gchar *name, GError **error)
+{
+  DFError *df_error = NULL;
+  DFExpr *expr = df_expr_new_column(name, &df_error);
+  return gdf_expr_new_result(expr, df_error, "new-column", error);
+}
+
+/**
+ * gdf_expr_new_literal:
+ * @array: A #GArrowArray that has the literal value.
+ * @i: The index of the literal value in @array.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (nullable): A new literal expression, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFExpr *
+gdf_expr_new_literal(GArrowArray *array, gint64 i, GError **error)
+{
+  GArrowArray *value = garrow_array_slice(array, i, 1);
+  gpointer c_abi_array = NULL;
+  gpointer c_abi_schema = NULL;
+  gboolean exported =
+    garrow_array_export(value, &c_abi_array, &c_abi_schema, error);
+  g_object_unref(value);
+  if (!exported) {
+    return NULL;
+  }
+  DFError *df_error = NULL;
+  DFExpr *expr = df_expr_new_literal(c_abi_schema, c_abi_array, &df_error);
+  g_free(c_abi_schema);
+  g_free(c_abi_array);
+  return gdf_expr_new_result(expr, df_error, "new-literal", error);
+}
+
+/**
+ * gdf_expr_new_binary:
+ * @left: A left hand side #GDFExpr.
+ * @operator: A #GDFOperator.
+ * @right: A right hand side #GDFExpr.
+ *
+ * Returns: A new binary operator expression such as `left + right`.
+ *
+ * Since: 21.0.0
+ */
+GDFExpr *
+gdf_expr_new_binary(GDFExpr *left, GDFOperator operator, GDFExpr *right)
+{
+  return gdf_expr_new_raw(df_expr_new_binary(gdf_expr_get_raw(left),
+                                             operator,
+                                             gdf_expr_get_raw(right)));
+}
+
+/**
+ * gdf_expr_new_unary:
+ * @operator: A #GDFUnaryOperator.
+ * @expr: An operand #GDFExpr.
+ *
+ * Returns: A new unary operator expression such as `NOT expr`.
+ *
+ * Since: 21.0.0
+ */
+GDFExpr *
+gdf_expr_new_unary(GDFUnaryOperator operator, GDFExpr *expr)
+{
+  return gdf_expr_new_raw(df_expr_new_unary(operator, gdf_expr_get_raw(expr)));
+}
+
+/**
+ * gdf_expr_new_function:
+ * @context: (nullable): A #GDFSessionContext to look up UDFs and
+ *   UDAFs.
+ * @name: A function name such as `abs` and `sum`.
+ * @args: (array length=n_args): Arguments of the function.
+ * @n_args: The number of @args.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (nullable): A new function call expression, %NULL on
+ *   error.
+ *
+ * Since: 21.0.0
+ */
+GDFExpr *
+gdf_expr_new_function(GDFSessionContext *context,
+                      const gchar *name,
+                      GDFExpr **args,
+                      gsize n_args,
+                      GError **error)
+{
+  DFSessionContext *df_context = NULL;
+  if (context) {
+    df_context = gdf_session_context_get_raw(context);
+  }
+  const DFExpr **df_args = gdf_exprs_get_raw(args, n_args);
+  DFError *df_error = NULL;
+  DFExpr *expr =
+    df_expr_new_function(df_context, name, df_args, n_args, &df_error);
+  g_free(df_args);
+  return gdf_expr_new_result(expr, df_error, "new-function", error);
+}
+
+/**
+ * gdf_expr_new_case:
+ * @base: (nullable): A base #GDFExpr that is compared with @whens.
+ * @whens: (array length=n_whens): `WHEN` expressions.
+ * @thens: (array length=n_whens): `THEN` expressions.
+ * @n_whens: The number of @whens and @thens.
+ * @else_: (nullable): An `ELSE` expression.
+ *
+ * Returns: A new `CASE` expression.
+ *
+ * Since: 21.0.0
+ */
+GDFExpr *
+gdf_expr_new_case(GDFExpr *base,
+                  GDFExpr **whens,
+                  GDFExpr **thens,
+                  gsize n_whens,
+                  GDFExpr *else_)
+{
+  const DFExpr **df_whens = gdf_exprs_get_raw(whens, n_whens);
+  const DFExpr **df_thens = gdf_exprs_get_raw(thens, n_whens);
+  DFExpr *expr =
+    df_expr_new_case(base ? gdf_expr_get_raw(base) : NULL,
+                     df_whens,
+                     df_thens,
+                     n_whens,
+                     else_ ? gdf_expr_get_raw(else_) : NULL);
+  g_free(df_whens);
+  g_free(df_thens);
+  return gdf_expr_new_raw(expr);
+}
+
+/**
+ * gdf_expr_new_cast:
+ * @expr: A #GDFExpr to be casted.
+ * @data_type: A #GArrowDataType to be casted to.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (nullable): A new `CAST` expression, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFExpr *
+gdf_expr_new_cast(GDFExpr *expr, GArrowDataType *data_type, GError **error)
+{
+  gpointer c_abi_data_type = garrow_data_type_export(data_type, error);
+  if (!c_abi_data_type) {
+    return NULL;
+  }
+  DFError *df_error = NULL;
+  DFExpr *casted = df_expr_new_cast(gdf_expr_get_raw(expr),
+                                    c_abi_data_type,
+                                    &df_error);
+  g_free(c_abi_data_type);
+  return gdf_expr_new_result(casted, df_error, "new-cast", error);
+}
+
+/**
+ * gdf_expr_new_in_list:
+ * @expr: A #GDFExpr to be tested.
+ * @list: (array length=n_list): Candidate expressions.
+ * @n_list: The number of @list.
+ * @negated: Whether `NOT IN` or not.
+ *
+ * Returns: A new `IN` list expression.
+ *
+ * Since: 21.0.0
+ */
+GDFExpr *
+gdf_expr_new_in_list(GDFExpr *expr,
+                     GDFExpr **list,
+                     gsize n_list,
+                     gboolean negated)
+{
+  const DFExpr **df_list = gdf_exprs_get_raw(list, n_list);
+  DFExpr *in_list = df_expr_new_in_list(gdf_expr_get_raw(expr),
+                                        df_list,
+                                        n_list,
+                                        negated);
+  g_free(df_list);
+  return gdf_expr_new_raw(in_list);
+}
+
+/**
+ * gdf_expr_new_alias:
+ * @expr: A #GDFExpr to be aliased.
+ * @name: A new name.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (nullable): A new alias expression, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFExpr *
+gdf_expr_new_alias(GDFExpr *expr, const gchar *name, GError **error)
+{
+  DFError *df_error = NULL;
+  DFExpr *alias = df_expr_new_alias(gdf_expr_get_raw(expr), name, &df_error);
+  return gdf_expr_new_result(alias, df_error, "new-alias", error);
+}
+
+/**
+ * gdf_expr_new_sort:
+ * @expr: A #GDFExpr to be sorted by.
+ * @ascending: Whether ascending order or descending order.
+ * @nulls_first: Whether `NULL`s are placed at the first or last.
+ *
+ * Returns: A new sort key expression for gdf_data_frame_sort_exprs().
+ *
+ * Since: 21.0.0
+ */
+GDFExpr *
+gdf_expr_new_sort(GDFExpr *expr, gboolean ascending, gboolean nulls_first)
+{
+  return gdf_expr_new_raw(df_expr_new_sort(gdf_expr_get_raw(expr),
+                                           ascending,
+                                           nulls_first));
+}
+
+/**
+ * gdf_expr_new_cube:
+ * @exprs: (array length=n_exprs): #GDFExpr s to be grouped by. All
+ *   combinations of them are used as grouping sets.
+ * @n_exprs: The number of @exprs.
+ *
+ * Returns: A new `CUBE` expression for gdf_data_frame_aggregate().
+ *
+ * Since: 21.0.0
+ */
+GDFExpr *
+gdf_expr_new_cube(GDFExpr **exprs, gsize n_exprs)
+{
+  const DFExpr **df_exprs = gdf_exprs_get_raw(exprs, n_exprs);
+  DFExpr *cube = df_expr_new_cube(df_exprs, n_exprs);
+  g_free(df_exprs);
+  return gdf_expr_new_raw(cube);
+}
+
+/**
+ * gdf_expr_new_rollup:
+ * @exprs: (array length=n_exprs): #GDFExpr s to be grouped by. All
+ *   prefixes of them are used as grouping sets.
+ * @n_exprs: The number of @exprs.
+ *
+ * Returns: A new `ROLLUP` expression for gdf_data_frame_aggregate().
+ *
+ * Since: 21.0.0
+ */
+GDFExpr *
+gdf_expr_new_rollup(GDFExpr **exprs, gsize n_exprs)
+{
+  const DFExpr **df_exprs = gdf_exprs_get_raw(exprs, n_exprs);
+  DFExpr *rollup = df_expr_new_rollup(df_exprs, n_exprs);
+  g_free(df_exprs);
+  return gdf_expr_new_raw(rollup);
+}
+
+/**
+ * gdf_expr_new_grouping_sets:
+ * @exprs: (array length=n_exprs): #GDFExpr s of all grouping sets.
+ *   They are concatenated.
+ * @n_exprs: The number of @exprs. It must be the sum of @set_sizes.
+ * @set_sizes: (array length=n_sets): The number of #GDFExpr s in each
+ *   grouping set.
+ * @n_sets: The number of grouping sets.
+ *
+ * For example, `GROUPING SETS ((a, b), (c))` uses `[a, b, c]` for
+ * @exprs and `[2, 1]` for @set_sizes.
+ *
+ * Returns: A new `GROUPING SETS` expression for
+ *   gdf_data_frame_aggregate().
+ *
+ * Since: 21.0.0
+ */
+GDFExpr *
+gdf_expr_new_grouping_sets(GDFExpr **exprs,
+                           gsize n_exprs,
+                           const gsize *set_sizes,
+                           gsize n_sets)
+{
+  gsize total_set_size = 0;
+  gsize i;
+  for (i = 0; i < n_sets; i++) {
+    total_set_size += set_sizes[i];
+  }
+  g_return_val_if_fail(total_set_size == n_exprs, NULL);
+  const DFExpr **df_exprs = gdf_exprs_get_raw(exprs, n_exprs);
+  DFExpr *grouping_sets =
+    df_expr_new_grouping_sets(df_exprs, set_sizes, n_sets);
+  g_free(df_exprs);
+  return gdf_expr_new_raw(grouping_sets);
+}
+
+/**
+ * gdf_expr_to_string:
+ * @expr: A #GDFExpr.
+ *
+ * Returns: The string representation of @expr.
+ *
+ *   It should be freed with g_free() when no longer needed.
+ *
+ * Since: 21.0.0
+ */
+gchar *
+gdf_expr_to_string(GDFExpr *expr)
+{
+  char *df_string = df_expr_to_string(gdf_expr_get_raw(expr));
+  gchar *string = g_strdup(df_string);
+  free(df_string);
+  return string;
+}
+
+GDFExpr *
+gdf_expr_new_raw(DFExpr *expr)
+{
+  return g_object_new(GDF_TYPE_EXPR,
+                      "expr", expr,
+                      NULL);
+}
+
+DFExpr *
+gdf_expr_get_raw(GDFExpr *expr)
+{
+  GDFExprPrivate *priv = gdf_expr_get_instance_private(expr);
+  return priv->expr;
+}
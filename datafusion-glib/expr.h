@@ -0,0 +1,192 @@
+/*
+ * Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#pragma once
+
+#include <arrow-glib/arrow-glib.h>
+
+#include <datafusion-glib/version.h>
+
+G_BEGIN_DECLS
+
+/* session-context.h includes this header via data-frame.h. */
+typedef struct _GDFSessionContext GDFSessionContext;
+
+/**
+ * GDFOperator:
+ * @GDF_OPERATOR_EQ: `=`
+ * @GDF_OPERATOR_NOT_EQ: `!=`
+ * @GDF_OPERATOR_LT: `<`
+ * @GDF_OPERATOR_LT_EQ: `<=`
+ * @GDF_OPERATOR_GT: `>`
+ * @GDF_OPERATOR_GT_EQ: `>=`
+ * @GDF_OPERATOR_PLUS: `+`
+ * @GDF_OPERATOR_MINUS: `-`
+ * @GDF_OPERATOR_MULTIPLY: `*`
+ * @GDF_OPERATOR_DIVIDE: `/`
+ * @GDF_OPERATOR_MODULO: `%`
+ * @GDF_OPERATOR_AND: `AND`
+ * @GDF_OPERATOR_OR: `OR`
+ * @GDF_OPERATOR_IS_DISTINCT_FROM: `IS DISTINCT FROM`
+ * @GDF_OPERATOR_IS_NOT_DISTINCT_FROM: `IS NOT DISTINCT FROM`
+ * @GDF_OPERATOR_REGEX_MATCH: `~`
+ * @GDF_OPERATOR_REGEX_I_MATCH: `~*`
+ * @GDF_OPERATOR_REGEX_NOT_MATCH: `!~`
+ * @GDF_OPERATOR_REGEX_NOT_I_MATCH: `!~*`
+ * @GDF_OPERATOR_BITWISE_AND: `&`
+ * @GDF_OPERATOR_BITWISE_OR: `|`
+ * @GDF_OPERATOR_BITWISE_XOR: `#`
+ * @GDF_OPERATOR_BITWISE_SHIFT_RIGHT: `>>`
+ * @GDF_OPERATOR_BITWISE_SHIFT_LEFT: `<<`
+ * @GDF_OPERATOR_STRING_CONCAT: `||`
+ *
+ * Binary operators for gdf_expr_new_binary().
+ *
+ * They are corresponding to `DFOperator` values.
+ *
+ * Since: 21.0.0
+ */
+typedef enum {
+  GDF_OPERATOR_EQ,
+  GDF_OPERATOR_NOT_EQ,
+  GDF_OPERATOR_LT,
+  GDF_OPERATOR_LT_EQ,
+  GDF_OPERATOR_GT,
+  GDF_OPERATOR_GT_EQ,
+  GDF_OPERATOR_PLUS,
+  GDF_OPERATOR_MINUS,
+  GDF_OPERATOR_MULTIPLY,
+  GDF_OPERATOR_DIVIDE,
+  GDF_OPERATOR_MODULO,
+  GDF_OPERATOR_AND,
+  GDF_OPERATOR_OR,
+  GDF_OPERATOR_IS_DISTINCT_FROM,
+  GDF_OPERATOR_IS_NOT_DISTINCT_FROM,
+  GDF_OPERATOR_REGEX_MATCH,
+  GDF_OPERATOR_REGEX_I_MATCH,
+  GDF_OPERATOR_REGEX_NOT_MATCH,
+  GDF_OPERATOR_REGEX_NOT_I_MATCH,
+  GDF_OPERATOR_BITWISE_AND,
+  GDF_OPERATOR_BITWISE_OR,
+  GDF_OPERATOR_BITWISE_XOR,
+  GDF_OPERATOR_BITWISE_SHIFT_RIGHT,
+  GDF_OPERATOR_BITWISE_SHIFT_LEFT,
+  GDF_OPERATOR_STRING_CONCAT,
+} GDFOperator;
+
+/**
+ * GDFUnaryOperator:
+ * @GDF_UNARY_OPERATOR_NOT: `NOT expr`
+ * @GDF_UNARY_OPERATOR_NEGATIVE: `-expr`
+ * @GDF_UNARY_OPERATOR_IS_NULL: `expr IS NULL`
+ * @GDF_UNARY_OPERATOR_IS_NOT_NULL: `expr IS NOT NULL`
+ * @GDF_UNARY_OPERATOR_IS_TRUE: `expr IS TRUE`
+ * @GDF_UNARY_OPERATOR_IS_FALSE: `expr IS FALSE`
+ * @GDF_UNARY_OPERATOR_IS_UNKNOWN: `expr IS UNKNOWN`
+ * @GDF_UNARY_OPERATOR_IS_NOT_TRUE: `expr IS NOT TRUE`
+ * @GDF_UNARY_OPERATOR_IS_NOT_FALSE: `expr IS NOT FALSE`
+ * @GDF_UNARY_OPERATOR_IS_NOT_UNKNOWN: `expr IS NOT UNKNOWN`
+ *
+ * Unary operators for gdf_expr_new_unary().
+ *
+ * They are corresponding to `DFUnaryOperator` values.
+ *
+ * Since: 21.0.0
+ */
+typedef enum {
+  GDF_UNARY_OPERATOR_NOT,
+  GDF_UNARY_OPERATOR_NEGATIVE,
+  GDF_UNARY_OPERATOR_IS_NULL,
+  GDF_UNARY_OPERATOR_IS_NOT_NULL,
+  GDF_UNARY_OPERATOR_IS_TRUE,
+  GDF_UNARY_OPERATOR_IS_FALSE,
+  GDF_UNARY_OPERATOR_IS_UNKNOWN,
+  GDF_UNARY_OPERATOR_IS_NOT_TRUE,
+  GDF_UNARY_OPERATOR_IS_NOT_FALSE,
+  GDF_UNARY_OPERATOR_IS_NOT_UNKNOWN,
+} GDFUnaryOperator;
+
+#define GDF_TYPE_EXPR (gdf_expr_get_type())
+G_DECLARE_DERIVABLE_TYPE(GDFExpr,
+                         gdf_expr,
+                         GDF,
+                         EXPR,
+                         GObject)
+struct _GDFExprClass
+{
+  GObjectClass parent_class;
+};
+
+GDF_AVAILABLE_IN_21_0
+GDFExpr *
+gdf_expr_new_column(const gchar *name, GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFExpr *
+gdf_expr_new_literal(GArrowArray *array, gint64 i, GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFExpr *
+gdf_expr_new_binary(GDFExpr *left, GDFOperator operator, GDFExpr *right);
+GDF_AVAILABLE_IN_21_0
+GDFExpr *
+gdf_expr_new_unary(GDFUnaryOperator operator, GDFExpr *expr);
+GDF_AVAILABLE_IN_21_0
+GDFExpr *
+gdf_expr_new_function(GDFSessionContext *context,
+                      const gchar *name,
+                      GDFExpr **args,
+                      gsize n_args,
+                      GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFExpr *
+gdf_expr_new_case(GDFExpr *base,
+                  GDFExpr **whens,
+                  GDFExpr **thens,
+                  gsize n_whens,
+                  GDFExpr *else_);
+GDF_AVAILABLE_IN_21_0
+GDFExpr *
+gdf_expr_new_cast(GDFExpr *expr, GArrowDataType *data_type, GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFExpr *
+gdf_expr_new_in_list(GDFExpr *expr,
+                     GDFExpr **list,
+                     gsize n_list,
+                     gboolean negated);
+GDF_AVAILABLE_IN_21_0
+GDFExpr *
+gdf_expr_new_alias(GDFExpr *expr, const gchar *name, GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFExpr *
+gdf_expr_new_sort(GDFExpr *expr, gboolean ascending, gboolean nulls_first);
+GDF_AVAILABLE_IN_21_0
+GDFExpr *
+gdf_expr_new_cube(GDFExpr **exprs, gsize n_exprs);
+GDF_AVAILABLE_IN_21_0
+GDFExpr *
+gdf_expr_new_rollup(GDFExpr **exprs, gsize n_exprs);
+GDF_AVAILABLE_IN_21_0
+GDFExpr *
+gdf_expr_new_grouping_sets(GDFExpr **exprs,
+                           gsize n_exprs,
+                           const gsize *set_sizes,
+                           gsize n_sets);
+
+GDF_AVAILABLE_IN_21_0
+gchar *
+gdf_expr_to_string(GDFExpr *expr);
+
+
+G_END_DECLS
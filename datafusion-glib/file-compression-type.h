@@ -0,0 +1,55 @@
+/*
+ * Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#pragma once
+
+#include <glib-object.h>
+
+#include <datafusion-glib/version.h>
+
+G_BEGIN_DECLS
+
+/**
+ * SECTION: file-compression-type
+ * @include: datafusion-glib/datafusion-glib.h
+ *
+ * #GDFFileCompressionType is an enum for compression types of data
+ * source files such as newline-delimited JSON files.
+ */
+
+/**
+ * GDFFileCompressionType:
+ * @GDF_FILE_COMPRESSION_TYPE_UNCOMPRESSED: Uncompressed file.
+ * @GDF_FILE_COMPRESSION_TYPE_GZIP: Gzip-ed file.
+ * @GDF_FILE_COMPRESSION_TYPE_BZIP2: Bzip2-ed file.
+ * @GDF_FILE_COMPRESSION_TYPE_XZ: Xz-ed file.
+ * @GDF_FILE_COMPRESSION_TYPE_ZSTD: Zstd-ed file.
+ *
+ * Compression types of data source files.
+ *
+ * They are corresponding to `DFFileCompressionType` values.
+ *
+ * Since: 21.0.0
+ */
+typedef enum {
+  GDF_FILE_COMPRESSION_TYPE_UNCOMPRESSED,
+  GDF_FILE_COMPRESSION_TYPE_GZIP,
+  GDF_FILE_COMPRESSION_TYPE_BZIP2,
+  GDF_FILE_COMPRESSION_TYPE_XZ,
+  GDF_FILE_COMPRESSION_TYPE_ZSTD,
+} GDFFileCompressionType;
+
+G_END_DECLS
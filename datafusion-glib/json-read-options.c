@@ -0,0 +1,412 @@
+/*
+ * Copyright 2022-2023 Sutou Kouhei <kou@clear-code.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#include <datafusion-glib/json-read-options-raw.h>
+#include <datafusion-glib/error.h>
+
+G_BEGIN_DECLS
+
+/**
+ * SECTION: json-read-options
+ * @include: datafusion-glib/datafusion-glib.h
+ *
+ * #GDFJSONReadOptions is a class for options on reading
+ * newline-delimited JSON.
+ */
+
+typedef struct GDFJSONReadOptionsPrivate_ {
+  DFJSONReadOptions *options;
+  GArrowSchema *schema;
+  GArrowSchema *table_partition_columns;
+  gchar *file_extension;
+} GDFJSONReadOptionsPrivate;
+
+enum {
+  PROP_SCHEMA_INFER_MAX_N_RECORDS = 1,
+};
+
+G_DEFINE_TYPE_WITH_PRIVATE(GDFJSONReadOptions,
+                           gdf_json_read_options,
+                           G_TYPE_OBJECT)
+
+static void
+gdf_json_read_options_finalize(GObject *object)
+{
+  GDFJSONReadOptionsPrivate *priv =
+    gdf_json_read_options_get_instance_private(GDF_JSON_READ_OPTIONS(object));
+  df_json_read_options_free(priv->options);
+  g_free(priv->file_extension);
+  G_OBJECT_CLASS(gdf_json_read_options_parent_class)->finalize(object);
+}
+
+static void
+gdf_json_read_options_dispose(GObject *object)
+{
+  GDFJSONReadOptionsPrivate *priv =
+    gdf_json_read_options_get_instance_private(GDF_JSON_READ_OPTIONS(object));
+  if (priv->schema) {
+    g_object_unref(priv->schema);
+    priv->schema = NULL;
+  }
+  if (priv->table_partition_columns) {
+    g_object_unref(priv->table_partition_columns);
+    priv->table_partition_columns = NULL;
+  }
+  G_OBJECT_CLASS(gdf_json_read_options_parent_class)->dispose(object);
+}
+
+static void
+gdf_json_read_options_set_property(GObject *object,
+                                   guint prop_id,
+                                   const GValue *value,
+                                   GParamSpec *pspec)
+{
+  GDFJSONReadOptionsPrivate *priv =
+    gdf_json_read_options_get_instance_private(GDF_JSON_READ_OPTIONS(object));
+
+  switch (prop_id) {
+  case PROP_SCHEMA_INFER_MAX_N_RECORDS:
+    df_json_read_options_set_schema_infer_max_records(
+      priv->options,
+      g_value_get_uint64(value));
+    break;
+  default:
+    G_OBJECT_WARN_INVALID_PROPERTY_ID(object, prop_id, pspec);
+    break;
+  }
+}
+
+static void
+gdf_json_read_options_get_property(GObject *object,
+                                   guint prop_id,
+                                   GValue *value,
+                                   GParamSpec *pspec)
+{
+  GDFJSONReadOptionsPrivate *priv =
+    gdf_json_read_options_get_instance_private(GDF_JSON_READ_OPTIONS(object));
+
+  switch (prop_id) {
+  case PROP_SCHEMA_INFER_MAX_N_RECORDS:
+    g_value_set_uint64(
+      value,
+      df_json_read_options_get_schema_infer_max_records(priv->options));
+    break;
+  default:
+    G_OBJECT_WARN_INVALID_PROPERTY_ID(object, prop_id, pspec);
+    break;
+  }
+}
+
+static void
+gdf_json_read_options_init(GDFJSONReadOptions *object)
+{
+  GDFJSONReadOptionsPrivate *priv =
+    gdf_json_read_options_get_instance_private(GDF_JSON_READ_OPTIONS(object));
+  priv->options = df_json_read_options_new();
+  priv->schema = NULL;
+  char *extension = df_json_read_options_get_file_extension(priv->options);
+  priv->file_extension = g_strdup(extension);
+  free(extension);
+}
+
+static void
+gdf_json_read_options_class_init(GDFJSONReadOptionsClass *klass)
+{
+  GObjectClass *gobject_class = G_OBJECT_CLASS(klass);
+  gobject_class->finalize = gdf_json_read_options_finalize;
+  gobject_class->dispose = gdf_json_read_options_dispose;
+  gobject_class->set_property = gdf_json_read_options_set_property;
+  gobject_class->get_property = gdf_json_read_options_get_property;
+
+  DFJSONReadOptions *options = df_json_read_options_new();
+
+  GParamSpec *spec;
+  /**
+   * GDFJSONReadOptions:schema-infer-max-n-records:
+   *
+   * The max number of rows to read from JSON files for schema
+   * inference if needed. Defaults to `1000`.
+   */
+  spec = g_param_spec_uint64("schema-infer-max-n-records",
+                             NULL,
+                             NULL,
+                             0,
+                             G_MAXUINT64,
+                             df_json_read_options_get_schema_infer_max_records(options),
+                             G_PARAM_READWRITE);
+  g_object_class_install_property(gobject_class,
+                                  PROP_SCHEMA_INFER_MAX_N_RECORDS,
+                                  spec);
+
+  df_json_read_options_free(options);
+}
+
+/**
+ * gdf_json_read_options_new:
+ *
+ * Returns: A new JSON read options.
+ *
+ * Since: 21.0.0
+ */
+GDFJSONReadOptions *
+gdf_json_read_options_new(void)
+{
+  return g_object_new(GDF_TYPE_JSON_READ_OPTIONS, NULL);
+}
+
+/**
+ * gdf_json_read_options_set_schema:
+ * @options: A #GDFJSONReadOptions.
+ * @schema: (nullable): A #GArrowSchema for read JSON.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: %TRUE on success, %FALSE otherwise.
+ *
+ * Since: 21.0.0
+ */
+gboolean
+gdf_json_read_options_set_schema(GDFJSONReadOptions *options,
+                                 GArrowSchema *schema,
+                                 GError **error)
+{
+  GDFJSONReadOptionsPrivate *priv =
+    gdf_json_read_options_get_instance_private(options);
+  if (priv->schema == schema) {
+    return TRUE;
+  }
+  gpointer c_abi_schema = NULL;
+  if (schema) {
+    c_abi_schema = garrow_schema_export(schema, error);
+    if (!c_abi_schema) {
+      return FALSE;
+    }
+  }
+  DFError *df_error = NULL;
+  bool success =
+    df_json_read_options_set_schema(priv->options, c_abi_schema, &df_error);
+  if (success) {
+    if (priv->schema) {
+      g_object_unref(priv->schema);
+    }
+    if (schema) {
+      priv->schema = g_object_ref(schema);
+    } else {
+      priv->schema = NULL;
+    }
+  } else {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[json-read-options][set-schema] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+  }
+  return success;
+}
+
+/**
+ * gdf_json_read_options_get_schema:
+ * @options: A #GDFJSONReadOptions.
+ *
+ * Returns: (transfer none) (nullable): A #GArrowSchema to be used on
+ *   reading JSON.
+ *
+ * Since: 21.0.0
+ */
+GArrowSchema *
+gdf_json_read_options_get_schema(GDFJSONReadOptions *options)
+{
+  GDFJSONReadOptionsPrivate *priv =
+    gdf_json_read_options_get_instance_private(options);
+  return priv->schema;
+}
+
+/**
+ * gdf_json_read_options_set_file_extension:
+ * @options: A #GDFJSONReadOptions.
+ * @extension: An extension for JSON file.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: %TRUE on success, %FALSE otherwise.
+ *
+ * Since: 21.0.0
+ */
+gboolean
+gdf_json_read_options_set_file_extension(GDFJSONReadOptions *options,
+                                         const gchar *extension,
+                                         GError **error)
+{
+  GDFJSONReadOptionsPrivate *priv =
+    gdf_json_read_options_get_instance_private(options);
+  if (!extension) {
+    g_set_error(error,
+                GDF_ERROR,
+                GDF_ERROR_ARROW,
+                "[json-read-options][set-file-extension] must not NULL");
+    return FALSE;
+  }
+  if (strcmp(priv->file_extension, extension) == 0) {
+    return TRUE;
+  }
+  DFError *df_error = NULL;
+  bool success =
+    df_json_read_options_set_file_extension(priv->options,
+                                            extension,
+                                            &df_error);
+  if (success) {
+    g_free(priv->file_extension);
+    priv->file_extension = g_strdup(extension);
+  } else {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[json-read-options][set-file-extension] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+  }
+  return success;
+}
+
+/**
+ * gdf_json_read_options_get_file_extension:
+ * @options: A #GDFJSONReadOptions.
+ *
+ * Returns: An extension for JSON to be read.
+ *
+ * Since: 21.0.0
+ */
+const gchar *
+gdf_json_read_options_get_file_extension(GDFJSONReadOptions *options)
+{
+  GDFJSONReadOptionsPrivate *priv =
+    gdf_json_read_options_get_instance_private(options);
+  return priv->file_extension;
+}
+
+/**
+ * gdf_json_read_options_set_table_partition_columns:
+ * @options: A #GDFJSONReadOptions.
+ * @schema: Column names and their types that are used for table
+ *   partition.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: %TRUE on success, %FALSE otherwise.
+ *
+ * Since: 21.0.0
+ */
+gboolean
+gdf_json_read_options_set_table_partition_columns(GDFJSONReadOptions *options,
+                                                  GArrowSchema *schema,
+                                                  GError **error)
+{
+  GDFJSONReadOptionsPrivate *priv =
+    gdf_json_read_options_get_instance_private(options);
+  if (priv->table_partition_columns == schema) {
+    return TRUE;
+  }
+  gpointer c_abi_schema = NULL;
+  if (schema) {
+    c_abi_schema = garrow_schema_export(schema, error);
+    if (!c_abi_schema) {
+      return FALSE;
+    }
+  }
+  DFError *df_error = NULL;
+  bool success =
+    df_json_read_options_set_table_partition_columns(priv->options,
+                                                     c_abi_schema,
+                                                     &df_error);
+  if (success) {
+    if (priv->table_partition_columns) {
+      g_object_unref(priv->table_partition_columns);
+    }
+    if (schema) {
+      priv->table_partition_columns = g_object_ref(schema);
+    } else {
+      priv->table_partition_columns = NULL;
+    }
+  } else {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[json-read-options][set-table-partition-columns] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+  }
+  return success;
+}
+
+/**
+ * gdf_json_read_options_get_table_partition_columns:
+ * @options: A #GDFJSONReadOptions.
+ *
+ * Returns: (transfer none) (nullable): The column names and their
+ *   types that are used for table partition.
+ *
+ * Since: 21.0.0
+ */
+GArrowSchema *
+gdf_json_read_options_get_table_partition_columns(GDFJSONReadOptions *options)
+{
+  GDFJSONReadOptionsPrivate *priv =
+    gdf_json_read_options_get_instance_private(options);
+  return priv->table_partition_columns;
+}
+
+/**
+ * gdf_json_read_options_set_file_compression_type:
+ * @options: A #GDFJSONReadOptions.
+ * @type: A #GDFFileCompressionType of JSON files.
+ *
+ * The file extension isn't changed automatically. You may need to
+ * change it too by gdf_json_read_options_set_file_extension() such as
+ * `.json.gz`.
+ *
+ * Since: 21.0.0
+ */
+void
+gdf_json_read_options_set_file_compression_type(
+  GDFJSONReadOptions *options,
+  GDFFileCompressionType type)
+{
+  GDFJSONReadOptionsPrivate *priv =
+    gdf_json_read_options_get_instance_private(options);
+  df_json_read_options_set_file_compression_type(priv->options, type);
+}
+
+/**
+ * gdf_json_read_options_get_file_compression_type:
+ * @options: A #GDFJSONReadOptions.
+ *
+ * Returns: A #GDFFileCompressionType of JSON files to be read.
+ *
+ * Since: 21.0.0
+ */
+GDFFileCompressionType
+gdf_json_read_options_get_file_compression_type(GDFJSONReadOptions *options)
+{
+  GDFJSONReadOptionsPrivate *priv =
+    gdf_json_read_options_get_instance_private(options);
+  return df_json_read_options_get_file_compression_type(priv->options);
+}
+
+DFJSONReadOptions *
+gdf_json_read_options_get_raw(GDFJSONReadOptions *options)
+{
+  GDFJSONReadOptionsPrivate *priv =
+    gdf_json_read_options_get_instance_private(options);
+  return priv->options;
+}
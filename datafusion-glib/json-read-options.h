@@ -0,0 +1,77 @@
+/*
+ * Copyright 2022-2023 Sutou Kouhei <kou@clear-code.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#pragma once
+
+#include <arrow-glib/arrow-glib.h>
+
+#include <datafusion-glib/file-compression-type.h>
+#include <datafusion-glib/version.h>
+
+G_BEGIN_DECLS
+
+#define GDF_TYPE_JSON_READ_OPTIONS (gdf_json_read_options_get_type())
+G_DECLARE_DERIVABLE_TYPE(GDFJSONReadOptions,
+                         gdf_json_read_options,
+                         GDF,
+                         JSON_READ_OPTIONS,
+                         GObject)
+struct _GDFJSONReadOptionsClass
+{
+  GObjectClass parent_class;
+};
+
+GDF_AVAILABLE_IN_21_0
+GDFJSONReadOptions *
+gdf_json_read_options_new(void);
+
+GDF_AVAILABLE_IN_21_0
+gboolean
+gdf_json_read_options_set_schema(GDFJSONReadOptions *options,
+                                 GArrowSchema *schema,
+                                 GError **error);
+GDF_AVAILABLE_IN_21_0
+GArrowSchema *
+gdf_json_read_options_get_schema(GDFJSONReadOptions *options);
+
+GDF_AVAILABLE_IN_21_0
+gboolean
+gdf_json_read_options_set_file_extension(GDFJSONReadOptions *options,
+                                         const gchar *extension,
+                                         GError **error);
+GDF_AVAILABLE_IN_21_0
+const gchar *
+gdf_json_read_options_get_file_extension(GDFJSONReadOptions *options);
+
+GDF_AVAILABLE_IN_21_0
+gboolean
+gdf_json_read_options_set_table_partition_columns(GDFJSONReadOptions *options,
+                                                  GArrowSchema *schema,
+                                                  GError **error);
+GDF_AVAILABLE_IN_21_0
+GArrowSchema *
+gdf_json_read_options_get_table_partition_columns(GDFJSONReadOptions *options);
+
+GDF_AVAILABLE_IN_21_0
+void
+gdf_json_read_options_set_file_compression_type(
+  GDFJSONReadOptions *options,
+  GDFFileCompressionType type);
+GDF_AVAILABLE_IN_21_0
+GDFFileCompressionType
+gdf_json_read_options_get_file_compression_type(GDFJSONReadOptions *options);
+
+G_END_DECLS
@@ -16,6 +16,7 @@
 
 #include <datafusion-glib/parquet-read-options-raw.h>
 #include <datafusion-glib/error.h>
+#include <datafusion-glib/expr-raw.h>
 
 G_BEGIN_DECLS
 
@@ -28,12 +29,14 @@ G_BEGIN_DECLS
 
 typedef struct GDFParquetReadOptionsPrivate_ {
   DFParquetReadOptions *options;
+  GArrowSchema *schema;
   GArrowSchema *table_partition_columns;
   gchar *file_extension;
 } GDFParquetReadOptionsPrivate;
 
 enum {
   PROP_PRUNING = 1,
+  PROP_SKIP_METADATA,
 };
 
 G_DEFINE_TYPE_WITH_PRIVATE(GDFParquetReadOptions,
@@ -57,6 +60,10 @@ gdf_parquet_read_options_dispose(GObject *object)
   GDFParquetReadOptionsPrivate *priv =
     gdf_parquet_read_options_get_instance_private(
       GDF_PARQUET_READ_OPTIONS(object));
+  if (priv->schema) {
+    g_object_unref(priv->schema);
+    priv->schema = NULL;
+  }
   if (priv->table_partition_columns) {
     g_object_unref(priv->table_partition_columns);
     priv->table_partition_columns = NULL;
@@ -79,6 +86,10 @@ gdf_parquet_read_options_set_property(GObject *object,
     df_parquet_read_options_set_pruning(priv->options,
                                         g_value_get_boolean(value));
     break;
+  case PROP_SKIP_METADATA:
+    df_parquet_read_options_set_skip_metadata(priv->options,
+                                              g_value_get_boolean(value));
+    break;
   default:
     G_OBJECT_WARN_INVALID_PROPERTY_ID(object, prop_id, pspec);
     break;
@@ -100,6 +111,11 @@ gdf_parquet_read_options_get_property(GObject *object,
     g_value_set_boolean(value,
                         df_parquet_read_options_get_pruning(priv->options));
     break;
+  case PROP_SKIP_METADATA:
+    g_value_set_boolean(
+      value,
+      df_parquet_read_options_get_skip_metadata(priv->options));
+    break;
   default:
     G_OBJECT_WARN_INVALID_PROPERTY_ID(object, prop_id, pspec);
     break;
@@ -143,6 +159,28 @@ gdf_parquet_read_options_class_init(GDFParquetReadOptionsClass *klass)
                               G_PARAM_READWRITE);
   g_object_class_install_property(gobject_class, PROP_PRUNING, spec);
 
+  /**
+   * GDFParquetReadOptions:skip-metadata:
+   *
+   * Whether metadata embedded in the schema of Parquet files is
+   * skipped or not. Skipping metadata helps to merge schemas of
+   * files that have different metadata. This overrides a
+   * configuration on #GDFSessionContext.
+   *
+   * If this isn't set, this is the default value of the
+   * `datafusion.execution.parquet.skip_metadata` configuration. It's
+   * %TRUE. The configuration of a #GDFSessionContext isn't
+   * considered.
+   *
+   * Since: 21.0.0
+   */
+  spec = g_param_spec_boolean("skip-metadata",
+                              NULL,
+                              NULL,
+                              df_parquet_read_options_get_skip_metadata(options),
+                              G_PARAM_READWRITE);
+  g_object_class_install_property(gobject_class, PROP_SKIP_METADATA, spec);
+
   df_parquet_read_options_free(options);
 }
 
@@ -326,6 +364,155 @@ gdf_parquet_read_options_is_set_pruning(GDFParquetReadOptions *options)
   return df_parquet_read_options_is_set_pruning(priv->options);
 }
 
+/**
+ * gdf_parquet_read_options_set_schema:
+ * @options: A #GDFParquetReadOptions.
+ * @schema: (nullable): A #GArrowSchema for read Parquet. If this is
+ *   %NULL, the schema is inferred from the files.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: %TRUE on success, %FALSE otherwise.
+ *
+ * Since: 21.0.0
+ */
+gboolean
+gdf_parquet_read_options_set_schema(GDFParquetReadOptions *options,
+                                    GArrowSchema *schema,
+                                    GError **error)
+{
+  GDFParquetReadOptionsPrivate *priv =
+    gdf_parquet_read_options_get_instance_private(options);
+  if (priv->schema == schema) {
+    return TRUE;
+  }
+  gpointer c_abi_schema = NULL;
+  if (schema) {
+    c_abi_schema = garrow_schema_export(schema, error);
+    if (!c_abi_schema) {
+      return FALSE;
+    }
+  }
+  DFError *df_error = NULL;
+  bool success =
+    df_parquet_read_options_set_schema(priv->options, c_abi_schema, &df_error);
+  if (success) {
+    if (priv->schema) {
+      g_object_unref(priv->schema);
+    }
+    if (schema) {
+      priv->schema = g_object_ref(schema);
+    } else {
+      priv->schema = NULL;
+    }
+  } else {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[parquet-read-options][set-schema] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+  }
+  return success;
+}
+
+/**
+ * gdf_parquet_read_options_get_schema:
+ * @options: A #GDFParquetReadOptions.
+ *
+ * Returns: (transfer none) (nullable): A #GArrowSchema to be used on
+ *   reading Parquet.
+ *
+ * Since: 21.0.0
+ */
+GArrowSchema *
+gdf_parquet_read_options_get_schema(GDFParquetReadOptions *options)
+{
+  GDFParquetReadOptionsPrivate *priv =
+    gdf_parquet_read_options_get_instance_private(options);
+  return priv->schema;
+}
+
+/**
+ * gdf_parquet_read_options_unset_skip_metadata:
+ * @options: A #GDFParquetReadOptions.
+ *
+ * Unset skip metadata for the options. If the options doesn't have
+ * skip metadata, value in session is used.
+ *
+ * Since: 21.0.0
+ */
+void
+gdf_parquet_read_options_unset_skip_metadata(GDFParquetReadOptions *options)
+{
+  GDFParquetReadOptionsPrivate *priv =
+    gdf_parquet_read_options_get_instance_private(options);
+  df_parquet_read_options_unset_skip_metadata(priv->options);
+}
+
+/**
+ * gdf_parquet_read_options_is_set_skip_metadata:
+ * @options: A #GDFParquetReadOptions.
+ *
+ * Returns: %TRUE when the options have skip metadata value, %FALSE
+ *   otherwise.
+ *
+ * Since: 21.0.0
+ */
+gboolean
+gdf_parquet_read_options_is_set_skip_metadata(GDFParquetReadOptions *options)
+{
+  GDFParquetReadOptionsPrivate *priv =
+    gdf_parquet_read_options_get_instance_private(options);
+  return df_parquet_read_options_is_set_skip_metadata(priv->options);
+}
+
+/**
+ * gdf_parquet_read_options_set_file_sort_order:
+ * @options: A #GDFParquetReadOptions.
+ * @sort_exprs: (array length=n_sort_exprs): Sort keys created by
+ *   gdf_expr_new_sort(). Each sort key must be a column reference.
+ * @n_sort_exprs: The number of @sort_exprs. If this is `0`, the files
+ *   aren't treated as sorted.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Declares the sort order of Parquet files. DataFusion uses this to
+ * avoid redundant sorts.
+ *
+ * Returns: %TRUE on success, %FALSE otherwise.
+ *
+ * Since: 21.0.0
+ */
+gboolean
+gdf_parquet_read_options_set_file_sort_order(GDFParquetReadOptions *options,
+                                             GDFExpr **sort_exprs,
+                                             gsize n_sort_exprs,
+                                             GError **error)
+{
+  GDFParquetReadOptionsPrivate *priv =
+    gdf_parquet_read_options_get_instance_private(options);
+  const DFExpr **df_sort_exprs = g_new(const DFExpr *, n_sort_exprs);
+  gsize i;
+  for (i = 0; i < n_sort_exprs; i++) {
+    df_sort_exprs[i] = gdf_expr_get_raw(sort_exprs[i]);
+  }
+  DFError *df_error = NULL;
+  bool success =
+    df_parquet_read_options_set_file_sort_order(priv->options,
+                                                df_sort_exprs,
+                                                n_sort_exprs,
+                                                &df_error);
+  g_free(df_sort_exprs);
+  if (!success) {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[parquet-read-options][set-file-sort-order] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+  }
+  return success;
+}
+
 DFParquetReadOptions *
 gdf_parquet_read_options_get_raw(GDFParquetReadOptions *options)
 {
@@ -18,6 +18,7 @@
 
 #include <arrow-glib/arrow-glib.h>
 
+#include <datafusion-glib/expr.h>
 #include <datafusion-glib/version.h>
 
 G_BEGIN_DECLS
@@ -65,5 +66,28 @@ GDF_AVAILABLE_IN_21_0
 gboolean
 gdf_parquet_read_options_is_set_pruning(GDFParquetReadOptions *options);
 
+GDF_AVAILABLE_IN_21_0
+gboolean
+gdf_parquet_read_options_set_schema(GDFParquetReadOptions *options,
+                                    GArrowSchema *schema,
+                                    GError **error);
+GDF_AVAILABLE_IN_21_0
+GArrowSchema *
+gdf_parquet_read_options_get_schema(GDFParquetReadOptions *options);
+
+GDF_AVAILABLE_IN_21_0
+void
+gdf_parquet_read_options_unset_skip_metadata(GDFParquetReadOptions *options);
+
+GDF_AVAILABLE_IN_21_0
+gboolean
+gdf_parquet_read_options_is_set_skip_metadata(GDFParquetReadOptions *options);
+
+GDF_AVAILABLE_IN_21_0
+gboolean
+gdf_parquet_read_options_set_file_sort_order(GDFParquetReadOptions *options,
+                                             GDFExpr **sort_exprs,
+                                             gsize n_sort_exprs,
+                                             GError **error);
 
 G_END_DECLS
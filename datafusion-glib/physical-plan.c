@@ -0,0 +1,188 @@
+/*
+ * Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#include <datafusion-glib/error.h>
+#include <datafusion-glib/physical-plan-raw.h>
+
+G_BEGIN_DECLS
+
+/**
+ * SECTION: physical-plan
+ * @include: datafusion-glib/datafusion-glib.h
+ *
+ * #GDFPhysicalPlan is a class for physical plan created by
+ * gdf_data_frame_create_physical_plan(). You can get execution
+ * metrics of each operator after you execute it.
+ */
+
+typedef struct GDFPhysicalPlanPrivate_ {
+  DFPhysicalPlan *plan;
+} GDFPhysicalPlanPrivate;
+
+enum {
+  PROP_PLAN = 1,
+};
+
+G_DEFINE_TYPE_WITH_PRIVATE(GDFPhysicalPlan,
+                           gdf_physical_plan,
+                           G_TYPE_OBJECT)
+
+static void
+gdf_physical_plan_finalize(GObject *object)
+{
+  GDFPhysicalPlanPrivate *priv =
+    gdf_physical_plan_get_instance_private(GDF_PHYSICAL_PLAN(object));
+  df_physical_plan_free(priv->plan);
+  G_OBJECT_CLASS(gdf_physical_plan_parent_class)->finalize(object);
+}
+
+static void
+gdf_physical_plan_set_property(GObject *object,
+                               guint prop_id,
+                               const GValue *value,
+                               GParamSpec *pspec)
+{
+  GDFPhysicalPlanPrivate *priv =
+    gdf_physical_plan_get_instance_private(GDF_PHYSICAL_PLAN(object));
+
+  switch (prop_id) {
+  case PROP_PLAN:
+    priv->plan = g_value_get_pointer(value);
+    break;
+  default:
+    G_OBJECT_WARN_INVALID_PROPERTY_ID(object, prop_id, pspec);
+    break;
+  }
+}
+
+static void
+gdf_physical_plan_init(GDFPhysicalPlan *object)
+{
+}
+
+static void
+gdf_physical_plan_class_init(GDFPhysicalPlanClass *klass)
+{
+  GObjectClass *gobject_class = G_OBJECT_CLASS(klass);
+  gobject_class->finalize = gdf_physical_plan_finalize;
+  gobject_class->set_property = gdf_physical_plan_set_property;
+
+  GParamSpec *spec;
+  spec = g_param_spec_pointer("plan",
+                              "Plan",
+                              "The raw DFPhysicalPlan *",
+                              G_PARAM_WRITABLE | G_PARAM_CONSTRUCT_ONLY);
+  g_object_class_install_property(gobject_class, PROP_PLAN, spec);
+}
+
+/**
+ * gdf_physical_plan_execute_stream:
+ * @plan: A #GDFPhysicalPlan.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Executes @plan as a stream. Execution metrics are collected while
+ * the returned reader is read.
+ *
+ * Returns: (transfer full) (nullable): A #GArrowRecordBatchReader to
+ *   read the result of @plan, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GArrowRecordBatchReader *
+gdf_physical_plan_execute_stream(GDFPhysicalPlan *plan, GError **error)
+{
+  GDFPhysicalPlanPrivate *priv = gdf_physical_plan_get_instance_private(plan);
+  DFArrowArrayStream c_abi_stream;
+  DFError *df_error = NULL;
+  if (!df_physical_plan_execute_stream(priv->plan,
+                                       &c_abi_stream,
+                                       &df_error)) {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[physical-plan][execute-stream] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+    return NULL;
+  }
+  return garrow_record_batch_reader_import(&c_abi_stream, error);
+}
+
+/**
+ * gdf_physical_plan_get_metrics:
+ * @plan: A #GDFPhysicalPlan.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Gets execution metrics of each operator in @plan. Each row is a
+ * metric value of an operator. The returned record batch has the
+ * following columns:
+ *
+ *   * `operator_id` (`uint64`): The ID of the operator. Operators are
+ *     numbered in depth-first pre-order from `0` for the root.
+ *   * `parent_id` (`uint64`): The ID of the parent operator. `NULL`
+ *     for the root.
+ *   * `operator` (`utf8`): The description of the operator.
+ *   * `partition` (`uint64`): The partition of the metric.
+ *   * `name` (`utf8`): The metric name such as `output_rows`.
+ *   * `labels` (`utf8`): The labels of the metric.
+ *   * `value` (`uint64`): The metric value.
+ *
+ * Operators that aren't executed yet don't report metrics.
+ *
+ * Returns: (transfer full) (nullable): A #GArrowRecordBatch that has
+ *   execution metrics, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GArrowRecordBatch *
+gdf_physical_plan_get_metrics(GDFPhysicalPlan *plan, GError **error)
+{
+  GDFPhysicalPlanPrivate *priv = gdf_physical_plan_get_instance_private(plan);
+  DFArrowSchema *c_abi_schema = NULL;
+  DFArrowArray *c_abi_record_batch = NULL;
+  DFError *df_error = NULL;
+  if (!df_physical_plan_export_metrics(priv->plan,
+                                       &c_abi_schema,
+                                       &c_abi_record_batch,
+                                       &df_error)) {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[physical-plan][get-metrics] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+    return NULL;
+  }
+  GArrowSchema *schema = garrow_schema_import(c_abi_schema, error);
+  free(c_abi_schema);
+  if (!schema) {
+    free(c_abi_record_batch);
+    return NULL;
+  }
+  GArrowRecordBatch *record_batch =
+    garrow_record_batch_import(c_abi_record_batch, schema, error);
+  free(c_abi_record_batch);
+  g_object_unref(schema);
+  return record_batch;
+}
+
+GDFPhysicalPlan *
+gdf_physical_plan_new_raw(DFPhysicalPlan *plan)
+{
+  return g_object_new(GDF_TYPE_PHYSICAL_PLAN,
+                      "plan", plan,
+                      NULL);
+}
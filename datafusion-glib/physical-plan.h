@@ -0,0 +1,43 @@
+/*
+ * Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#pragma once
+
+#include <arrow-glib/arrow-glib.h>
+
+#include <datafusion-glib/version.h>
+
+G_BEGIN_DECLS
+
+#define GDF_TYPE_PHYSICAL_PLAN (gdf_physical_plan_get_type())
+G_DECLARE_DERIVABLE_TYPE(GDFPhysicalPlan,
+                         gdf_physical_plan,
+                         GDF,
+                         PHYSICAL_PLAN,
+                         GObject)
+struct _GDFPhysicalPlanClass
+{
+  GObjectClass parent_class;
+};
+
+GDF_AVAILABLE_IN_21_0
+GArrowRecordBatchReader *
+gdf_physical_plan_execute_stream(GDFPhysicalPlan *plan, GError **error);
+GDF_AVAILABLE_IN_21_0
+GArrowRecordBatch *
+gdf_physical_plan_get_metrics(GDFPhysicalPlan *plan, GError **error);
+
+G_END_DECLS
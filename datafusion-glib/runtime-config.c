@@ -0,0 +1,173 @@
+/*
+ * Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#include <datafusion-glib/error.h>
+#include <datafusion-glib/runtime-config-raw.h>
+
+G_BEGIN_DECLS
+
+/**
+ * SECTION: runtime-config
+ * @include: datafusion-glib/datafusion-glib.h
+ *
+ * #GDFRuntimeConfig is a class for runtime environment configuration
+ * of #GDFSessionContext such as memory limit and temporary files.
+ */
+
+typedef struct GDFRuntimeConfigPrivate_ {
+  DFRuntimeConfig *config;
+} GDFRuntimeConfigPrivate;
+
+G_DEFINE_TYPE_WITH_PRIVATE(GDFRuntimeConfig,
+                           gdf_runtime_config,
+                           G_TYPE_OBJECT)
+
+static void
+gdf_runtime_config_finalize(GObject *object)
+{
+  GDFRuntimeConfigPrivate *priv =
+    gdf_runtime_config_get_instance_private(GDF_RUNTIME_CONFIG(object));
+  df_runtime_config_free(priv->config);
+  G_OBJECT_CLASS(gdf_runtime_config_parent_class)->finalize(object);
+}
+
+static void
+gdf_runtime_config_init(GDFRuntimeConfig *object)
+{
+  GDFRuntimeConfigPrivate *priv =
+    gdf_runtime_config_get_instance_private(object);
+  priv->config = df_runtime_config_new();
+}
+
+static void
+gdf_runtime_config_class_init(GDFRuntimeConfigClass *klass)
+{
+  GObjectClass *gobject_class = G_OBJECT_CLASS(klass);
+  gobject_class->finalize = gdf_runtime_config_finalize;
+}
+
+/**
+ * gdf_runtime_config_new:
+ *
+ * Returns: A new runtime config.
+ *
+ * Since: 21.0.0
+ */
+GDFRuntimeConfig *
+gdf_runtime_config_new(void)
+{
+  return g_object_new(GDF_TYPE_RUNTIME_CONFIG, NULL);
+}
+
+/**
+ * gdf_runtime_config_set_greedy_memory_pool:
+ * @config: A #GDFRuntimeConfig.
+ * @pool_size: The max number of bytes for the pool.
+ *
+ * Uses a greedy memory pool. A query that needs more memory than
+ * @pool_size fails with %GDF_ERROR_RESOURCES_EXHAUSTED.
+ *
+ * Each #GDFSessionContext created with @config has its own pool.
+ *
+ * Since: 21.0.0
+ */
+void
+gdf_runtime_config_set_greedy_memory_pool(GDFRuntimeConfig *config,
+                                          gsize pool_size)
+{
+  GDFRuntimeConfigPrivate *priv =
+    gdf_runtime_config_get_instance_private(config);
+  df_runtime_config_set_greedy_memory_pool(priv->config, pool_size);
+}
+
+/**
+ * gdf_runtime_config_set_fair_spill_memory_pool:
+ * @config: A #GDFRuntimeConfig.
+ * @pool_size: The max number of bytes for the pool.
+ *
+ * Uses a fair spill memory pool. Operators that can spill to disk
+ * such as sort spill to disk instead of failing when they reach
+ * their share of @pool_size.
+ *
+ * Each #GDFSessionContext created with @config has its own pool.
+ *
+ * Since: 21.0.0
+ */
+void
+gdf_runtime_config_set_fair_spill_memory_pool(GDFRuntimeConfig *config,
+                                              gsize pool_size)
+{
+  GDFRuntimeConfigPrivate *priv =
+    gdf_runtime_config_get_instance_private(config);
+  df_runtime_config_set_fair_spill_memory_pool(priv->config, pool_size);
+}
+
+/**
+ * gdf_runtime_config_set_temporary_directory:
+ * @config: A #GDFRuntimeConfig.
+ * @path: A path of a directory for temporary files such as spill
+ *   files.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: %TRUE on success, %FALSE otherwise.
+ *
+ * Since: 21.0.0
+ */
+gboolean
+gdf_runtime_config_set_temporary_directory(GDFRuntimeConfig *config,
+                                           const gchar *path,
+                                           GError **error)
+{
+  GDFRuntimeConfigPrivate *priv =
+    gdf_runtime_config_get_instance_private(config);
+  DFError *df_error = NULL;
+  bool success =
+    df_runtime_config_set_temporary_directory(priv->config, path, &df_error);
+  if (!success) {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[runtime-config][set-temporary-directory] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+  }
+  return success;
+}
+
+/**
+ * gdf_runtime_config_disable_disk_manager:
+ * @config: A #GDFRuntimeConfig.
+ *
+ * Disables temporary files. Operators that need to spill to disk
+ * fail instead.
+ *
+ * Since: 21.0.0
+ */
+void
+gdf_runtime_config_disable_disk_manager(GDFRuntimeConfig *config)
+{
+  GDFRuntimeConfigPrivate *priv =
+    gdf_runtime_config_get_instance_private(config);
+  df_runtime_config_disable_disk_manager(priv->config);
+}
+
+DFRuntimeConfig *
+gdf_runtime_config_get_raw(GDFRuntimeConfig *config)
+{
+  GDFRuntimeConfigPrivate *priv =
+    gdf_runtime_config_get_instance_private(config);
+  return priv->config;
+}
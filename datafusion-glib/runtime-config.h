@@ -0,0 +1,59 @@
+/*
+ * Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#pragma once
+
+#include <glib-object.h>
+
+#include <datafusion-glib/version.h>
+
+G_BEGIN_DECLS
+
+#define GDF_TYPE_RUNTIME_CONFIG (gdf_runtime_config_get_type())
+G_DECLARE_DERIVABLE_TYPE(GDFRuntimeConfig,
+                         gdf_runtime_config,
+                         GDF,
+                         RUNTIME_CONFIG,
+                         GObject)
+struct _GDFRuntimeConfigClass
+{
+  GObjectClass parent_class;
+};
+
+GDF_AVAILABLE_IN_21_0
+GDFRuntimeConfig *
+gdf_runtime_config_new(void);
+
+GDF_AVAILABLE_IN_21_0
+void
+gdf_runtime_config_set_greedy_memory_pool(GDFRuntimeConfig *config,
+                                          gsize pool_size);
+GDF_AVAILABLE_IN_21_0
+void
+gdf_runtime_config_set_fair_spill_memory_pool(GDFRuntimeConfig *config,
+                                              gsize pool_size);
+
+GDF_AVAILABLE_IN_21_0
+gboolean
+gdf_runtime_config_set_temporary_directory(GDFRuntimeConfig *config,
+                                           const gchar *path,
+                                           GError **error);
+GDF_AVAILABLE_IN_21_0
+void
+gdf_runtime_config_disable_disk_manager(GDFRuntimeConfig *config);
+
+
+G_END_DECLS
@@ -0,0 +1,471 @@
+/*
+ * Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#include <datafusion-glib/error.h>
+#include <datafusion-glib/session-config-raw.h>
+
+G_BEGIN_DECLS
+
+/**
+ * SECTION: session-config
+ * @include: datafusion-glib/datafusion-glib.h
+ *
+ * #GDFSessionConfig is a class for configuration of
+ * #GDFSessionContext.
+ */
+
+typedef struct GDFSessionConfigPrivate_ {
+  DFSessionConfig *config;
+} GDFSessionConfigPrivate;
+
+enum {
+  PROP_TARGET_PARTITIONS = 1,
+  PROP_BATCH_SIZE,
+  PROP_INFORMATION_SCHEMA,
+  PROP_REPARTITION_JOINS,
+  PROP_REPARTITION_AGGREGATIONS,
+  PROP_REPARTITION_WINDOWS,
+  PROP_REPARTITION_SORTS,
+  PROP_REPARTITION_FILE_SCANS,
+};
+
+G_DEFINE_TYPE_WITH_PRIVATE(GDFSessionConfig,
+                           gdf_session_config,
+                           G_TYPE_OBJECT)
+
+static void
+gdf_session_config_finalize(GObject *object)
+{
+  GDFSessionConfigPrivate *priv =
+    gdf_session_config_get_instance_private(GDF_SESSION_CONFIG(object));
+  df_session_config_free(priv->config);
+  G_OBJECT_CLASS(gdf_session_config_parent_class)->finalize(object);
+}
+
+static void
+gdf_session_config_set_property(GObject *object,
+                                guint prop_id,
+                                const GValue *value,
+                                GParamSpec *pspec)
+{
+  GDFSessionConfigPrivate *priv =
+    gdf_session_config_get_instance_private(GDF_SESSION_CONFIG(object));
+
+  switch (prop_id) {
+  case PROP_TARGET_PARTITIONS:
+    df_session_config_set_target_partitions(priv->config,
+                                            g_value_get_uint64(value));
+    break;
+  case PROP_BATCH_SIZE:
+    df_session_config_set_batch_size(priv->config, g_value_get_uint64(value));
+    break;
+  case PROP_INFORMATION_SCHEMA:
+    df_session_config_set_information_schema(priv->config,
+                                             g_value_get_boolean(value));
+    break;
+  case PROP_REPARTITION_JOINS:
+    df_session_config_set_repartition_joins(priv->config,
+                                            g_value_get_boolean(value));
+    break;
+  case PROP_REPARTITION_AGGREGATIONS:
+    df_session_config_set_repartition_aggregations(priv->config,
+                                                   g_value_get_boolean(value));
+    break;
+  case PROP_REPARTITION_WINDOWS:
+    df_session_config_set_repartition_windows(priv->config,
+                                              g_value_get_boolean(value));
+    break;
+  case PROP_REPARTITION_SORTS:
+    df_session_config_set_repartition_sorts(priv->config,
+                                            g_value_get_boolean(value));
+    break;
+  case PROP_REPARTITION_FILE_SCANS:
+    df_session_config_set_repartition_file_scans(priv->config,
+                                                 g_value_get_boolean(value));
+    break;
+  default:
+    G_OBJECT_WARN_INVALID_PROPERTY_ID(object, prop_id, pspec);
+    break;
+  }
+}
+
+static void
+gdf_session_config_get_property(GObject *object,
+                                guint prop_id,
+                                GValue *value,
+                                GParamSpec *pspec)
+{
+  GDFSessionConfigPrivate *priv =
+    gdf_session_config_get_instance_private(GDF_SESSION_CONFIG(object));
+
+  switch (prop_id) {
+  case PROP_TARGET_PARTITIONS:
+    g_value_set_uint64(value,
+                       df_session_config_get_target_partitions(priv->config));
+    break;
+  case PROP_BATCH_SIZE:
+    g_value_set_uint64(value, df_session_config_get_batch_size(priv->config));
+    break;
+  case PROP_INFORMATION_SCHEMA:
+    g_value_set_boolean(
+      value,
+      df_session_config_get_information_schema(priv->config));
+    break;
+  case PROP_REPARTITION_JOINS:
+    g_value_set_boolean(value,
+                        df_session_config_get_repartition_joins(priv->config));
+    break;
+  case PROP_REPARTITION_AGGREGATIONS:
+    g_value_set_boolean(
+      value,
+      df_session_config_get_repartition_aggregations(priv->config));
+    break;
+  case PROP_REPARTITION_WINDOWS:
+    g_value_set_boolean(
+      value,
+      df_session_config_get_repartition_windows(priv->config));
+    break;
+  case PROP_REPARTITION_SORTS:
+    g_value_set_boolean(value,
+                        df_session_config_get_repartition_sorts(priv->config));
+    break;
+  case PROP_REPARTITION_FILE_SCANS:
+    g_value_set_boolean(
+      value,
+      df_session_config_get_repartition_file_scans(priv->config));
+    break;
+  default:
+    G_OBJECT_WARN_INVALID_PROPERTY_ID(object, prop_id, pspec);
+    break;
+  }
+}
+
+static void
+gdf_session_config_init(GDFSessionConfig *object)
+{
+  GDFSessionConfigPrivate *priv =
+    gdf_session_config_get_instance_private(object);
+  priv->config = df_session_config_new();
+}
+
+static void
+gdf_session_config_class_init(GDFSessionConfigClass *klass)
+{
+  GObjectClass *gobject_class = G_OBJECT_CLASS(klass);
+  gobject_class->finalize = gdf_session_config_finalize;
+  gobject_class->set_property = gdf_session_config_set_property;
+  gobject_class->get_property = gdf_session_config_get_property;
+
+  DFSessionConfig *config = df_session_config_new();
+
+  GParamSpec *spec;
+  /**
+   * GDFSessionConfig:target-partitions:
+   *
+   * The number of partitions for query execution. `0` uses the
+   * number of CPU cores.
+   *
+   * Since: 21.0.0
+   */
+  spec = g_param_spec_uint64("target-partitions",
+                             NULL,
+                             NULL,
+                             0,
+                             G_MAXUINT64,
+                             df_session_config_get_target_partitions(config),
+                             G_PARAM_READWRITE);
+  g_object_class_install_property(gobject_class, PROP_TARGET_PARTITIONS, spec);
+
+  /**
+   * GDFSessionConfig:batch-size:
+   *
+   * The default batch size while creating new batches. `0` uses the
+   * default value. Defaults to `8192`.
+   *
+   * Since: 21.0.0
+   */
+  spec = g_param_spec_uint64("batch-size",
+                             NULL,
+                             NULL,
+                             0,
+                             G_MAXUINT64,
+                             df_session_config_get_batch_size(config),
+                             G_PARAM_READWRITE);
+  g_object_class_install_property(gobject_class, PROP_BATCH_SIZE, spec);
+
+  /**
+   * GDFSessionConfig:information-schema:
+   *
+   * Whether `information_schema` virtual tables are available or not.
+   *
+   * Since: 21.0.0
+   */
+  spec = g_param_spec_boolean("information-schema",
+                              NULL,
+                              NULL,
+                              df_session_config_get_information_schema(config),
+                              G_PARAM_READWRITE);
+  g_object_class_install_property(gobject_class,
+                                  PROP_INFORMATION_SCHEMA,
+                                  spec);
+
+  /**
+   * GDFSessionConfig:repartition-joins:
+   *
+   * Whether joins are repartitioned to increase parallelism or not.
+   *
+   * Since: 21.0.0
+   */
+  spec = g_param_spec_boolean("repartition-joins",
+                              NULL,
+                              NULL,
+                              df_session_config_get_repartition_joins(config),
+                              G_PARAM_READWRITE);
+  g_object_class_install_property(gobject_class,
+                                  PROP_REPARTITION_JOINS,
+                                  spec);
+
+  /**
+   * GDFSessionConfig:repartition-aggregations:
+   *
+   * Whether aggregations are repartitioned to increase parallelism
+   * or not.
+   *
+   * Since: 21.0.0
+   */
+  spec = g_param_spec_boolean(
+    "repartition-aggregations",
+    NULL,
+    NULL,
+    df_session_config_get_repartition_aggregations(config),
+    G_PARAM_READWRITE);
+  g_object_class_install_property(gobject_class,
+                                  PROP_REPARTITION_AGGREGATIONS,
+                                  spec);
+
+  /**
+   * GDFSessionConfig:repartition-windows:
+   *
+   * Whether window functions are repartitioned to increase
+   * parallelism or not.
+   *
+   * Since: 21.0.0
+   */
+  spec = g_param_spec_boolean("repartition-windows",
+                              NULL,
+                              NULL,
+                              df_session_config_get_repartition_windows(config),
+                              G_PARAM_READWRITE);
+  g_object_class_install_property(gobject_class,
+                                  PROP_REPARTITION_WINDOWS,
+                                  spec);
+
+  /**
+   * GDFSessionConfig:repartition-sorts:
+   *
+   * Whether sorts are executed in parallel per partition or not.
+   *
+   * Since: 21.0.0
+   */
+  spec = g_param_spec_boolean("repartition-sorts",
+                              NULL,
+                              NULL,
+                              df_session_config_get_repartition_sorts(config),
+                              G_PARAM_READWRITE);
+  g_object_class_install_property(gobject_class,
+                                  PROP_REPARTITION_SORTS,
+                                  spec);
+
+  /**
+   * GDFSessionConfig:repartition-file-scans:
+   *
+   * Whether file scans are repartitioned to increase parallelism or
+   * not.
+   *
+   * Since: 21.0.0
+   */
+  spec = g_param_spec_boolean(
+    "repartition-file-scans",
+    NULL,
+    NULL,
+    df_session_config_get_repartition_file_scans(config),
+    G_PARAM_READWRITE);
+  g_object_class_install_property(gobject_class,
+                                  PROP_REPARTITION_FILE_SCANS,
+                                  spec);
+
+  df_session_config_free(config);
+}
+
+/**
+ * gdf_session_config_new:
+ *
+ * Returns: A new session config.
+ *
+ * Since: 21.0.0
+ */
+GDFSessionConfig *
+gdf_session_config_new(void)
+{
+  return g_object_new(GDF_TYPE_SESSION_CONFIG, NULL);
+}
+
+/**
+ * gdf_session_config_set_default_catalog_and_schema:
+ * @config: A #GDFSessionConfig.
+ * @catalog: A name of the default catalog.
+ * @schema: A name of the default schema.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: %TRUE on success, %FALSE otherwise.
+ *
+ * Since: 21.0.0
+ */
+gboolean
+gdf_session_config_set_default_catalog_and_schema(GDFSessionConfig *config,
+                                                  const gchar *catalog,
+                                                  const gchar *schema,
+                                                  GError **error)
+{
+  GDFSessionConfigPrivate *priv =
+    gdf_session_config_get_instance_private(config);
+  DFError *df_error = NULL;
+  bool success =
+    df_session_config_set_default_catalog_and_schema(priv->config,
+                                                     catalog,
+                                                     schema,
+                                                     &df_error);
+  if (!success) {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[session-config][set-default-catalog-and-schema] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+  }
+  return success;
+}
+
+static gchar *
+gdf_session_config_dup_string(char *df_string)
+{
+  if (!df_string) {
+    return NULL;
+  }
+  gchar *string = g_strdup(df_string);
+  free(df_string);
+  return string;
+}
+
+/**
+ * gdf_session_config_get_default_catalog:
+ * @config: A #GDFSessionConfig.
+ *
+ * Returns: The name of the default catalog.
+ *
+ *   It should be freed with g_free() when no longer needed.
+ *
+ * Since: 21.0.0
+ */
+gchar *
+gdf_session_config_get_default_catalog(GDFSessionConfig *config)
+{
+  GDFSessionConfigPrivate *priv =
+    gdf_session_config_get_instance_private(config);
+  return gdf_session_config_dup_string(
+    df_session_config_get_default_catalog(priv->config));
+}
+
+/**
+ * gdf_session_config_get_default_schema:
+ * @config: A #GDFSessionConfig.
+ *
+ * Returns: The name of the default schema.
+ *
+ *   It should be freed with g_free() when no longer needed.
+ *
+ * Since: 21.0.0
+ */
+gchar *
+gdf_session_config_get_default_schema(GDFSessionConfig *config)
+{
+  GDFSessionConfigPrivate *priv =
+    gdf_session_config_get_instance_private(config);
+  return gdf_session_config_dup_string(
+    df_session_config_get_default_schema(priv->config));
+}
+
+/**
+ * gdf_session_config_set:
+ * @config: A #GDFSessionConfig.
+ * @key: A key of the option such as `datafusion.execution.batch_size`.
+ * @value: A value of the option as string.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: %TRUE on success, %FALSE otherwise.
+ *
+ * Since: 21.0.0
+ */
+gboolean
+gdf_session_config_set(GDFSessionConfig *config,
+                       const gchar *key,
+                       const gchar *value,
+                       GError **error)
+{
+  GDFSessionConfigPrivate *priv =
+    gdf_session_config_get_instance_private(config);
+  DFError *df_error = NULL;
+  bool success = df_session_config_set(priv->config, key, value, &df_error);
+  if (!success) {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[session-config][set] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+  }
+  return success;
+}
+
+/**
+ * gdf_session_config_get:
+ * @config: A #GDFSessionConfig.
+ * @key: A key of the option such as `datafusion.execution.batch_size`.
+ *
+ * Returns: (nullable): The value of the option as string, %NULL if
+ *   the option doesn't exist or doesn't have value.
+ *
+ *   It should be freed with g_free() when no longer needed.
+ *
+ * Since: 21.0.0
+ */
+gchar *
+gdf_session_config_get(GDFSessionConfig *config,
+                       const gchar *key)
+{
+  GDFSessionConfigPrivate *priv =
+    gdf_session_config_get_instance_private(config);
+  return gdf_session_config_dup_string(
+    df_session_config_get(priv->config, key));
+}
+
+DFSessionConfig *
+gdf_session_config_get_raw(GDFSessionConfig *config)
+{
+  GDFSessionConfigPrivate *priv =
+    gdf_session_config_get_instance_private(config);
+  return priv->config;
+}
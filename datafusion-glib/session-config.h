@@ -0,0 +1,65 @@
+/*
+ * Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#pragma once
+
+#include <glib-object.h>
+
+#include <datafusion-glib/version.h>
+
+G_BEGIN_DECLS
+
+#define GDF_TYPE_SESSION_CONFIG (gdf_session_config_get_type())
+G_DECLARE_DERIVABLE_TYPE(GDFSessionConfig,
+                         gdf_session_config,
+                         GDF,
+                         SESSION_CONFIG,
+                         GObject)
+struct _GDFSessionConfigClass
+{
+  GObjectClass parent_class;
+};
+
+GDF_AVAILABLE_IN_21_0
+GDFSessionConfig *
+gdf_session_config_new(void);
+
+GDF_AVAILABLE_IN_21_0
+gboolean
+gdf_session_config_set_default_catalog_and_schema(GDFSessionConfig *config,
+                                                  const gchar *catalog,
+                                                  const gchar *schema,
+                                                  GError **error);
+GDF_AVAILABLE_IN_21_0
+gchar *
+gdf_session_config_get_default_catalog(GDFSessionConfig *config);
+GDF_AVAILABLE_IN_21_0
+gchar *
+gdf_session_config_get_default_schema(GDFSessionConfig *config);
+
+GDF_AVAILABLE_IN_21_0
+gboolean
+gdf_session_config_set(GDFSessionConfig *config,
+                       const gchar *key,
+                       const gchar *value,
+                       GError **error);
+GDF_AVAILABLE_IN_21_0
+gchar *
+gdf_session_config_get(GDFSessionConfig *config,
+                       const gchar *key);
+
+
+G_END_DECLS
@@ -25,5 +25,8 @@ G_BEGIN_DECLS
 GDF_AVAILABLE_IN_10_0
 GDFSessionContext *
 gdf_session_context_new_raw(DFSessionContext *raw_context);
+GDF_AVAILABLE_IN_21_0
+DFSessionContext *
+gdf_session_context_get_raw(GDFSessionContext *context);
 
 G_END_DECLS
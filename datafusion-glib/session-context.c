@@ -14,11 +14,19 @@
  * limitations under the License.
  */
 
+#include <datafusion-glib/accumulator.h>
+#include <datafusion-glib/avro-read-options-raw.h>
+#include <datafusion-glib/cancellation-token-raw.h>
 #include <datafusion-glib/csv-read-options-raw.h>
 #include <datafusion-glib/data-frame-raw.h>
 #include <datafusion-glib/error.h>
+#include <datafusion-glib/json-read-options-raw.h>
 #include <datafusion-glib/parquet-read-options-raw.h>
+#include <datafusion-glib/runtime-config-raw.h>
+#include <datafusion-glib/session-config-raw.h>
 #include <datafusion-glib/session-context-raw.h>
+#include <datafusion-glib/signature-raw.h>
+#include <datafusion-glib/tokio-runtime-raw.h>
 
 G_BEGIN_DECLS
 
@@ -130,6 +138,136 @@ gdf_session_context_new(void)
   return gdf_session_context_new_raw(df_session_context_new());
 }
 
+/**
+ * gdf_session_context_new_with_config:
+ * @config: A #GDFSessionConfig.
+ *
+ * Returns: A new session context that uses the given configuration.
+ *
+ * Since: 21.0.0
+ */
+GDFSessionContext *
+gdf_session_context_new_with_config(GDFSessionConfig *config)
+{
+  DFSessionConfig *df_config = gdf_session_config_get_raw(config);
+  return gdf_session_context_new_raw(
+    df_session_context_new_with_config(df_config));
+}
+
+/**
+ * gdf_session_context_new_with_runtime_config:
+ * @config: (nullable): A #GDFSessionConfig.
+ * @runtime_config: A #GDFRuntimeConfig.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * The new session context has its own memory pool. Memory used by
+ * other session contexts created with the same @runtime_config isn't
+ * counted.
+ *
+ * Returns: (nullable): A new session context that uses the given
+ *   configurations, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFSessionContext *
+gdf_session_context_new_with_runtime_config(GDFSessionConfig *config,
+                                            GDFRuntimeConfig *runtime_config,
+                                            GError **error)
+{
+  DFSessionConfig *df_config = NULL;
+  if (config) {
+    df_config = gdf_session_config_get_raw(config);
+  }
+  DFRuntimeConfig *df_runtime_config =
+    gdf_runtime_config_get_raw(runtime_config);
+  DFError *df_error = NULL;
+  DFSessionContext *context =
+    df_session_context_new_with_runtime_config(df_config,
+                                               df_runtime_config,
+                                               &df_error);
+  if (!context) {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[session-context][new-with-runtime-config] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+    return NULL;
+  }
+  return gdf_session_context_new_raw(context);
+}
+
+/**
+ * gdf_session_context_set_tokio_runtime:
+ * @context: A #GDFSessionContext.
+ * @runtime: (nullable): A #GDFTokioRuntime or %NULL.
+ *
+ * Sets a Tokio runtime that executes queries of @context. Data frames
+ * created by @context use @runtime too.
+ *
+ * If @runtime is %NULL, the process-wide default runtime is used.
+ *
+ * Since: 21.0.0
+ */
+void
+gdf_session_context_set_tokio_runtime(GDFSessionContext *context,
+                                      GDFTokioRuntime *runtime)
+{
+  GDFSessionContextPrivate *priv =
+    gdf_session_context_get_instance_private(context);
+  DFTokioRuntime *raw_runtime = NULL;
+  if (runtime) {
+    raw_runtime = gdf_tokio_runtime_get_raw(runtime);
+  }
+  df_session_context_set_tokio_runtime(priv->context, raw_runtime);
+}
+
+/**
+ * gdf_session_context_set_cancellation_token:
+ * @context: A #GDFSessionContext.
+ * @token: (nullable): A #GDFCancellationToken or %NULL.
+ *
+ * Sets a token to cancel queries of @context. Data frames created by
+ * @context use @token too.
+ *
+ * If @token is %NULL, queries of @context can't be cancelled.
+ *
+ * Since: 21.0.0
+ */
+void
+gdf_session_context_set_cancellation_token(GDFSessionContext *context,
+                                           GDFCancellationToken *token)
+{
+  GDFSessionContextPrivate *priv =
+    gdf_session_context_get_instance_private(context);
+  DFCancellationToken *raw_token = NULL;
+  if (token) {
+    raw_token = gdf_cancellation_token_get_raw(token);
+  }
+  df_session_context_set_cancellation_token(priv->context, raw_token);
+}
+
+/**
+ * gdf_session_context_set_timeout:
+ * @context: A #GDFSessionContext.
+ * @timeout_milliseconds: The timeout in milliseconds. `0` means no
+ *   timeout.
+ *
+ * Sets a timeout for SQL planning and each execution of @context.
+ * Timed out executions report %GDF_ERROR_TIMEOUT. Data frames
+ * created by @context use the timeout too.
+ *
+ * Since: 21.0.0
+ */
+void
+gdf_session_context_set_timeout(GDFSessionContext *context,
+                                guint64 timeout_milliseconds)
+{
+  GDFSessionContextPrivate *priv =
+    gdf_session_context_get_instance_private(context);
+  df_session_context_set_timeout(priv->context, timeout_milliseconds);
+}
+
 /**
  * gdf_session_context_sql:
  * @context: A #GDFSessionContext.
@@ -164,6 +302,99 @@ gdf_session_context_sql(GDFSessionContext *context,
   }
 }
 
+static void
+gdf_session_context_sql_async_callback(DFDataFrame *data_frame,
+                                       DFError *df_error,
+                                       gpointer user_data)
+{
+  GTask *task = user_data;
+  gdf_cancellable_link_disconnect(g_task_get_task_data(task));
+  if (df_error) {
+    g_task_return_new_error(task,
+                            GDF_ERROR,
+                            df_error_get_code(df_error),
+                            "[session-context][sql-async] %s",
+                            df_error_get_message(df_error));
+    df_error_free(df_error);
+  } else {
+    g_task_return_pointer(task,
+                          gdf_data_frame_new_raw(data_frame),
+                          g_object_unref);
+  }
+}
+
+/**
+ * gdf_session_context_sql_async:
+ * @context: A #GDFSessionContext.
+ * @sql: A SQL to be ran.
+ * @cancellable: (nullable): A #GCancellable or %NULL.
+ * @callback: (scope async): A #GAsyncReadyCallback to call when the
+ *   SQL is processed.
+ * @user_data: (closure): Data to pass to @callback.
+ *
+ * Processes @sql asynchronously without blocking the caller thread.
+ * Call gdf_session_context_sql_finish() in @callback to get the
+ * result.
+ *
+ * If @cancellable is cancelled, the running query is cancelled and
+ * gdf_session_context_sql_finish() reports a %GDF_ERROR_CANCELLED
+ * error.
+ *
+ * Since: 21.0.0
+ */
+void
+gdf_session_context_sql_async(GDFSessionContext *context,
+                              const gchar *sql,
+                              GCancellable *cancellable,
+                              GAsyncReadyCallback callback,
+                              gpointer user_data)
+{
+  GDFSessionContextPrivate *priv =
+    gdf_session_context_get_instance_private(context);
+  GTask *task = g_task_new(context, cancellable, callback, user_data);
+  /* Report GDF_ERROR_CANCELLED instead of G_IO_ERROR_CANCELLED. */
+  g_task_set_check_cancellable(task, FALSE);
+  GDFCancellableLink *link = gdf_cancellable_link_new(cancellable);
+  g_task_set_task_data(task, link, (GDestroyNotify)gdf_cancellable_link_free);
+  DFError *df_error = NULL;
+  if (!df_session_context_sql_async(priv->context,
+                                    sql,
+                                    link->token,
+                                    gdf_session_context_sql_async_callback,
+                                    g_object_ref(task),
+                                    g_object_unref,
+                                    &df_error)) {
+    g_task_return_new_error(task,
+                            GDF_ERROR,
+                            df_error_get_code(df_error),
+                            "[session-context][sql-async] %s",
+                            df_error_get_message(df_error));
+    df_error_free(df_error);
+  }
+  g_object_unref(task);
+}
+
+/**
+ * gdf_session_context_sql_finish:
+ * @context: A #GDFSessionContext.
+ * @result: A #GAsyncResult passed to the callback of
+ *   gdf_session_context_sql_async().
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (transfer full) (nullable): The executed result as
+ *   #GDFDataFrame, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFDataFrame *
+gdf_session_context_sql_finish(GDFSessionContext *context,
+                               GAsyncResult *result,
+                               GError **error)
+{
+  g_return_val_if_fail(g_task_is_valid(result, context), NULL);
+  return g_task_propagate_pointer(G_TASK(result), error);
+}
+
 static void
 df_arrow_schema_release(DFArrowSchema *schema)
 {
@@ -371,6 +602,57 @@ exit:
   return success;
 }
 
+/**
+ * gdf_session_context_register_record_batch_reader:
+ * @context: A #GDFSessionContext.
+ * @name: A name for the reader in the context.
+ * @reader: A record batch reader to be registered.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Registers a table that reads record batches from @reader on
+ * demand. The registered table can be scanned only once because
+ * @reader can't be rewound. The second scan reports
+ * %GDF_ERROR_EXECUTION.
+ *
+ * Returns: %TRUE on success, %FALSE otherwise.
+ *
+ * Since: 21.0.0
+ */
+gboolean
+gdf_session_context_register_record_batch_reader(
+  GDFSessionContext *context,
+  const gchar *name,
+  GArrowRecordBatchReader *reader,
+  GError **error)
+{
+  GDFSessionContextPrivate *priv =
+    gdf_session_context_get_instance_private(context);
+  gpointer c_abi_stream = garrow_record_batch_reader_export(reader, error);
+  if (!c_abi_stream) {
+    return FALSE;
+  }
+  DFError *df_error = NULL;
+  bool success =
+    df_session_context_register_record_batch_stream(priv->context,
+                                                    name,
+                                                    c_abi_stream,
+                                                    &df_error);
+  if (!success) {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[session-context][register-record-batch-reader] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+    DFArrowArrayStream *stream = c_abi_stream;
+    if (stream->release) {
+      stream->release(stream);
+    }
+  }
+  g_free(c_abi_stream);
+  return success;
+}
+
 /**
  * gdf_session_context_register_csv:
  * @context: A #GDFSessionContext.
@@ -414,6 +696,48 @@ gdf_session_context_register_csv(GDFSessionContext *context,
   return success;
 }
 
+/**
+ * gdf_session_context_read_csv:
+ * @context: A #GDFSessionContext.
+ * @url: An URL of the CSV to be read.
+ * @options: (nullable): A #GDFCSVReadOptions.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Reads CSV without registering it as a table.
+ *
+ * Returns: (transfer full) (nullable): A #GDFDataFrame for the CSV,
+ *   %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFDataFrame *
+gdf_session_context_read_csv(GDFSessionContext *context,
+                             const gchar *url,
+                             GDFCSVReadOptions *options,
+                             GError **error)
+{
+  GDFSessionContextPrivate *priv =
+    gdf_session_context_get_instance_private(context);
+  DFCSVReadOptions *df_options = NULL;
+  if (options) {
+    df_options = gdf_csv_read_options_get_raw(options);
+  }
+  DFError *df_error = NULL;
+  DFDataFrame *data_frame =
+    df_session_context_read_csv(priv->context, url, df_options, &df_error);
+  if (df_error) {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[session-context][read-csv] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+    return NULL;
+  } else {
+    return gdf_data_frame_new_raw(data_frame);
+  }
+}
+
 /**
  * gdf_session_context_register_parquet:
  * @context: A #GDFSessionContext.
@@ -457,6 +781,626 @@ gdf_session_context_register_parquet(GDFSessionContext *context,
   return success;
 }
 
+/**
+ * gdf_session_context_read_parquet:
+ * @context: A #GDFSessionContext.
+ * @url: An URL of the Apache Parquet to be read.
+ * @options: (nullable): A #GDFParquetReadOptions.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Reads Apache Parquet without registering it as a table.
+ *
+ * Returns: (transfer full) (nullable): A #GDFDataFrame for the
+ *   Apache Parquet, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFDataFrame *
+gdf_session_context_read_parquet(GDFSessionContext *context,
+                                 const gchar *url,
+                                 GDFParquetReadOptions *options,
+                                 GError **error)
+{
+  GDFSessionContextPrivate *priv =
+    gdf_session_context_get_instance_private(context);
+  DFParquetReadOptions *df_options = NULL;
+  if (options) {
+    df_options = gdf_parquet_read_options_get_raw(options);
+  }
+  DFError *df_error = NULL;
+  DFDataFrame *data_frame =
+    df_session_context_read_parquet(priv->context, url, df_options, &df_error);
+  if (df_error) {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[session-context][read-parquet] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+    return NULL;
+  } else {
+    return gdf_data_frame_new_raw(data_frame);
+  }
+}
+
+/**
+ * gdf_session_context_register_json:
+ * @context: A #GDFSessionContext.
+ * @name: A name for the newline-delimited JSON in the context.
+ * @url: An URL of the newline-delimited JSON to be registered.
+ * @options: (nullable): A #GDFJSONReadOptions.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: %TRUE on success, %FALSE otherwise.
+ *
+ * Since: 21.0.0
+ */
+gboolean
+gdf_session_context_register_json(GDFSessionContext *context,
+                                  const gchar *name,
+                                  const gchar *url,
+                                  GDFJSONReadOptions *options,
+                                  GError **error)
+{
+  GDFSessionContextPrivate *priv =
+    gdf_session_context_get_instance_private(context);
+  DFJSONReadOptions *df_options = NULL;
+  if (options) {
+    df_options = gdf_json_read_options_get_raw(options);
+  }
+  DFError *df_error = NULL;
+  bool success =
+    df_session_context_register_json(priv->context,
+                                     name,
+                                     url,
+                                     df_options,
+                                     &df_error);
+  if (!success) {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[session-context][register-json] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+  }
+  return success;
+}
+
+/**
+ * gdf_session_context_read_json:
+ * @context: A #GDFSessionContext.
+ * @url: An URL of the newline-delimited JSON to be read.
+ * @options: (nullable): A #GDFJSONReadOptions.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Reads newline-delimited JSON without registering it as a table.
+ *
+ * Returns: (transfer full) (nullable): A #GDFDataFrame for the JSON,
+ *   %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFDataFrame *
+gdf_session_context_read_json(GDFSessionContext *context,
+                              const gchar *url,
+                              GDFJSONReadOptions *options,
+                              GError **error)
+{
+  GDFSessionContextPrivate *priv =
+    gdf_session_context_get_instance_private(context);
+  DFJSONReadOptions *df_options = NULL;
+  if (options) {
+    df_options = gdf_json_read_options_get_raw(options);
+  }
+  DFError *df_error = NULL;
+  DFDataFrame *data_frame =
+    df_session_context_read_json(priv->context, url, df_options, &df_error);
+  if (df_error) {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[session-context][read-json] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+    return NULL;
+  } else {
+    return gdf_data_frame_new_raw(data_frame);
+  }
+}
+
+/**
+ * gdf_session_context_register_avro:
+ * @context: A #GDFSessionContext.
+ * @name: A name for the Apache Avro in the context.
+ * @url: An URL of the Apache Avro to be registered.
+ * @options: (nullable): A #GDFAvroReadOptions.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: %TRUE on success, %FALSE otherwise.
+ *
+ * Since: 21.0.0
+ */
+gboolean
+gdf_session_context_register_avro(GDFSessionContext *context,
+                                  const gchar *name,
+                                  const gchar *url,
+                                  GDFAvroReadOptions *options,
+                                  GError **error)
+{
+  GDFSessionContextPrivate *priv =
+    gdf_session_context_get_instance_private(context);
+  DFAvroReadOptions *df_options = NULL;
+  if (options) {
+    df_options = gdf_avro_read_options_get_raw(options);
+  }
+  DFError *df_error = NULL;
+  bool success =
+    df_session_context_register_avro(priv->context,
+                                     name,
+                                     url,
+                                     df_options,
+                                     &df_error);
+  if (!success) {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[session-context][register-avro] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+  }
+  return success;
+}
+
+/**
+ * gdf_session_context_read_avro:
+ * @context: A #GDFSessionContext.
+ * @url: An URL of the Apache Avro to be read.
+ * @options: (nullable): A #GDFAvroReadOptions.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Reads Apache Avro without registering it as a table.
+ *
+ * Returns: (transfer full) (nullable): A #GDFDataFrame for the
+ *   Apache Avro, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFDataFrame *
+gdf_session_context_read_avro(GDFSessionContext *context,
+                              const gchar *url,
+                              GDFAvroReadOptions *options,
+                              GError **error)
+{
+  GDFSessionContextPrivate *priv =
+    gdf_session_context_get_instance_private(context);
+  DFAvroReadOptions *df_options = NULL;
+  if (options) {
+    df_options = gdf_avro_read_options_get_raw(options);
+  }
+  DFError *df_error = NULL;
+  DFDataFrame *data_frame =
+    df_session_context_read_avro(priv->context, url, df_options, &df_error);
+  if (df_error) {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[session-context][read-avro] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+    return NULL;
+  } else {
+    return gdf_data_frame_new_raw(data_frame);
+  }
+}
+
+static DFError *
+gdf_session_context_udf_error(GError *error)
+{
+  DFErrorCode code = DF_ERROR_CODE_EXTERNAL;
+  if (error->domain == GDF_ERROR) {
+    code = error->code;
+  }
+  return df_error_new(code, error->message);
+}
+
+typedef struct GDFScalarUDFData_ {
+  GDFScalarUDFFunc func;
+  gpointer user_data;
+  GDestroyNotify destroy_notify;
+} GDFScalarUDFData;
+
+static void
+gdf_scalar_udf_data_free(void *data)
+{
+  GDFScalarUDFData *udf_data = data;
+  if (udf_data->destroy_notify) {
+    udf_data->destroy_notify(udf_data->user_data);
+  }
+  g_free(udf_data);
+}
+
+static bool
+gdf_scalar_udf_function(DFArrowSchema *args_schema,
+                        DFArrowArray *args,
+                        DFArrowArray *result,
+                        void *user_data,
+                        DFError **df_error)
+{
+  GDFScalarUDFData *udf_data = user_data;
+  GError *error = NULL;
+  GArrowSchema *schema = garrow_schema_import(args_schema, &error);
+  if (!schema) {
+    *df_error = gdf_session_context_udf_error(error);
+    g_error_free(error);
+    return false;
+  }
+  GArrowRecordBatch *record_batch =
+    garrow_record_batch_import(args, schema, &error);
+  g_object_unref(schema);
+  if (!record_batch) {
+    *df_error = gdf_session_context_udf_error(error);
+    g_error_free(error);
+    return false;
+  }
+  GArrowArray *array =
+    udf_data->func(record_batch, udf_data->user_data, &error);
+  g_object_unref(record_batch);
+  if (!array) {
+    if (error) {
+      *df_error = gdf_session_context_udf_error(error);
+      g_error_free(error);
+    } else {
+      *df_error = df_error_new(DF_ERROR_CODE_EXECUTION,
+                               "scalar UDF didn't return an array");
+    }
+    return false;
+  }
+  gpointer c_abi_array = NULL;
+  gboolean success = garrow_array_export(array, &c_abi_array, NULL, &error);
+  g_object_unref(array);
+  if (!success) {
+    *df_error = gdf_session_context_udf_error(error);
+    g_error_free(error);
+    return false;
+  }
+  *result = *((DFArrowArray *)c_abi_array);
+  g_free(c_abi_array);
+  return true;
+}
+
+/**
+ * gdf_session_context_register_scalar_udf:
+ * @context: A #GDFSessionContext.
+ * @name: A name of the UDF.
+ * @signature: Acceptable arguments of the UDF.
+ * @return_type: The return type of the UDF.
+ * @func: (scope notified) (closure user_data) (destroy destroy_notify):
+ *   A callback that computes the UDF.
+ * @user_data: Data passed to @func.
+ * @destroy_notify: (nullable): A callback to free @user_data. This is
+ *   also called on error.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Registers a scalar UDF that is implemented by @func.
+ *
+ * If all arguments are scalar values or the UDF has no argument,
+ * @func is called only with one row and the result is used as a
+ * scalar value.
+ *
+ * If @func sets a #GError in %GDF_ERROR domain, its code is used as
+ * the code of the query error. Other errors are reported as
+ * %GDF_ERROR_EXTERNAL.
+ *
+ * Returns: %TRUE on success, %FALSE otherwise.
+ *
+ * Since: 21.0.0
+ */
+gboolean
+gdf_session_context_register_scalar_udf(GDFSessionContext *context,
+                                        const gchar *name,
+                                        GDFSignature *signature,
+                                        GArrowDataType *return_type,
+                                        GDFScalarUDFFunc func,
+                                        gpointer user_data,
+                                        GDestroyNotify destroy_notify,
+                                        GError **error)
+{
+  GDFSessionContextPrivate *priv =
+    gdf_session_context_get_instance_private(context);
+  GDFScalarUDFData *udf_data = g_new(GDFScalarUDFData, 1);
+  udf_data->func = func;
+  udf_data->user_data = user_data;
+  udf_data->destroy_notify = destroy_notify;
+  gpointer c_abi_return_type = garrow_data_type_export(return_type, error);
+  if (!c_abi_return_type) {
+    gdf_scalar_udf_data_free(udf_data);
+    return FALSE;
+  }
+  DFError *df_error = NULL;
+  bool success =
+    df_session_context_register_scalar_udf(priv->context,
+                                           name,
+                                           gdf_signature_get_raw(signature),
+                                           c_abi_return_type,
+                                           gdf_scalar_udf_function,
+                                           udf_data,
+                                           gdf_scalar_udf_data_free,
+                                           &df_error);
+  g_free(c_abi_return_type);
+  if (!success) {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[session-context][register-scalar-udf] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+    return FALSE;
+  }
+  return TRUE;
+}
+
+static bool
+gdf_aggregate_udf_create(void **accumulator,
+                         void *user_data,
+                         DFError **df_error)
+{
+  GType accumulator_type = GPOINTER_TO_SIZE(user_data);
+  *accumulator = g_object_new(accumulator_type, NULL);
+  return true;
+}
+
+static GArrowRecordBatch *
+gdf_aggregate_udf_import_batch(DFArrowSchema *c_abi_schema,
+                               DFArrowArray *c_abi_array,
+                               DFError **df_error)
+{
+  GError *error = NULL;
+  GArrowSchema *schema = garrow_schema_import(c_abi_schema, &error);
+  if (!schema) {
+    *df_error = gdf_session_context_udf_error(error);
+    g_error_free(error);
+    return NULL;
+  }
+  GArrowRecordBatch *record_batch =
+    garrow_record_batch_import(c_abi_array, schema, &error);
+  g_object_unref(schema);
+  if (!record_batch) {
+    *df_error = gdf_session_context_udf_error(error);
+    g_error_free(error);
+    return NULL;
+  }
+  return record_batch;
+}
+
+static bool
+gdf_aggregate_udf_update_batch(void *accumulator,
+                               DFArrowSchema *values_schema,
+                               DFArrowArray *values,
+                               void *user_data,
+                               DFError **df_error)
+{
+  GArrowRecordBatch *record_batch =
+    gdf_aggregate_udf_import_batch(values_schema, values, df_error);
+  if (!record_batch) {
+    return false;
+  }
+  GError *error = NULL;
+  gboolean success = gdf_accumulator_update_batch(accumulator,
+                                                  record_batch,
+                                                  &error);
+  g_object_unref(record_batch);
+  if (!success) {
+    *df_error = gdf_session_context_udf_error(error);
+    g_error_free(error);
+    return false;
+  }
+  return true;
+}
+
+static bool
+gdf_aggregate_udf_merge_batch(void *accumulator,
+                              DFArrowSchema *states_schema,
+                              DFArrowArray *states,
+                              void *user_data,
+                              DFError **df_error)
+{
+  GArrowRecordBatch *record_batch =
+    gdf_aggregate_udf_import_batch(states_schema, states, df_error);
+  if (!record_batch) {
+    return false;
+  }
+  GError *error = NULL;
+  gboolean success = gdf_accumulator_merge_batch(accumulator,
+                                                 record_batch,
+                                                 &error);
+  g_object_unref(record_batch);
+  if (!success) {
+    *df_error = gdf_session_context_udf_error(error);
+    g_error_free(error);
+    return false;
+  }
+  return true;
+}
+
+static bool
+gdf_aggregate_udf_state(void *accumulator,
+                        DFArrowArray *result,
+                        void *user_data,
+                        DFError **df_error)
+{
+  GError *error = NULL;
+  GArrowRecordBatch *state = gdf_accumulator_get_state(accumulator, &error);
+  if (!state) {
+    if (error) {
+      *df_error = gdf_session_context_udf_error(error);
+      g_error_free(error);
+    } else {
+      *df_error = df_error_new(DF_ERROR_CODE_EXECUTION,
+                               "accumulator didn't return a state");
+    }
+    return false;
+  }
+  gpointer c_abi_array = NULL;
+  gboolean success =
+    garrow_record_batch_export(state, &c_abi_array, NULL, &error);
+  g_object_unref(state);
+  if (!success) {
+    *df_error = gdf_session_context_udf_error(error);
+    g_error_free(error);
+    return false;
+  }
+  *result = *((DFArrowArray *)c_abi_array);
+  g_free(c_abi_array);
+  return true;
+}
+
+static bool
+gdf_aggregate_udf_evaluate(void *accumulator,
+                           DFArrowArray *result,
+                           void *user_data,
+                           DFError **df_error)
+{
+  GError *error = NULL;
+  GArrowArray *array = gdf_accumulator_evaluate(accumulator, &error);
+  if (!array) {
+    if (error) {
+      *df_error = gdf_session_context_udf_error(error);
+      g_error_free(error);
+    } else {
+      *df_error = df_error_new(DF_ERROR_CODE_EXECUTION,
+                               "accumulator didn't return a value");
+    }
+    return false;
+  }
+  gpointer c_abi_array = NULL;
+  gboolean success = garrow_array_export(array, &c_abi_array, NULL, &error);
+  g_object_unref(array);
+  if (!success) {
+    *df_error = gdf_session_context_udf_error(error);
+    g_error_free(error);
+    return false;
+  }
+  *result = *((DFArrowArray *)c_abi_array);
+  g_free(c_abi_array);
+  return true;
+}
+
+static void
+gdf_aggregate_udf_free(void *accumulator, void *user_data)
+{
+  g_object_unref(accumulator);
+}
+
+static size_t
+gdf_aggregate_udf_size(void *accumulator, void *user_data)
+{
+  return gdf_accumulator_get_size(accumulator);
+}
+
+static const DFAccumulatorFunctions gdf_aggregate_udf_functions = {
+  gdf_aggregate_udf_create,
+  gdf_aggregate_udf_update_batch,
+  gdf_aggregate_udf_merge_batch,
+  gdf_aggregate_udf_state,
+  gdf_aggregate_udf_evaluate,
+  gdf_aggregate_udf_free,
+  gdf_aggregate_udf_size,
+};
+
+/**
+ * gdf_session_context_register_aggregate_udf:
+ * @context: A #GDFSessionContext.
+ * @name: A name of the UDF.
+ * @signature: Acceptable arguments of the UDF. It must not accept
+ *   zero arguments.
+ * @return_type: The return type of the UDF.
+ * @state_types: (array length=n_state_types): The types of the
+ *   state returned by gdf_accumulator_get_state().
+ * @n_state_types: The number of @state_types.
+ * @accumulator_type: A #GType of a #GDFAccumulator subclass that
+ *   implements the UDF.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Registers an aggregate UDF that is implemented by
+ * @accumulator_type. A new @accumulator_type object is created for
+ * each group.
+ *
+ * If a #GDFAccumulator method sets a #GError in %GDF_ERROR domain,
+ * its code is used as the code of the query error. Other errors are
+ * reported as %GDF_ERROR_EXTERNAL.
+ *
+ * Returns: %TRUE on success, %FALSE otherwise.
+ *
+ * Since: 21.0.0
+ */
+gboolean
+gdf_session_context_register_aggregate_udf(GDFSessionContext *context,
+                                           const gchar *name,
+                                           GDFSignature *signature,
+                                           GArrowDataType *return_type,
+                                           GArrowDataType **state_types,
+                                           gsize n_state_types,
+                                           GType accumulator_type,
+                                           GError **error)
+{
+  if (!g_type_is_a(accumulator_type, GDF_TYPE_ACCUMULATOR)) {
+    g_set_error(error,
+                GDF_ERROR,
+                GDF_ERROR_PLAN,
+                "[session-context][register-aggregate-udf] "
+                "accumulator type must be a GDFAccumulator subclass: %s",
+                g_type_name(accumulator_type));
+    return FALSE;
+  }
+  GList *fields = NULL;
+  for (gsize i = 0; i < n_state_types; i++) {
+    gchar *field_name = g_strdup_printf("c%" G_GSIZE_FORMAT, i);
+    fields = g_list_prepend(fields,
+                            garrow_field_new(field_name, state_types[i]));
+    g_free(field_name);
+  }
+  fields = g_list_reverse(fields);
+  GArrowSchema *state_schema = garrow_schema_new(fields);
+  g_list_free_full(fields, g_object_unref);
+  gpointer c_abi_state_types = garrow_schema_export(state_schema, error);
+  g_object_unref(state_schema);
+  if (!c_abi_state_types) {
+    return FALSE;
+  }
+  gpointer c_abi_return_type = garrow_data_type_export(return_type, error);
+  if (!c_abi_return_type) {
+    DFArrowSchema *c_abi_schema = c_abi_state_types;
+    c_abi_schema->release(c_abi_schema);
+    g_free(c_abi_state_types);
+    return FALSE;
+  }
+  GDFSessionContextPrivate *priv =
+    gdf_session_context_get_instance_private(context);
+  DFError *df_error = NULL;
+  bool success =
+    df_session_context_register_aggregate_udf(priv->context,
+                                              name,
+                                              gdf_signature_get_raw(signature),
+                                              c_abi_return_type,
+                                              c_abi_state_types,
+                                              &gdf_aggregate_udf_functions,
+                                              GSIZE_TO_POINTER(accumulator_type),
+                                              NULL,
+                                              &df_error);
+  g_free(c_abi_return_type);
+  g_free(c_abi_state_types);
+  if (!success) {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[session-context][register-aggregate-udf] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+    return FALSE;
+  }
+  return TRUE;
+}
+
 GDFSessionContext *
 gdf_session_context_new_raw(DFSessionContext *raw_context)
 {
@@ -464,3 +1408,11 @@ gdf_session_context_new_raw(DFSessionContext *raw_context)
                       "session-context", raw_context,
                       NULL);
 }
+
+DFSessionContext *
+gdf_session_context_get_raw(GDFSessionContext *context)
+{
+  GDFSessionContextPrivate *priv =
+    gdf_session_context_get_instance_private(context);
+  return priv->context;
+}
@@ -16,11 +16,21 @@
 
 #pragma once
 
+#include <gio/gio.h>
+
 #include <arrow-glib/arrow-glib.h>
 
+#include <datafusion-glib/accumulator.h>
+#include <datafusion-glib/avro-read-options.h>
+#include <datafusion-glib/cancellation-token.h>
 #include <datafusion-glib/csv-read-options.h>
 #include <datafusion-glib/data-frame.h>
+#include <datafusion-glib/json-read-options.h>
 #include <datafusion-glib/parquet-read-options.h>
+#include <datafusion-glib/runtime-config.h>
+#include <datafusion-glib/session-config.h>
+#include <datafusion-glib/signature.h>
+#include <datafusion-glib/tokio-runtime.h>
 
 G_BEGIN_DECLS
 
@@ -38,11 +48,43 @@ struct _GDFSessionContextClass
 GDF_AVAILABLE_IN_10_0
 GDFSessionContext *
 gdf_session_context_new(void);
+GDF_AVAILABLE_IN_21_0
+GDFSessionContext *
+gdf_session_context_new_with_config(GDFSessionConfig *config);
+GDF_AVAILABLE_IN_21_0
+GDFSessionContext *
+gdf_session_context_new_with_runtime_config(GDFSessionConfig *config,
+                                            GDFRuntimeConfig *runtime_config,
+                                            GError **error);
+GDF_AVAILABLE_IN_21_0
+void
+gdf_session_context_set_tokio_runtime(GDFSessionContext *context,
+                                      GDFTokioRuntime *runtime);
+GDF_AVAILABLE_IN_21_0
+void
+gdf_session_context_set_cancellation_token(GDFSessionContext *context,
+                                           GDFCancellationToken *token);
+GDF_AVAILABLE_IN_21_0
+void
+gdf_session_context_set_timeout(GDFSessionContext *context,
+                                guint64 timeout_milliseconds);
 GDF_AVAILABLE_IN_10_0
 GDFDataFrame *
 gdf_session_context_sql(GDFSessionContext *context,
                         const gchar *sql,
                         GError **error);
+GDF_AVAILABLE_IN_21_0
+void
+gdf_session_context_sql_async(GDFSessionContext *context,
+                              const gchar *sql,
+                              GCancellable *cancellable,
+                              GAsyncReadyCallback callback,
+                              gpointer user_data);
+GDF_AVAILABLE_IN_21_0
+GDFDataFrame *
+gdf_session_context_sql_finish(GDFSessionContext *context,
+                               GAsyncResult *result,
+                               GError **error);
 GDF_AVAILABLE_IN_10_0
 gboolean
 gdf_session_context_deregister(GDFSessionContext *context,
@@ -60,6 +102,13 @@ gdf_session_context_register_table(GDFSessionContext *context,
                                    const gchar *name,
                                    GArrowTable *table,
                                    GError **error);
+GDF_AVAILABLE_IN_21_0
+gboolean
+gdf_session_context_register_record_batch_reader(
+  GDFSessionContext *context,
+  const gchar *name,
+  GArrowRecordBatchReader *reader,
+  GError **error);
 
 GDF_AVAILABLE_IN_10_0
 gboolean
@@ -68,6 +117,12 @@ gdf_session_context_register_csv(GDFSessionContext *context,
                                  const gchar *url,
                                  GDFCSVReadOptions *options,
                                  GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFDataFrame *
+gdf_session_context_read_csv(GDFSessionContext *context,
+                             const gchar *url,
+                             GDFCSVReadOptions *options,
+                             GError **error);
 
 GDF_AVAILABLE_IN_10_0
 gboolean
@@ -76,6 +131,82 @@ gdf_session_context_register_parquet(GDFSessionContext *context,
                                      const gchar *url,
                                      GDFParquetReadOptions *options,
                                      GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFDataFrame *
+gdf_session_context_read_parquet(GDFSessionContext *context,
+                                 const gchar *url,
+                                 GDFParquetReadOptions *options,
+                                 GError **error);
+
+GDF_AVAILABLE_IN_21_0
+gboolean
+gdf_session_context_register_json(GDFSessionContext *context,
+                                  const gchar *name,
+                                  const gchar *url,
+                                  GDFJSONReadOptions *options,
+                                  GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFDataFrame *
+gdf_session_context_read_json(GDFSessionContext *context,
+                              const gchar *url,
+                              GDFJSONReadOptions *options,
+                              GError **error);
+
+GDF_AVAILABLE_IN_21_0
+gboolean
+gdf_session_context_register_avro(GDFSessionContext *context,
+                                  const gchar *name,
+                                  const gchar *url,
+                                  GDFAvroReadOptions *options,
+                                  GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFDataFrame *
+gdf_session_context_read_avro(GDFSessionContext *context,
+                              const gchar *url,
+                              GDFAvroReadOptions *options,
+                              GError **error);
+
+/**
+ * GDFScalarUDFFunc:
+ * @args: A #GArrowRecordBatch that has arguments as its columns.
+ * @user_data: The data passed to
+ *   gdf_session_context_register_scalar_udf().
+ * @error: Return location for a #GError or %NULL.
+ *
+ * A callback that computes a scalar UDF. This is called in a thread
+ * of the #GDFTokioRuntime that executes the query.
+ *
+ * Returns: (transfer full) (nullable): The result array that has the
+ *   same length as @args and the return type of the UDF, %NULL on
+ *   error.
+ *
+ * Since: 21.0.0
+ */
+typedef GArrowArray *(*GDFScalarUDFFunc)(GArrowRecordBatch *args,
+                                         gpointer user_data,
+                                         GError **error);
+
+GDF_AVAILABLE_IN_21_0
+gboolean
+gdf_session_context_register_scalar_udf(GDFSessionContext *context,
+                                        const gchar *name,
+                                        GDFSignature *signature,
+                                        GArrowDataType *return_type,
+                                        GDFScalarUDFFunc func,
+                                        gpointer user_data,
+                                        GDestroyNotify destroy_notify,
+                                        GError **error);
+
+GDF_AVAILABLE_IN_21_0
+gboolean
+gdf_session_context_register_aggregate_udf(GDFSessionContext *context,
+                                           const gchar *name,
+                                           GDFSignature *signature,
+                                           GArrowDataType *return_type,
+                                           GArrowDataType **state_types,
+                                           gsize n_state_types,
+                                           GType accumulator_type,
+                                           GError **error);
 
 
 G_END_DECLS
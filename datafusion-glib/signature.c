@@ -0,0 +1,297 @@
+/*
+ * Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#include <datafusion-glib/error.h>
+#include <datafusion-glib/signature-raw.h>
+
+G_BEGIN_DECLS
+
+/**
+ * SECTION: signature
+ * @include: datafusion-glib/datafusion-glib.h
+ *
+ * #GDFSignature is a class that describes acceptable arguments of a
+ * user-defined function. You can use this by
+ * gdf_session_context_register_scalar_udf().
+ */
+
+typedef struct GDFSignaturePrivate_ {
+  DFSignature *signature;
+} GDFSignaturePrivate;
+
+enum {
+  PROP_SIGNATURE = 1,
+};
+
+G_DEFINE_TYPE_WITH_PRIVATE(GDFSignature,
+                           gdf_signature,
+                           G_TYPE_OBJECT)
+
+static void
+gdf_signature_finalize(GObject *object)
+{
+  GDFSignaturePrivate *priv =
+    gdf_signature_get_instance_private(GDF_SIGNATURE(object));
+  df_signature_free(priv->signature);
+  G_OBJECT_CLASS(gdf_signature_parent_class)->finalize(object);
+}
+
+static void
+gdf_signature_set_property(GObject *object,
+                           guint prop_id,
+                           const GValue *value,
+                           GParamSpec *pspec)
+{
+  GDFSignaturePrivate *priv =
+    gdf_signature_get_instance_private(GDF_SIGNATURE(object));
+
+  switch (prop_id) {
+  case PROP_SIGNATURE:
+    priv->signature = g_value_get_pointer(value);
+    break;
+  default:
+    G_OBJECT_WARN_INVALID_PROPERTY_ID(object, prop_id, pspec);
+    break;
+  }
+}
+
+static void
+gdf_signature_init(GDFSignature *object)
+{
+}
+
+static void
+gdf_signature_class_init(GDFSignatureClass *klass)
+{
+  GObjectClass *gobject_class = G_OBJECT_CLASS(klass);
+  gobject_class->finalize = gdf_signature_finalize;
+  gobject_class->set_property = gdf_signature_set_property;
+
+  GParamSpec *spec;
+  spec = g_param_spec_pointer("signature",
+                              "Signature",
+                              "The raw DFSignature *",
+                              G_PARAM_WRITABLE | G_PARAM_CONSTRUCT_ONLY);
+  g_object_class_install_property(gobject_class, PROP_SIGNATURE, spec);
+}
+
+static gpointer
+gdf_signature_export_types(GArrowDataType **types,
+                           gsize n_types,
+                           GError **error)
+{
+  GList *fields = NULL;
+  gsize i;
+  for (i = 0; i < n_types; i++) {
+    gchar *name = g_strdup_printf("c%" G_GSIZE_FORMAT, i);
+    fields = g_list_prepend(fields, garrow_field_new(name, types[i]));
+    g_free(name);
+  }
+  fields = g_list_reverse(fields);
+  GArrowSchema *schema = garrow_schema_new(fields);
+  g_list_free_full(fields, g_object_unref);
+  gpointer c_abi_types = garrow_schema_export(schema, error);
+  g_object_unref(schema);
+  return c_abi_types;
+}
+
+static GDFSignature *
+gdf_signature_new_result(DFSignature *signature,
+                         DFError *df_error,
+                         const gchar *tag,
+                         GError **error)
+{
+  if (!signature) {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[signature][%s] %s",
+                tag,
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+    return NULL;
+  }
+  return gdf_signature_new_raw(signature);
+}
+
+/**
+ * gdf_signature_new_exact:
+ * @types: (array length=n_types): Argument types.
+ * @n_types: The number of @types.
+ * @volatility: A volatility of the function.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (nullable): A new #GDFSignature that accepts arguments of
+ *   the exact types, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFSignature *
+gdf_signature_new_exact(GArrowDataType **types,
+                        gsize n_types,
+                        GDFVolatility volatility,
+                        GError **error)
+{
+  gpointer c_abi_types = gdf_signature_export_types(types, n_types, error);
+  if (!c_abi_types) {
+    return NULL;
+  }
+  DFError *df_error = NULL;
+  DFSignature *signature =
+    df_signature_new_exact(c_abi_types, volatility, &df_error);
+  g_free(c_abi_types);
+  return gdf_signature_new_result(signature, df_error, "new-exact", error);
+}
+
+/**
+ * gdf_signature_new_uniform:
+ * @n_arguments: The number of arguments.
+ * @types: (array length=n_types): Acceptable types.
+ * @n_types: The number of @types.
+ * @volatility: A volatility of the function.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (nullable): A new #GDFSignature that accepts
+ *   @n_arguments arguments of the same type that is one of @types,
+ *   %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFSignature *
+gdf_signature_new_uniform(gsize n_arguments,
+                          GArrowDataType **types,
+                          gsize n_types,
+                          GDFVolatility volatility,
+                          GError **error)
+{
+  gpointer c_abi_types = gdf_signature_export_types(types, n_types, error);
+  if (!c_abi_types) {
+    return NULL;
+  }
+  DFError *df_error = NULL;
+  DFSignature *signature =
+    df_signature_new_uniform(n_arguments, c_abi_types, volatility, &df_error);
+  g_free(c_abi_types);
+  return gdf_signature_new_result(signature, df_error, "new-uniform", error);
+}
+
+/**
+ * gdf_signature_new_variadic:
+ * @types: (array length=n_types): Acceptable types.
+ * @n_types: The number of @types.
+ * @volatility: A volatility of the function.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (nullable): A new #GDFSignature that accepts one or more
+ *   arguments of the same type that is one of @types, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFSignature *
+gdf_signature_new_variadic(GArrowDataType **types,
+                           gsize n_types,
+                           GDFVolatility volatility,
+                           GError **error)
+{
+  gpointer c_abi_types = gdf_signature_export_types(types, n_types, error);
+  if (!c_abi_types) {
+    return NULL;
+  }
+  DFError *df_error = NULL;
+  DFSignature *signature =
+    df_signature_new_variadic(c_abi_types, volatility, &df_error);
+  g_free(c_abi_types);
+  return gdf_signature_new_result(signature, df_error, "new-variadic", error);
+}
+
+/**
+ * gdf_signature_new_variadic_equal:
+ * @volatility: A volatility of the function.
+ *
+ * Returns: A new #GDFSignature that accepts one or more arguments of
+ *   the same arbitrary type.
+ *
+ * Since: 21.0.0
+ */
+GDFSignature *
+gdf_signature_new_variadic_equal(GDFVolatility volatility)
+{
+  return gdf_signature_new_raw(df_signature_new_variadic_equal(volatility));
+}
+
+/**
+ * gdf_signature_new_any:
+ * @n_arguments: The number of arguments.
+ * @volatility: A volatility of the function.
+ *
+ * Returns: A new #GDFSignature that accepts @n_arguments arguments
+ *   of arbitrary types.
+ *
+ * Since: 21.0.0
+ */
+GDFSignature *
+gdf_signature_new_any(gsize n_arguments, GDFVolatility volatility)
+{
+  return gdf_signature_new_raw(df_signature_new_any(n_arguments, volatility));
+}
+
+/**
+ * gdf_signature_new_one_of:
+ * @signatures: (array length=n_signatures): Candidate signatures.
+ *   Their volatilities are ignored.
+ * @n_signatures: The number of @signatures.
+ * @volatility: A volatility of the function.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (nullable): A new #GDFSignature that accepts arguments
+ *   that match one of @signatures, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFSignature *
+gdf_signature_new_one_of(GDFSignature **signatures,
+                         gsize n_signatures,
+                         GDFVolatility volatility,
+                         GError **error)
+{
+  const DFSignature **df_signatures = g_new(const DFSignature *, n_signatures);
+  gsize i;
+  for (i = 0; i < n_signatures; i++) {
+    df_signatures[i] = gdf_signature_get_raw(signatures[i]);
+  }
+  DFError *df_error = NULL;
+  DFSignature *signature = df_signature_new_one_of(df_signatures,
+                                                   n_signatures,
+                                                   volatility,
+                                                   &df_error);
+  g_free(df_signatures);
+  return gdf_signature_new_result(signature, df_error, "new-one-of", error);
+}
+
+GDFSignature *
+gdf_signature_new_raw(DFSignature *signature)
+{
+  return g_object_new(GDF_TYPE_SIGNATURE,
+                      "signature", signature,
+                      NULL);
+}
+
+DFSignature *
+gdf_signature_get_raw(GDFSignature *signature)
+{
+  GDFSignaturePrivate *priv = gdf_signature_get_instance_private(signature);
+  return priv->signature;
+}
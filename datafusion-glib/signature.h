@@ -0,0 +1,89 @@
+/*
+ * Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#pragma once
+
+#include <arrow-glib/arrow-glib.h>
+
+#include <datafusion-glib/version.h>
+
+G_BEGIN_DECLS
+
+/**
+ * GDFVolatility:
+ * @GDF_VOLATILITY_IMMUTABLE: The function always returns the same
+ *   output for the same input.
+ * @GDF_VOLATILITY_STABLE: The function returns the same output for
+ *   the same input in a query.
+ * @GDF_VOLATILITY_VOLATILE: The function may return different output
+ *   for the same input.
+ *
+ * How a function's output changes with the same input.
+ *
+ * They are corresponding to `DFVolatility` values.
+ *
+ * Since: 21.0.0
+ */
+typedef enum {
+  GDF_VOLATILITY_IMMUTABLE,
+  GDF_VOLATILITY_STABLE,
+  GDF_VOLATILITY_VOLATILE,
+} GDFVolatility;
+
+#define GDF_TYPE_SIGNATURE (gdf_signature_get_type())
+G_DECLARE_DERIVABLE_TYPE(GDFSignature,
+                         gdf_signature,
+                         GDF,
+                         SIGNATURE,
+                         GObject)
+struct _GDFSignatureClass
+{
+  GObjectClass parent_class;
+};
+
+GDF_AVAILABLE_IN_21_0
+GDFSignature *
+gdf_signature_new_exact(GArrowDataType **types,
+                        gsize n_types,
+                        GDFVolatility volatility,
+                        GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFSignature *
+gdf_signature_new_uniform(gsize n_arguments,
+                          GArrowDataType **types,
+                          gsize n_types,
+                          GDFVolatility volatility,
+                          GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFSignature *
+gdf_signature_new_variadic(GArrowDataType **types,
+                           gsize n_types,
+                           GDFVolatility volatility,
+                           GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFSignature *
+gdf_signature_new_variadic_equal(GDFVolatility volatility);
+GDF_AVAILABLE_IN_21_0
+GDFSignature *
+gdf_signature_new_any(gsize n_arguments, GDFVolatility volatility);
+GDF_AVAILABLE_IN_21_0
+GDFSignature *
+gdf_signature_new_one_of(GDFSignature **signatures,
+                         gsize n_signatures,
+                         GDFVolatility volatility,
+                         GError **error);
+
+G_END_DECLS
@@ -0,0 +1,217 @@
+/*
+ * Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#include <datafusion-glib/error.h>
+#include <datafusion-glib/tokio-runtime-raw.h>
+
+G_BEGIN_DECLS
+
+/**
+ * SECTION: tokio-runtime
+ * @include: datafusion-glib/datafusion-glib.h
+ *
+ * #GDFTokioRuntime is a class for Tokio runtime that executes
+ * queries. You can use this by
+ * gdf_session_context_set_tokio_runtime().
+ *
+ * All #GDFSessionContext that don't have an explicit runtime use
+ * the process-wide default runtime. You can get it by
+ * gdf_tokio_runtime_get_default().
+ *
+ * Note that freeing a #GDFTokioRuntime doesn't shut down the
+ * runtime. Use gdf_tokio_runtime_shutdown() for it.
+ */
+
+typedef struct GDFTokioRuntimePrivate_ {
+  DFTokioRuntime *runtime;
+} GDFTokioRuntimePrivate;
+
+enum {
+  PROP_RUNTIME = 1,
+};
+
+G_DEFINE_TYPE_WITH_PRIVATE(GDFTokioRuntime,
+                           gdf_tokio_runtime,
+                           G_TYPE_OBJECT)
+
+static void
+gdf_tokio_runtime_finalize(GObject *object)
+{
+  GDFTokioRuntimePrivate *priv =
+    gdf_tokio_runtime_get_instance_private(GDF_TOKIO_RUNTIME(object));
+  df_tokio_runtime_free(priv->runtime);
+  G_OBJECT_CLASS(gdf_tokio_runtime_parent_class)->finalize(object);
+}
+
+static void
+gdf_tokio_runtime_set_property(GObject *object,
+                               guint prop_id,
+                               const GValue *value,
+                               GParamSpec *pspec)
+{
+  GDFTokioRuntimePrivate *priv =
+    gdf_tokio_runtime_get_instance_private(GDF_TOKIO_RUNTIME(object));
+
+  switch (prop_id) {
+  case PROP_RUNTIME:
+    priv->runtime = g_value_get_pointer(value);
+    break;
+  default:
+    G_OBJECT_WARN_INVALID_PROPERTY_ID(object, prop_id, pspec);
+    break;
+  }
+}
+
+static void
+gdf_tokio_runtime_init(GDFTokioRuntime *object)
+{
+}
+
+static void
+gdf_tokio_runtime_class_init(GDFTokioRuntimeClass *klass)
+{
+  GObjectClass *gobject_class = G_OBJECT_CLASS(klass);
+  gobject_class->finalize = gdf_tokio_runtime_finalize;
+  gobject_class->set_property = gdf_tokio_runtime_set_property;
+
+  GParamSpec *spec;
+  spec = g_param_spec_pointer("runtime",
+                              "Runtime",
+                              "The raw DFTokioRuntime *",
+                              G_PARAM_WRITABLE | G_PARAM_CONSTRUCT_ONLY);
+  g_object_class_install_property(gobject_class, PROP_RUNTIME, spec);
+}
+
+/**
+ * gdf_tokio_runtime_new:
+ * @flavor: A scheduler type of the runtime.
+ * @n_workers: The number of worker threads. `0` uses the number of
+ *   CPU cores. This is ignored for
+ *   %GDF_TOKIO_RUNTIME_FLAVOR_CURRENT_THREAD.
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * Returns: (nullable): A newly created #GDFTokioRuntime on success,
+ *   %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFTokioRuntime *
+gdf_tokio_runtime_new(GDFTokioRuntimeFlavor flavor,
+                      guint n_workers,
+                      GError **error)
+{
+  DFError *df_error = NULL;
+  DFTokioRuntime *runtime =
+    df_tokio_runtime_new(flavor, n_workers, &df_error);
+  if (!runtime) {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[tokio-runtime][new] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+    return NULL;
+  }
+  return gdf_tokio_runtime_new_raw(runtime);
+}
+
+/**
+ * gdf_tokio_runtime_get_default:
+ * @error: (nullable): Return location for a #GError or %NULL.
+ *
+ * If the default runtime is already shut down, a new default runtime
+ * is created.
+ *
+ * Returns: (nullable) (transfer full): The process-wide default
+ *   #GDFTokioRuntime on success, %NULL on error.
+ *
+ * Since: 21.0.0
+ */
+GDFTokioRuntime *
+gdf_tokio_runtime_get_default(GError **error)
+{
+  DFError *df_error = NULL;
+  DFTokioRuntime *runtime = df_tokio_runtime_get_default(&df_error);
+  if (!runtime) {
+    g_set_error(error,
+                GDF_ERROR,
+                df_error_get_code(df_error),
+                "[tokio-runtime][get-default] %s",
+                df_error_get_message(df_error));
+    df_error_free(df_error);
+    return NULL;
+  }
+  return gdf_tokio_runtime_new_raw(runtime);
+}
+
+/**
+ * gdf_tokio_runtime_shutdown:
+ * @runtime: A #GDFTokioRuntime.
+ * @timeout_milliseconds: The max time to wait for spawned tasks.
+ *
+ * Shuts down @runtime. This doesn't wait for running queries that
+ * use @runtime. If there are no such queries, this waits for spawned
+ * tasks at most @timeout_milliseconds. Otherwise, @runtime is shut
+ * down when the last running query finishes.
+ *
+ * All queries that use @runtime after this report an error.
+ *
+ * If this is called in a thread of a Tokio runtime such as in a UDF
+ * callback, this doesn't wait for spawned tasks.
+ * @timeout_milliseconds is ignored in the case.
+ *
+ * Since: 21.0.0
+ */
+void
+gdf_tokio_runtime_shutdown(GDFTokioRuntime *runtime,
+                           guint64 timeout_milliseconds)
+{
+  GDFTokioRuntimePrivate *priv =
+    gdf_tokio_runtime_get_instance_private(runtime);
+  df_tokio_runtime_shutdown(priv->runtime, timeout_milliseconds);
+}
+
+/**
+ * gdf_tokio_runtime_is_shutdown:
+ * @runtime: A #GDFTokioRuntime.
+ *
+ * Returns: %TRUE if @runtime is shut down, %FALSE otherwise.
+ *
+ * Since: 21.0.0
+ */
+gboolean
+gdf_tokio_runtime_is_shutdown(GDFTokioRuntime *runtime)
+{
+  GDFTokioRuntimePrivate *priv =
+    gdf_tokio_runtime_get_instance_private(runtime);
+  return df_tokio_runtime_is_shutdown(priv->runtime);
+}
+
+GDFTokioRuntime *
+gdf_tokio_runtime_new_raw(DFTokioRuntime *runtime)
+{
+  return g_object_new(GDF_TYPE_TOKIO_RUNTIME,
+                      "runtime", runtime,
+                      NULL);
+}
+
+DFTokioRuntime *
+gdf_tokio_runtime_get_raw(GDFTokioRuntime *runtime)
+{
+  GDFTokioRuntimePrivate *priv =
+    gdf_tokio_runtime_get_instance_private(runtime);
+  return priv->runtime;
+}
@@ -0,0 +1,69 @@
+/*
+ * Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#pragma once
+
+#include <glib-object.h>
+
+#include <datafusion-glib/version.h>
+
+G_BEGIN_DECLS
+
+/**
+ * GDFTokioRuntimeFlavor:
+ * @GDF_TOKIO_RUNTIME_FLAVOR_MULTI_THREAD: Multi-threaded scheduler.
+ * @GDF_TOKIO_RUNTIME_FLAVOR_CURRENT_THREAD: Scheduler that executes
+ *   all tasks in the thread that runs a query.
+ *
+ * Scheduler types of a Tokio runtime.
+ *
+ * They are corresponding to `DFTokioRuntimeFlavor` values.
+ *
+ * Since: 21.0.0
+ */
+typedef enum {
+  GDF_TOKIO_RUNTIME_FLAVOR_MULTI_THREAD,
+  GDF_TOKIO_RUNTIME_FLAVOR_CURRENT_THREAD,
+} GDFTokioRuntimeFlavor;
+
+#define GDF_TYPE_TOKIO_RUNTIME (gdf_tokio_runtime_get_type())
+G_DECLARE_DERIVABLE_TYPE(GDFTokioRuntime,
+                         gdf_tokio_runtime,
+                         GDF,
+                         TOKIO_RUNTIME,
+                         GObject)
+struct _GDFTokioRuntimeClass
+{
+  GObjectClass parent_class;
+};
+
+GDF_AVAILABLE_IN_21_0
+GDFTokioRuntime *
+gdf_tokio_runtime_new(GDFTokioRuntimeFlavor flavor,
+                      guint n_workers,
+                      GError **error);
+GDF_AVAILABLE_IN_21_0
+GDFTokioRuntime *
+gdf_tokio_runtime_get_default(GError **error);
+GDF_AVAILABLE_IN_21_0
+void
+gdf_tokio_runtime_shutdown(GDFTokioRuntime *runtime,
+                           guint64 timeout_milliseconds);
+GDF_AVAILABLE_IN_21_0
+gboolean
+gdf_tokio_runtime_is_shutdown(GDFTokioRuntime *runtime);
+
+G_END_DECLS
@@ -12,30 +12,118 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::any::Any;
 use std::boxed::Box;
+use std::collections::HashMap;
 use std::ffi::CStr;
 use std::ffi::CString;
 use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::str::FromStr;
 use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::RwLock;
+use std::time::Duration;
 
+use arrow::array::make_array;
 use arrow::array::Array;
+use arrow::array::ArrayRef;
+use arrow::array::StringBuilder;
 use arrow::array::StructArray;
+use arrow::array::UInt64Builder;
 use arrow::datatypes::DataType;
 use arrow::datatypes::Field;
 use arrow::datatypes::Schema;
+use arrow::datatypes::SchemaRef;
 use arrow::error::ArrowError;
 use arrow::ffi::ArrowArray;
 use arrow::ffi::FFI_ArrowArray;
 use arrow::ffi::FFI_ArrowSchema;
+use arrow::ffi_stream::export_reader_into_raw;
+use arrow::ffi_stream::FFI_ArrowArrayStream;
 use arrow::record_batch::RecordBatch;
+use arrow::record_batch::RecordBatchReader;
 use arrow_data::ArrayData;
+use datafusion::common::config::ConfigOptions;
+use datafusion::common::Column;
+use datafusion::common::DFSchemaRef;
 use datafusion::common::DataFusionError;
+use datafusion::common::ScalarValue;
+use datafusion::common::TableReference;
 use datafusion::dataframe::DataFrame;
+use datafusion::datasource::file_format::file_type::FileCompressionType;
+use datafusion::datasource::listing::ListingOptions;
+use datafusion::datasource::listing::ListingTable;
+use datafusion::datasource::listing::ListingTableConfig;
+use datafusion::datasource::listing::ListingTableUrl;
+use datafusion::datasource::streaming::PartitionStream;
+use datafusion::datasource::streaming::StreamingTable;
 use datafusion::datasource::MemTable;
+use datafusion::execution::context::SessionConfig;
 use datafusion::execution::context::SessionContext;
+use datafusion::execution::context::SessionState;
+use datafusion::execution::context::TaskContext;
+use datafusion::execution::disk_manager::DiskManagerConfig;
+use datafusion::execution::memory_pool::FairSpillPool;
+use datafusion::execution::memory_pool::GreedyMemoryPool;
+use datafusion::execution::memory_pool::MemoryPool;
+use datafusion::execution::options::AvroReadOptions;
 use datafusion::execution::options::CsvReadOptions;
+use datafusion::execution::options::NdJsonReadOptions;
 use datafusion::execution::options::ParquetReadOptions;
+use datafusion::execution::options::ReadOptions;
+use datafusion::execution::runtime_env::RuntimeConfig;
+use datafusion::execution::runtime_env::RuntimeEnv;
+use datafusion::logical_expr::binary_expr;
+use datafusion::logical_expr::cast;
+use datafusion::logical_expr::col;
+use datafusion::logical_expr::cube;
+use datafusion::logical_expr::expr;
+use datafusion::logical_expr::expr::Case;
+use datafusion::logical_expr::grouping_set;
+use datafusion::logical_expr::lit;
+use datafusion::logical_expr::rollup;
+use datafusion::logical_expr::type_coercion::binary::comparison_coercion;
+use datafusion::logical_expr::Accumulator;
+use datafusion::logical_expr::AccumulatorFunctionImplementation;
+use datafusion::logical_expr::AggregateFunction;
+use datafusion::logical_expr::AggregateUDF;
+use datafusion::logical_expr::BuiltinScalarFunction;
+use datafusion::logical_expr::ColumnarValue;
+use datafusion::logical_expr::Expr;
+use datafusion::logical_expr::JoinType;
+use datafusion::logical_expr::LogicalPlan;
+use datafusion::logical_expr::Operator;
+use datafusion::logical_expr::ReturnTypeFunction;
+use datafusion::logical_expr::ScalarFunctionImplementation;
+use datafusion::logical_expr::ScalarUDF;
+use datafusion::logical_expr::Signature;
+use datafusion::logical_expr::StateTypeFunction;
+use datafusion::logical_expr::TableSource;
+use datafusion::logical_expr::TypeSignature;
+use datafusion::logical_expr::Volatility;
+use datafusion::physical_plan::displayable;
+use datafusion::physical_plan::execute_stream;
+use datafusion::physical_plan::stream::RecordBatchStreamAdapter;
+use datafusion::physical_plan::ExecutionPlan;
+use datafusion::physical_plan::SendableRecordBatchStream;
+use datafusion::sql::planner::ContextProvider;
+use datafusion::sql::planner::ParserOptions;
+use datafusion::sql::planner::PlannerContext;
+use datafusion::sql::planner::SqlToRel;
+use datafusion::sql::sqlparser::ast::Expr as SQLExpr;
+use datafusion::sql::sqlparser::ast::Ident;
+use datafusion::sql::sqlparser::ast::SelectItem;
+use datafusion::sql::sqlparser::dialect::GenericDialect;
+use datafusion::sql::sqlparser::parser::Parser;
+use datafusion::sql::sqlparser::parser::ParserError;
+use datafusion::sql::sqlparser::tokenizer::Token;
+use futures::future::{AbortHandle, Abortable};
+use futures::FutureExt;
+use futures::StreamExt;
 use parquet::file::properties::WriterProperties;
+use tokio::runtime;
+use tokio::runtime::Runtime;
 
 fn strdup(rs_str: &str) -> *mut libc::c_char {
     unsafe {
@@ -71,7 +159,7 @@ fn c_string_to_str<'a>(
 pub enum DFErrorCode {
     Arrow,
     Parquet,
-    #[allow(dead_code)]
+    #[cfg_attr(not(feature = "avro"), allow(dead_code))]
     Avro,
     ObjectStore,
     IO,
@@ -87,6 +175,9 @@ pub enum DFErrorCode {
     JIT,
     Context,
     Substrait,
+    Panic,
+    Cancelled,
+    Timeout,
 }
 
 /// \struct DFError
@@ -108,6 +199,23 @@ impl DFError {
     }
 }
 
+impl std::fmt::Debug for DFError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DFError")
+            .field("code", &(self.code as i32))
+            .field("message", &self.message)
+            .finish()
+    }
+}
+
+impl std::fmt::Display for DFError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message.to_string_lossy())
+    }
+}
+
+impl std::error::Error for DFError {}
+
 #[no_mangle]
 #[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub extern "C" fn df_error_new(
@@ -191,6 +299,63 @@ fn df_error_set(error: *mut *mut DFError, code: DFErrorCode, message: &str) {
     };
 }
 
+// DFError in DataFusionError::External keeps its code in
+// into_df_error().
+fn df_external_error(code: DFErrorCode, message: &str) -> DataFusionError {
+    let c_string_message = CString::new(message).unwrap_or_default();
+    DataFusionError::External(Box::new(DFError::new(
+        code,
+        c_string_message.into_boxed_c_str(),
+    )))
+}
+
+// DataFusion may wrap a DFError by DataFusionError::Context. Our
+// code such as DF_ERROR_CODE_CANCELLED must not be lost by it.
+fn df_error_code_in(e: &DataFusionError) -> Option<DFErrorCode> {
+    match e {
+        DataFusionError::External(e) => e.downcast_ref::<DFError>().map(|e| e.code),
+        DataFusionError::Context(_, e) => df_error_code_in(e),
+        _ => None,
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.as_str()
+    } else {
+        "unknown panic"
+    }
+}
+
+fn df_catch_panic<T, F: FnOnce() -> T>(
+    error: *mut *mut DFError,
+    error_value: T,
+    f: F,
+) -> T {
+    match std::panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(value) => value,
+        Err(payload) => {
+            let message = panic_message(payload.as_ref());
+            df_error_set(error, DFErrorCode::Panic, &format!("Panic: {}", message));
+            error_value
+        }
+    }
+}
+
+// For functions that don't have an error parameter. Unwinding into C
+// is undefined behavior. So we abort instead.
+fn df_abort_on_panic<T, F: FnOnce() -> T>(f: F) -> T {
+    match std::panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(value) => value,
+        Err(payload) => {
+            eprintln!("datafusion-c: Panic: {}", panic_message(payload.as_ref()));
+            std::process::abort();
+        }
+    }
+}
+
 impl<V> IntoDFError for Result<V, ArrowError> {
     type Value = V;
     fn into_df_error(
@@ -234,10 +399,14 @@ impl<V> IntoDFError for Result<V, DataFusionError> {
                     DataFusionError::ResourcesExhausted(_) => {
                         DFErrorCode::ResourcesExhausted
                     }
-                    DataFusionError::External(_) => DFErrorCode::External,
+                    DataFusionError::External(_) => {
+                        df_error_code_in(&e).unwrap_or(DFErrorCode::External)
+                    }
                     #[cfg(feature = "jit")]
                     DataFusionError::JITError(_) => DFErrorCode::JIT,
-                    DataFusionError::Context(_, _) => DFErrorCode::Context,
+                    DataFusionError::Context(_, _) => {
+                        df_error_code_in(&e).unwrap_or(DFErrorCode::Context)
+                    }
                     DataFusionError::Substrait(_) => DFErrorCode::Substrait,
                 };
                 df_error_set(error, code, &e.to_string());
@@ -352,6 +521,180 @@ impl From<FFI_ArrowSchema> for Box<DFArrowSchema> {
     }
 }
 
+struct DFArrowSchemaMetadataPrivateData {
+    metadata: Vec<u8>,
+    release: Option<unsafe extern "C" fn(schema: *mut DFArrowSchema)>,
+    private_data: *mut libc::c_void,
+}
+
+unsafe extern "C" fn df_arrow_schema_release_with_metadata(schema: *mut DFArrowSchema) {
+    if schema.is_null() {
+        return;
+    }
+    let schema = &mut *schema;
+    let private_data =
+        Box::from_raw(schema.private_data as *mut DFArrowSchemaMetadataPrivateData);
+    schema.metadata = std::ptr::null();
+    schema.release = private_data.release;
+    schema.private_data = private_data.private_data;
+    if let Some(release) = schema.release {
+        release(schema);
+    }
+}
+
+impl DFArrowSchema {
+    // FFI_ArrowSchema doesn't export metadata. We attach metadata
+    // after it's exported. The original release callback is wrapped
+    // to free the attached metadata.
+    fn set_metadata(&mut self, metadata: &HashMap<String, String>) {
+        if metadata.is_empty() {
+            return;
+        }
+        let mut entries: Vec<_> = metadata.iter().collect();
+        entries.sort();
+        let mut buffer = vec![];
+        buffer.extend_from_slice(&(entries.len() as i32).to_ne_bytes());
+        for (key, value) in entries {
+            buffer.extend_from_slice(&(key.len() as i32).to_ne_bytes());
+            buffer.extend_from_slice(key.as_bytes());
+            buffer.extend_from_slice(&(value.len() as i32).to_ne_bytes());
+            buffer.extend_from_slice(value.as_bytes());
+        }
+        let private_data = Box::new(DFArrowSchemaMetadataPrivateData {
+            metadata: buffer,
+            release: self.release,
+            private_data: self.private_data,
+        });
+        self.metadata = private_data.metadata.as_ptr() as *const libc::c_char;
+        self.release = Some(df_arrow_schema_release_with_metadata);
+        self.private_data = Box::into_raw(private_data) as *mut libc::c_void;
+    }
+
+    fn set_children_metadata(&mut self, data_type: &DataType) {
+        let fields = match data_type {
+            DataType::List(field)
+            | DataType::LargeList(field)
+            | DataType::FixedSizeList(field, _)
+            | DataType::Map(field, _) => vec![field.as_ref().clone()],
+            DataType::Struct(fields) | DataType::Union(fields, _, _) => fields.clone(),
+            _ => return,
+        };
+        for (i, field) in fields.iter().enumerate().take(self.n_children as usize) {
+            let child = unsafe { &mut **self.children.add(i) };
+            child.set_metadata(field.metadata());
+            child.set_children_metadata(field.data_type());
+        }
+    }
+}
+
+impl DFArrowSchema {
+    // FFI_ArrowSchema doesn't import metadata. We read metadata by
+    // ourselves. See also set_metadata().
+    fn import_metadata(&self) -> Result<HashMap<String, String>, ArrowError> {
+        let mut metadata = HashMap::new();
+        if self.metadata.is_null() {
+            return Ok(metadata);
+        }
+        unsafe fn read_bytes<'a>(position: &mut *const u8, length: usize) -> &'a [u8] {
+            let bytes = std::slice::from_raw_parts(*position, length);
+            *position = position.add(length);
+            bytes
+        }
+        unsafe fn read_i32(position: &mut *const u8) -> i32 {
+            let bytes = read_bytes(position, std::mem::size_of::<i32>());
+            i32::from_ne_bytes(bytes.try_into().unwrap())
+        }
+        unsafe fn read_string(position: &mut *const u8) -> Result<String, ArrowError> {
+            let length = read_i32(position) as usize;
+            String::from_utf8(read_bytes(position, length).to_vec())
+                .map_err(|e| ArrowError::CDataInterface(format!("invalid metadata: {e}")))
+        }
+        let mut position = self.metadata as *const u8;
+        let n_entries = unsafe { read_i32(&mut position) };
+        for _ in 0..n_entries {
+            let key = unsafe { read_string(&mut position) }?;
+            let value = unsafe { read_string(&mut position) }?;
+            metadata.insert(key, value);
+        }
+        Ok(metadata)
+    }
+
+    fn child(&self, i: usize) -> Option<&DFArrowSchema> {
+        if i >= self.n_children as usize {
+            return None;
+        }
+        unsafe { self.children.add(i).read().as_ref() }
+    }
+
+    fn import_field(&self, rs_field: &Field) -> Result<Field, ArrowError> {
+        let rs_data_type = self.import_children_metadata(rs_field.data_type())?;
+        Ok(rs_field
+            .clone()
+            .with_data_type(rs_data_type)
+            .with_metadata(self.import_metadata()?))
+    }
+
+    fn import_fields(&self, rs_fields: &[Field]) -> Result<Vec<Field>, ArrowError> {
+        rs_fields
+            .iter()
+            .enumerate()
+            .map(|(i, rs_field)| match self.child(i) {
+                Some(child) => child.import_field(rs_field),
+                None => Ok(rs_field.clone()),
+            })
+            .collect()
+    }
+
+    fn import_children_metadata(
+        &self,
+        data_type: &DataType,
+    ) -> Result<DataType, ArrowError> {
+        let import_child_field = |rs_field: &Field| -> Result<Box<Field>, ArrowError> {
+            match self.child(0) {
+                Some(child) => Ok(Box::new(child.import_field(rs_field)?)),
+                None => Ok(Box::new(rs_field.clone())),
+            }
+        };
+        Ok(match data_type {
+            DataType::List(rs_field) => DataType::List(import_child_field(rs_field)?),
+            DataType::LargeList(rs_field) => {
+                DataType::LargeList(import_child_field(rs_field)?)
+            }
+            DataType::FixedSizeList(rs_field, size) => {
+                DataType::FixedSizeList(import_child_field(rs_field)?, *size)
+            }
+            DataType::Map(rs_field, sorted) => {
+                DataType::Map(import_child_field(rs_field)?, *sorted)
+            }
+            DataType::Struct(rs_fields) => {
+                DataType::Struct(self.import_fields(rs_fields)?)
+            }
+            DataType::Union(rs_fields, type_ids, mode) => {
+                DataType::Union(self.import_fields(rs_fields)?, type_ids.clone(), *mode)
+            }
+            _ => data_type.clone(),
+        })
+    }
+}
+
+fn import_schema(rs_ffi_schema: &FFI_ArrowSchema) -> Result<Schema, ArrowError> {
+    let rs_schema = Schema::try_from(rs_ffi_schema)?;
+    let c_abi_schema =
+        unsafe { &*((rs_ffi_schema as *const FFI_ArrowSchema) as *const DFArrowSchema) };
+    Ok(Schema::new_with_metadata(
+        c_abi_schema.import_fields(rs_schema.fields())?,
+        c_abi_schema.import_metadata()?,
+    ))
+}
+
+fn export_schema(rs_schema: &Schema) -> Result<Box<DFArrowSchema>, ArrowError> {
+    let rs_ffi_schema = FFI_ArrowSchema::try_from(rs_schema)?;
+    let mut c_abi_schema = Box::<DFArrowSchema>::from(rs_ffi_schema);
+    c_abi_schema.set_metadata(rs_schema.metadata());
+    c_abi_schema.set_children_metadata(&DataType::Struct(rs_schema.fields().clone()));
+    Ok(c_abi_schema)
+}
+
 /// \struct DFArrowArray
 /// \brief Same as the `ArrowArray` struct in the Arrow C data interface
 ///
@@ -371,6 +714,32 @@ pub struct DFArrowArray {
     private_data: *mut libc::c_void,
 }
 
+/// \struct DFArrowArrayStream
+/// \brief Same as the `ArrowArrayStream` struct in the Arrow C stream interface
+///
+/// See also: https://arrow.apache.org/docs/format/CStreamInterface.html#the-arrowarraystream-structure
+#[repr(C)]
+#[derive(Debug)]
+pub struct DFArrowArrayStream {
+    get_schema: Option<
+        unsafe extern "C" fn(
+            stream: *mut DFArrowArrayStream,
+            out: *mut DFArrowSchema,
+        ) -> libc::c_int,
+    >,
+    get_next: Option<
+        unsafe extern "C" fn(
+            stream: *mut DFArrowArrayStream,
+            out: *mut DFArrowArray,
+        ) -> libc::c_int,
+    >,
+    get_last_error: Option<
+        unsafe extern "C" fn(stream: *mut DFArrowArrayStream) -> *const libc::c_char,
+    >,
+    release: Option<unsafe extern "C" fn(stream: *mut DFArrowArrayStream)>,
+    private_data: *mut libc::c_void,
+}
+
 /// \struct DFParquertWriterProperties
 /// \brief A struct to customize how to write an Apache Parquet file.
 ///
@@ -415,278 +784,5151 @@ pub extern "C" fn df_parquet_writer_properties_set_max_row_group_size(
     properties.max_row_group_size = Some(size);
 }
 
-fn block_on<F: Future>(future: F) -> F::Output {
-    tokio::runtime::Runtime::new().unwrap().block_on(future)
+/// \enum DFTokioRuntimeFlavor
+/// \brief Scheduler type of a Tokio runtime
+///
+/// cbindgen:prefix-with-name
+/// cbindgen:rename-all=ScreamingSnakeCase
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub enum DFTokioRuntimeFlavor {
+    MultiThread,
+    #[allow(dead_code)]
+    CurrentThread,
 }
 
-/// \struct DFDataFrame
-/// \brief A struct for data frame.
+/// \struct DFTokioRuntime
+/// \brief A struct for Tokio runtime that executes queries.
 ///
-/// You get execution result as a data frame.
+/// All `DFSessionContext`s that don't have an explicit runtime use
+/// the process-wide default runtime. You can get it by
+/// `df_tokio_runtime_get_default()`.
 ///
-/// You need to free data frame by `df_data_frame_free()` when no
-/// longer needed.
-pub struct DFDataFrame {
-    data_frame: DataFrame,
+/// You need to free runtime by `df_tokio_runtime_free()` when no
+/// longer needed. Note that freeing a runtime doesn't shut down
+/// it. Use `df_tokio_runtime_shutdown()` for it.
+#[derive(Clone)]
+pub struct DFTokioRuntime {
+    // The inner Arc is cloned by running df_*() calls so that the
+    // lock isn't held while a query runs.
+    runtime: Arc<RwLock<Option<Arc<DFRuntime>>>>,
 }
 
-impl DFDataFrame {
-    pub fn new(data_frame: DataFrame) -> Self {
-        Self { data_frame }
+// Dropping a Tokio runtime in a thread of a runtime panics. It may
+// happen when the last user of a runtime such as a DFDataFrame is
+// freed in a callback of df_*_async(). We shut down the runtime in
+// background in the case.
+struct DFRuntime {
+    runtime: Option<Runtime>,
+}
+
+impl DFRuntime {
+    fn new(runtime: Runtime) -> Self {
+        Self {
+            runtime: Some(runtime),
+        }
+    }
+
+    fn runtime(&self) -> &Runtime {
+        // This is None only while dropping or shutting down.
+        self.runtime.as_ref().expect("Tokio runtime must exist")
+    }
+
+    // Waiting for spawned tasks in a thread of a runtime panics. It
+    // may happen when a callback such as a UDF shuts down another
+    // runtime. We don't wait in the case.
+    fn shutdown_timeout(mut self, timeout: Duration) {
+        if let Some(runtime) = self.runtime.take() {
+            if tokio::runtime::Handle::try_current().is_ok() {
+                runtime.shutdown_background();
+            } else {
+                runtime.shutdown_timeout(timeout);
+            }
+        }
     }
 }
 
-/// \brief Free the given `DFDataFrame`.
+impl Drop for DFRuntime {
+    fn drop(&mut self) {
+        if let Some(runtime) = self.runtime.take() {
+            if tokio::runtime::Handle::try_current().is_ok() {
+                runtime.shutdown_background();
+            }
+        }
+    }
+}
+
+static DEFAULT_TOKIO_RUNTIME: Mutex<Option<DFTokioRuntime>> = Mutex::new(None);
+
+impl DFTokioRuntime {
+    pub fn new(
+        flavor: DFTokioRuntimeFlavor,
+        n_workers: usize,
+    ) -> Result<Self, DataFusionError> {
+        let mut builder = match flavor {
+            DFTokioRuntimeFlavor::MultiThread => {
+                let mut builder = runtime::Builder::new_multi_thread();
+                if n_workers > 0 {
+                    builder.worker_threads(n_workers);
+                }
+                builder
+            }
+            DFTokioRuntimeFlavor::CurrentThread => runtime::Builder::new_current_thread(),
+        };
+        let runtime = builder.enable_all().build()?;
+        Ok(Self {
+            runtime: Arc::new(RwLock::new(Some(Arc::new(DFRuntime::new(runtime))))),
+        })
+    }
+
+    pub fn default_runtime() -> Result<Self, DataFusionError> {
+        let mut default_runtime = DEFAULT_TOKIO_RUNTIME
+            .lock()
+            .map_err(|e| DataFusionError::Execution(e.to_string()))?;
+        if let Some(runtime) = default_runtime.as_ref() {
+            if !runtime.is_shutdown() {
+                return Ok(runtime.clone());
+            }
+        }
+        let runtime = Self::new(DFTokioRuntimeFlavor::MultiThread, 0)?;
+        *default_runtime = Some(runtime.clone());
+        Ok(runtime)
+    }
+
+    pub fn is_shutdown(&self) -> bool {
+        match self.runtime.read() {
+            Ok(runtime) => runtime.is_none(),
+            Err(_) => true,
+        }
+    }
+
+    pub fn shutdown(&self, timeout: Duration) {
+        let maybe_runtime = match self.runtime.write() {
+            Ok(mut runtime) => runtime.take(),
+            Err(e) => e.into_inner().take(),
+        };
+        // If running df_*() calls still use the runtime, the last one
+        // of them drops it when it finishes.
+        if let Some(Ok(runtime)) = maybe_runtime.map(Arc::try_unwrap) {
+            runtime.shutdown_timeout(timeout);
+        }
+    }
+
+    fn block_on<T, F>(&self, future: F) -> Result<T, DataFusionError>
+    where
+        F: Future<Output = Result<T, DataFusionError>>,
+    {
+        let runtime = self
+            .runtime
+            .read()
+            .map_err(|e| DataFusionError::Execution(e.to_string()))?
+            .clone();
+        match runtime {
+            Some(runtime) => runtime.runtime().block_on(future),
+            None => Err(DataFusionError::Execution(
+                "Tokio runtime is already shut down".to_string(),
+            )),
+        }
+    }
+
+    fn spawn<F>(&self, future: F) -> Result<(), DataFusionError>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let runtime = self
+            .runtime
+            .read()
+            .map_err(|e| DataFusionError::Execution(e.to_string()))?;
+        match runtime.as_ref() {
+            Some(runtime) => {
+                runtime.runtime().spawn(future);
+                Ok(())
+            }
+            None => Err(DataFusionError::Execution(
+                "Tokio runtime is already shut down".to_string(),
+            )),
+        }
+    }
+}
+
+/// \brief Create a new `DFTokioRuntime`.
 ///
-/// \param _data_frame A `DFDataFrame`.
+/// \param flavor A scheduler type of the runtime.
+/// \param n_workers The number of worker threads. `0` uses the
+///   number of CPU cores. This is ignored for
+///   `DF_TOKIO_RUNTIME_FLAVOR_CURRENT_THREAD`.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFTokioRuntime` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_tokio_runtime_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_tokio_runtime_new(
+    flavor: DFTokioRuntimeFlavor,
+    n_workers: usize,
+    error: *mut *mut DFError,
+) -> Option<Box<DFTokioRuntime>> {
+    df_catch_panic(error, None, || {
+        let runtime =
+            DFTokioRuntime::new(flavor, n_workers).into_df_error(error, None)?;
+        Some(Box::new(runtime))
+    })
+}
+
+/// \brief Get the process-wide default `DFTokioRuntime`.
+///
+/// If the default runtime is already shut down, a new default
+/// runtime is created.
+///
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return The default `DFTokioRuntime` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_tokio_runtime_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_tokio_runtime_get_default(
+    error: *mut *mut DFError,
+) -> Option<Box<DFTokioRuntime>> {
+    df_catch_panic(error, None, || {
+        let runtime = DFTokioRuntime::default_runtime().into_df_error(error, None)?;
+        Some(Box::new(runtime))
+    })
+}
+
+/// \brief Free the given `DFTokioRuntime`.
+///
+/// The runtime itself is still available while it's used by other
+/// `DFTokioRuntime`s, `DFSessionContext`s or `DFDataFrame`s.
+///
+/// \param _runtime A `DFTokioRuntime`.
 ///
 /// # Safety
 ///
-/// This function should not be called for the same `data_frame`
+/// This function should not be called for the same `runtime`
 /// multiple times.
 #[no_mangle]
-pub extern "C" fn df_data_frame_free(_data_frame: Option<Box<DFDataFrame>>) {}
+pub extern "C" fn df_tokio_runtime_free(_runtime: Option<Box<DFTokioRuntime>>) {}
 
-/// \brief Show the given data frame contents to the standard output.
+/// \brief Shut down the given `DFTokioRuntime`.
 ///
-/// \param data_frame A `DFDataFrame` to be shown.
-/// \param error Return location for a `DFError` or `NULL`.
+/// This doesn't wait for running `df_*()` calls that use this
+/// runtime. If there are no such calls, this waits for spawned tasks
+/// at most `timeout_milliseconds`. Otherwise, the runtime is shut
+/// down when the last running call finishes.
+///
+/// All `df_*()` calls that use this runtime after this report an
+/// error.
+///
+/// If this is called in a thread of a Tokio runtime such as in a UDF
+/// callback or a `df_*_async()` callback, this doesn't wait for
+/// spawned tasks. `timeout_milliseconds` is ignored in the case.
+///
+/// \param runtime A `DFTokioRuntime` to be shut down.
+/// \param timeout_milliseconds The max time to wait for spawned tasks.
 #[no_mangle]
-pub extern "C" fn df_data_frame_show(
-    data_frame: &mut DFDataFrame,
-    error: *mut *mut DFError,
+pub extern "C" fn df_tokio_runtime_shutdown(
+    runtime: &mut DFTokioRuntime,
+    timeout_milliseconds: u64,
 ) {
-    let future = data_frame.data_frame.clone().show();
-    block_on(future).into_df_error(error, None);
+    df_abort_on_panic(|| runtime.shutdown(Duration::from_millis(timeout_milliseconds)))
 }
 
-/// \brief Write the given data frame contents as Apache Parquet format.
+/// \brief Whether the given `DFTokioRuntime` is shut down or not.
 ///
-/// \param data_frame A `DFDataFrame` to be written.
-/// \param path An output path.
-/// \param writer_properties Properties how to write Apache Parquet files.
-/// \param error Return location for a `DFError` or `NULL`.
+/// \param runtime A `DFTokioRuntime`.
+/// \return `true` if the runtime is shut down, `false` otherwise.
 #[no_mangle]
-pub extern "C" fn df_data_frame_write_parquet(
-    data_frame: &mut DFDataFrame,
-    path: *const libc::c_char,
-    writer_properties: Option<&DFParquetWriterProperties>,
-    error: *mut *mut DFError,
-) -> bool {
-    let maybe_success = || -> Option<bool> {
-        let cstr_path = unsafe { CStr::from_ptr(path) };
-        let maybe_rs_path = cstr_path.to_str().into_df_error(error, None);
-        let rs_path = match maybe_rs_path {
-            Some(rs_path) => rs_path,
-            None => return None,
-        };
-        let maybe_rs_writer_properties =
-            writer_properties.map(|properties| properties.build());
-        let future = data_frame
-            .data_frame
-            .clone()
-            .write_parquet(rs_path, maybe_rs_writer_properties);
-        block_on(future).into_df_error(error, None)?;
-        Some(true)
-    }();
-    maybe_success.unwrap_or(false)
+pub extern "C" fn df_tokio_runtime_is_shutdown(runtime: &mut DFTokioRuntime) -> bool {
+    runtime.is_shutdown()
 }
 
-#[no_mangle]
-pub extern "C" fn df_data_frame_export(
-    data_frame: &mut DFDataFrame,
-    c_abi_schema_out: *mut *mut DFArrowSchema,
-    c_abi_record_batches_out: *mut *mut *mut DFArrowArray,
-    error: *mut *mut DFError,
-) -> i64 {
-    let option = || -> Option<i64> {
-        let future = data_frame.data_frame.clone().collect();
-        let mut rs_record_batches = block_on(future).into_df_error(error, None)?;
-        let n = rs_record_batches.len();
-        let c_abi_record_batches = unsafe {
-            libc::malloc(std::mem::size_of::<*mut DFArrowArray>() * n)
-                as *mut *mut DFArrowArray
-        };
-        let c_abi_record_batch_slice =
-            unsafe { std::slice::from_raw_parts_mut(c_abi_record_batches, n) };
-        #[allow(clippy::needless_range_loop)]
-        for i in 0..n {
-            let rs_record_batch = rs_record_batches.remove(0);
-            let rs_struct_array = StructArray::from(rs_record_batch);
-            let rs_ffi_array = FFI_ArrowArray::new(&rs_struct_array.data().clone());
-            c_abi_record_batch_slice[i] =
-                Box::into_raw(Box::new(rs_ffi_array)) as *mut DFArrowArray;
-            if i == 0 {
-                let rs_ffi_schema =
-                    FFI_ArrowSchema::try_from(rs_struct_array.data().data_type())
-                        .into_df_error(error, None)?;
-                let c_abi_schema =
-                    Box::into_raw(Box::new(rs_ffi_schema)) as *mut DFArrowSchema;
-                unsafe {
-                    *c_abi_schema_out = c_abi_schema;
-                }
-            }
+/// \struct DFCancellationToken
+/// \brief A struct to cancel running queries from another thread.
+///
+/// You can use this by `df_session_context_set_cancellation_token()`
+/// or `df_data_frame_set_cancellation_token()`. Execution functions
+/// that use a cancelled token report a `DF_ERROR_CODE_CANCELLED`
+/// error.
+///
+/// Running queries are aborted when they wait for other Tokio tasks
+/// or I/O. A query that is executed in a single partition and never
+/// waits may not be aborted until it finishes.
+///
+/// A cancelled token stays cancelled. You need to create a new token
+/// for new queries.
+///
+/// You need to free this by `df_cancellation_token_free()` when no
+/// longer needed.
+#[derive(Clone)]
+pub struct DFCancellationToken {
+    state: Arc<Mutex<DFCancellationTokenState>>,
+}
+
+struct DFCancellationTokenState {
+    cancelled: bool,
+    next_id: u64,
+    abort_handles: HashMap<u64, AbortHandle>,
+}
+
+impl DFCancellationToken {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(DFCancellationTokenState {
+                cancelled: false,
+                next_id: 0,
+                abort_handles: HashMap::new(),
+            })),
+        }
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, DFCancellationTokenState> {
+        match self.state.lock() {
+            Ok(state) => state,
+            Err(e) => e.into_inner(),
         }
-        unsafe {
-            *c_abi_record_batches_out = c_abi_record_batches;
+    }
+
+    pub fn cancel(&self) {
+        let mut state = self.lock_state();
+        state.cancelled = true;
+        for abort_handle in state.abort_handles.values() {
+            abort_handle.abort();
         }
-        Some(n as i64)
-    }();
-    match option {
-        Some(n) => n,
-        None => {
-            unsafe {
-                if !(*c_abi_record_batches_out).is_null() {
-                    libc::free((*c_abi_record_batches_out) as *mut libc::c_void);
-                    *c_abi_record_batches_out = std::ptr::null_mut();
-                }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.lock_state().cancelled
+    }
+
+    async fn run<T, F>(&self, future: F) -> Result<T, DataFusionError>
+    where
+        F: Future<Output = Result<T, DataFusionError>>,
+    {
+        let (abort_handle, abort_registration) = AbortHandle::new_pair();
+        let id = {
+            let mut state = self.lock_state();
+            if state.cancelled {
+                return Err(df_external_error(
+                    DFErrorCode::Cancelled,
+                    "Query is cancelled",
+                ));
             }
-            -1
-        }
+            let id = state.next_id;
+            state.next_id += 1;
+            state.abort_handles.insert(id, abort_handle);
+            id
+        };
+        // Dropping an aborted future drops DataFusion streams in it.
+        // It aborts Tokio tasks spawned by the streams.
+        let result = match Abortable::new(future, abort_registration).await {
+            Ok(result) => result,
+            Err(_) => Err(df_external_error(
+                DFErrorCode::Cancelled,
+                "Query is cancelled",
+            )),
+        };
+        self.lock_state().abort_handles.remove(&id);
+        result
     }
 }
 
-/// \struct DFSessionContext
-/// \brief An entry point of DataFusion API.
-///
-/// You need to create `DFSessionContext` to use DataFusion API.
-pub struct DFSessionContext {
-    context: SessionContext,
+impl Default for DFCancellationToken {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
-impl DFSessionContext {
-    pub fn new(context: SessionContext) -> Self {
-        Self { context }
+// df_*_async() calls accept a token only for the call in addition
+// to the token of the context or the data frame.
+async fn run_with_cancellation_token<T, F>(
+    cancellation_token: Option<DFCancellationToken>,
+    future: F,
+) -> Result<T, DataFusionError>
+where
+    F: Future<Output = Result<T, DataFusionError>>,
+{
+    match cancellation_token {
+        Some(cancellation_token) => cancellation_token.run(future).await,
+        None => future.await,
     }
 }
 
-/// \brief Create a new `DFSessionContext`.
+/// \brief Create a new `DFCancellationToken`.
 ///
-/// \return A newly created `DFSessionContext`.
+/// \return A newly created `DFCancellationToken`.
 ///
-///   It should be freed by `df_session_context_free()` when no longer
-///   needed.
+///   It should be freed by `df_cancellation_token_free()` when no
+///   longer needed.
 #[no_mangle]
-pub extern "C" fn df_session_context_new() -> Box<DFSessionContext> {
-    Box::new(DFSessionContext::new(SessionContext::new()))
+pub extern "C" fn df_cancellation_token_new() -> Box<DFCancellationToken> {
+    Box::new(DFCancellationToken::new())
 }
 
-/// \brief Free the given `DFSessionContext`.
+/// \brief Free the given `DFCancellationToken`.
 ///
-/// \param _context A `DFSessionContext` created by
-///   `df_session_context_new()`.
+/// \param _token A `DFCancellationToken`.
 ///
 /// # Safety
 ///
-/// This function should not be called with `context` that is not
-/// created by `df_session_context_new()`.
-///
-/// This function should not be called for the same `context`
-/// multiple times.
+/// This function should not be called for the same `token` multiple
+/// times.
 #[no_mangle]
-pub extern "C" fn df_session_context_free(_context: Option<Box<DFSessionContext>>) {}
+pub extern "C" fn df_cancellation_token_free(_token: Option<Box<DFCancellationToken>>) {}
 
+/// \brief Cancel queries that use the given token.
+///
+/// This can be called from any thread. Running queries are aborted
+/// and report a `DF_ERROR_CODE_CANCELLED` error. Queries that use
+/// this token after this call also report the error.
+///
+/// \param token A `DFCancellationToken`.
 #[no_mangle]
-#[allow(clippy::not_unsafe_ptr_arg_deref)]
-pub extern "C" fn df_session_context_sql(
-    context: &mut DFSessionContext,
-    sql: *const libc::c_char,
-    error: *mut *mut DFError,
-) -> Option<Box<DFDataFrame>> {
-    let cstr_sql = unsafe { CStr::from_ptr(sql) };
-    let maybe_rs_sql = cstr_sql.to_str().into_df_error(error, None);
-    let rs_sql = match maybe_rs_sql {
-        Some(rs_sql) => rs_sql,
-        None => return None,
-    };
-    let result = block_on(context.context.sql(rs_sql));
-    let maybe_data_frame = result.into_df_error(error, None);
-    maybe_data_frame.map(|data_frame| Box::new(DFDataFrame::new(data_frame)))
+pub extern "C" fn df_cancellation_token_cancel(token: &DFCancellationToken) {
+    token.cancel();
 }
 
+/// \brief Whether the given token is cancelled or not.
+///
+/// \param token A `DFCancellationToken`.
+/// \return `true` if `token` is cancelled, `false` otherwise.
 #[no_mangle]
-#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn df_cancellation_token_is_cancelled(
+    token: &DFCancellationToken,
+) -> bool {
+    token.is_cancelled()
+}
+
+#[derive(Clone)]
+pub struct DFExecutor {
+    runtime: DFTokioRuntime,
+    cancellation_token: Option<DFCancellationToken>,
+    timeout: Option<Duration>,
+}
+
+impl DFExecutor {
+    pub fn new(runtime: DFTokioRuntime) -> Self {
+        Self {
+            runtime,
+            cancellation_token: None,
+            timeout: None,
+        }
+    }
+
+    fn set_timeout(&mut self, timeout_milliseconds: u64) {
+        self.timeout = match timeout_milliseconds {
+            0 => None,
+            _ => Some(Duration::from_millis(timeout_milliseconds)),
+        };
+    }
+
+    async fn run<T, F>(&self, future: F) -> Result<T, DataFusionError>
+    where
+        F: Future<Output = Result<T, DataFusionError>>,
+    {
+        let future = async {
+            match &self.cancellation_token {
+                Some(cancellation_token) => cancellation_token.run(future).await,
+                None => future.await,
+            }
+        };
+        match self.timeout {
+            Some(timeout) => match tokio::time::timeout(timeout, future).await {
+                Ok(result) => result,
+                Err(_) => Err(df_external_error(
+                    DFErrorCode::Timeout,
+                    &format!("Query timed out after {}ms", timeout.as_millis()),
+                )),
+            },
+            None => future.await,
+        }
+    }
+
+    fn block_on<T, F>(&self, future: F) -> Result<T, DataFusionError>
+    where
+        F: Future<Output = Result<T, DataFusionError>>,
+    {
+        self.runtime.block_on(self.run(future))
+    }
+
+    // The callback is called in a thread of the runtime. A panic in
+    // the future is passed to the callback as a DF_ERROR_CODE_PANIC
+    // error. The spawned task may hold the last reference to the
+    // runtime. DFRuntime doesn't panic when it's dropped in the task.
+    fn spawn<T, F, C>(&self, future: F, callback: C) -> Result<(), DataFusionError>
+    where
+        T: Send + 'static,
+        F: Future<Output = Result<T, DataFusionError>> + Send + 'static,
+        C: FnOnce(Result<T, DataFusionError>) + Send + 'static,
+    {
+        let executor = self.clone();
+        self.runtime.spawn(async move {
+            let result = AssertUnwindSafe(executor.run(future))
+                .catch_unwind()
+                .await
+                .unwrap_or_else(|payload| {
+                    let message = format!("Panic: {}", panic_message(payload.as_ref()));
+                    Err(df_external_error(DFErrorCode::Panic, &message))
+                });
+            callback(result);
+        })
+    }
+}
+
+/// \struct DFDataFrame
+/// \brief A struct for data frame.
+///
+/// You get execution result as a data frame.
+///
+/// You need to free data frame by `df_data_frame_free()` when no
+/// longer needed.
+pub struct DFDataFrame {
+    data_frame: DataFrame,
+    executor: DFExecutor,
+}
+
+impl DFDataFrame {
+    pub fn new(data_frame: DataFrame, executor: DFExecutor) -> Self {
+        Self {
+            data_frame,
+            executor,
+        }
+    }
+}
+
+struct DFRecordBatchReader {
+    schema: SchemaRef,
+    stream: SendableRecordBatchStream,
+    executor: DFExecutor,
+}
+
+impl Iterator for DFRecordBatchReader {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    // This is called by get_next() of the C stream interface. So we
+    // must not unwind.
+    fn next(&mut self) -> Option<Self::Item> {
+        let stream = &mut self.stream;
+        let executor = &self.executor;
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            executor.block_on(async { stream.next().await.transpose() })
+        }))
+        .unwrap_or_else(|payload| {
+            let message = format!("Panic: {}", panic_message(payload.as_ref()));
+            Err(df_external_error(DFErrorCode::Panic, &message))
+        });
+        result
+            .map_err(|e| ArrowError::ExternalError(Box::new(e)))
+            .transpose()
+    }
+}
+
+impl RecordBatchReader for DFRecordBatchReader {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+/// \brief Free the given `DFDataFrame`.
+///
+/// \param _data_frame A `DFDataFrame`.
+///
+/// # Safety
+///
+/// This function should not be called for the same `data_frame`
+/// multiple times.
+#[no_mangle]
+pub extern "C" fn df_data_frame_free(_data_frame: Option<Box<DFDataFrame>>) {}
+
+/// \brief Set a `DFCancellationToken` that is used by the given
+/// `DFDataFrame`.
+///
+/// `DFDataFrame`s created from the data frame use the token too.
+///
+/// \param data_frame A `DFDataFrame`.
+/// \param token A `DFCancellationToken` or `NULL`. If this is `NULL`,
+///   execution of the data frame can't be cancelled.
+#[no_mangle]
+pub extern "C" fn df_data_frame_set_cancellation_token(
+    data_frame: &mut DFDataFrame,
+    token: Option<&DFCancellationToken>,
+) {
+    data_frame.executor.cancellation_token = token.cloned();
+}
+
+/// \brief Set a timeout for each execution of the given
+/// `DFDataFrame`.
+///
+/// See `df_session_context_set_timeout()` for details.
+///
+/// `DFDataFrame`s created from the data frame use the timeout too.
+///
+/// \param data_frame A `DFDataFrame`.
+/// \param timeout_milliseconds The timeout in milliseconds. `0` means
+///   no timeout.
+#[no_mangle]
+pub extern "C" fn df_data_frame_set_timeout(
+    data_frame: &mut DFDataFrame,
+    timeout_milliseconds: u64,
+) {
+    data_frame.executor.set_timeout(timeout_milliseconds);
+}
+
+fn c_strings_to_strs<'a>(
+    c_strings: *const *const libc::c_char,
+    n_c_strings: usize,
+) -> Result<Vec<&'a str>, std::str::Utf8Error> {
+    if n_c_strings == 0 {
+        return Ok(vec![]);
+    }
+    let c_string_slice = unsafe { std::slice::from_raw_parts(c_strings, n_c_strings) };
+    c_string_slice
+        .iter()
+        .map(|c_string| c_string_to_str(*c_string))
+        .collect()
+}
+
+struct DFSQLExprContextProvider<'a> {
+    state: &'a SessionState,
+}
+
+impl<'a> ContextProvider for DFSQLExprContextProvider<'a> {
+    fn get_table_provider(
+        &self,
+        name: TableReference,
+    ) -> Result<Arc<dyn TableSource>, DataFusionError> {
+        Err(DataFusionError::Plan(format!(
+            "table '{}' can't be used in expression",
+            name
+        )))
+    }
+
+    fn get_function_meta(&self, name: &str) -> Option<Arc<ScalarUDF>> {
+        self.state.scalar_functions().get(name).cloned()
+    }
+
+    fn get_aggregate_meta(&self, name: &str) -> Option<Arc<AggregateUDF>> {
+        self.state.aggregate_functions().get(name).cloned()
+    }
+
+    fn get_variable_type(&self, _variable_names: &[String]) -> Option<DataType> {
+        None
+    }
+
+    fn options(&self) -> &ConfigOptions {
+        self.state.config_options()
+    }
+}
+
+struct DFSQLExprParser {
+    state: SessionState,
+    schema: DFSchemaRef,
+}
+
+impl DFSQLExprParser {
+    fn new(data_frame: &DataFrame) -> Self {
+        let (state, plan) = data_frame.clone().into_parts();
+        Self {
+            state,
+            schema: plan.schema().clone(),
+        }
+    }
+
+    fn parse<T, F>(&self, sql: &str, parse: F) -> Result<T, DataFusionError>
+    where
+        F: FnOnce(&mut Parser) -> Result<T, ParserError>,
+    {
+        let dialect = GenericDialect {};
+        let mut parser = Parser::new(&dialect).try_with_sql(sql)?;
+        let value = parse(&mut parser)?;
+        parser.expect_token(&Token::EOF)?;
+        Ok(value)
+    }
+
+    fn to_expr(&self, sql_expr: SQLExpr) -> Result<Expr, DataFusionError> {
+        let provider = DFSQLExprContextProvider { state: &self.state };
+        let sql_parser_options = &self.state.config_options().sql_parser;
+        let options = ParserOptions {
+            parse_float_as_decimal: sql_parser_options.parse_float_as_decimal,
+            enable_ident_normalization: sql_parser_options.enable_ident_normalization,
+        };
+        let planner = SqlToRel::new_with_options(&provider, options);
+        planner.sql_to_expr(sql_expr, &self.schema, &mut PlannerContext::new())
+    }
+
+    fn normalize_ident(&self, ident: Ident) -> String {
+        let options = &self.state.config_options().sql_parser;
+        if options.enable_ident_normalization && ident.quote_style.is_none() {
+            ident.value.to_ascii_lowercase()
+        } else {
+            ident.value
+        }
+    }
+
+    fn parse_expr(&self, sql: &str) -> Result<Expr, DataFusionError> {
+        let sql_expr = self.parse(sql, |parser| parser.parse_expr())?;
+        self.to_expr(sql_expr)
+    }
+
+    fn parse_select_item(&self, sql: &str) -> Result<Expr, DataFusionError> {
+        match self.parse(sql, |parser| parser.parse_select_item())? {
+            SelectItem::UnnamedExpr(sql_expr) => self.to_expr(sql_expr),
+            SelectItem::ExprWithAlias {
+                expr: sql_expr,
+                alias,
+            } => Ok(self.to_expr(sql_expr)?.alias(self.normalize_ident(alias))),
+            SelectItem::QualifiedWildcard(name, _) => Ok(Expr::QualifiedWildcard {
+                qualifier: name.to_string(),
+            }),
+            SelectItem::Wildcard(_) => Ok(Expr::Wildcard),
+        }
+    }
+
+    fn parse_sort_expr(&self, sql: &str) -> Result<Expr, DataFusionError> {
+        let order_by_expr = self.parse(sql, |parser| parser.parse_order_by_expr())?;
+        let asc = order_by_expr.asc.unwrap_or(true);
+        // NULLS FIRST is the default for DESC and NULLS LAST is the
+        // default for ASC like the SQL interface.
+        let nulls_first = order_by_expr.nulls_first.unwrap_or(!asc);
+        Ok(self.to_expr(order_by_expr.expr)?.sort(asc, nulls_first))
+    }
+}
+
+/// \brief Select columns by name.
+///
+/// \param data_frame A `DFDataFrame`.
+/// \param columns Column names to be selected.
+/// \param n_columns The number of `columns`.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFDataFrame` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_data_frame_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_data_frame_select_columns(
+    data_frame: &mut DFDataFrame,
+    columns: *const *const libc::c_char,
+    n_columns: usize,
+    error: *mut *mut DFError,
+) -> Option<Box<DFDataFrame>> {
+    df_catch_panic(error, None, || {
+        let rs_columns =
+            c_strings_to_strs(columns, n_columns).into_df_error(error, None)?;
+        let rs_data_frame = data_frame
+            .data_frame
+            .clone()
+            .select_columns(&rs_columns)
+            .into_df_error(error, None)?;
+        Some(Box::new(DFDataFrame::new(
+            rs_data_frame,
+            data_frame.executor.clone(),
+        )))
+    })
+}
+
+/// \brief Project the given expressions.
+///
+/// \param data_frame A `DFDataFrame`.
+/// \param sql_exprs SQL expressions such as `a + 1 AS b` to be
+///   selected. `*` and `AS alias` can be used like `SELECT`.
+/// \param n_sql_exprs The number of `sql_exprs`.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFDataFrame` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_data_frame_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_data_frame_select(
+    data_frame: &mut DFDataFrame,
+    sql_exprs: *const *const libc::c_char,
+    n_sql_exprs: usize,
+    error: *mut *mut DFError,
+) -> Option<Box<DFDataFrame>> {
+    df_catch_panic(error, None, || {
+        let rs_sql_exprs =
+            c_strings_to_strs(sql_exprs, n_sql_exprs).into_df_error(error, None)?;
+        let parser = DFSQLExprParser::new(&data_frame.data_frame);
+        let rs_exprs = rs_sql_exprs
+            .into_iter()
+            .map(|sql_expr| parser.parse_select_item(sql_expr))
+            .collect::<Result<Vec<_>, _>>()
+            .into_df_error(error, None)?;
+        let rs_data_frame = data_frame
+            .data_frame
+            .clone()
+            .select(rs_exprs)
+            .into_df_error(error, None)?;
+        Some(Box::new(DFDataFrame::new(
+            rs_data_frame,
+            data_frame.executor.clone(),
+        )))
+    })
+}
+
+/// \brief Filter rows by the given predicate.
+///
+/// \param data_frame A `DFDataFrame`.
+/// \param sql_predicate A SQL expression such as `a > 10 AND b IS NOT
+///   NULL` that returns a boolean.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFDataFrame` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_data_frame_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_data_frame_filter(
+    data_frame: &mut DFDataFrame,
+    sql_predicate: *const libc::c_char,
+    error: *mut *mut DFError,
+) -> Option<Box<DFDataFrame>> {
+    df_catch_panic(error, None, || {
+        let rs_sql_predicate =
+            c_string_to_str(sql_predicate).into_df_error(error, None)?;
+        let parser = DFSQLExprParser::new(&data_frame.data_frame);
+        let rs_predicate = parser
+            .parse_expr(rs_sql_predicate)
+            .into_df_error(error, None)?;
+        let rs_data_frame = data_frame
+            .data_frame
+            .clone()
+            .filter(rs_predicate)
+            .into_df_error(error, None)?;
+        Some(Box::new(DFDataFrame::new(
+            rs_data_frame,
+            data_frame.executor.clone(),
+        )))
+    })
+}
+
+/// \brief Sort rows by the given sort keys.
+///
+/// \param data_frame A `DFDataFrame`.
+/// \param sql_sort_exprs SQL sort keys such as `a DESC NULLS LAST`
+///   like `ORDER BY`.
+/// \param n_sql_sort_exprs The number of `sql_sort_exprs`.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFDataFrame` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_data_frame_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_data_frame_sort(
+    data_frame: &mut DFDataFrame,
+    sql_sort_exprs: *const *const libc::c_char,
+    n_sql_sort_exprs: usize,
+    error: *mut *mut DFError,
+) -> Option<Box<DFDataFrame>> {
+    df_catch_panic(error, None, || {
+        let rs_sql_sort_exprs = c_strings_to_strs(sql_sort_exprs, n_sql_sort_exprs)
+            .into_df_error(error, None)?;
+        let parser = DFSQLExprParser::new(&data_frame.data_frame);
+        let rs_sort_exprs = rs_sql_sort_exprs
+            .into_iter()
+            .map(|sql_sort_expr| parser.parse_sort_expr(sql_sort_expr))
+            .collect::<Result<Vec<_>, _>>()
+            .into_df_error(error, None)?;
+        let rs_data_frame = data_frame
+            .data_frame
+            .clone()
+            .sort(rs_sort_exprs)
+            .into_df_error(error, None)?;
+        Some(Box::new(DFDataFrame::new(
+            rs_data_frame,
+            data_frame.executor.clone(),
+        )))
+    })
+}
+
+/// \brief Limit the number of rows.
+///
+/// \param data_frame A `DFDataFrame`.
+/// \param offset The number of rows to be skipped.
+/// \param fetch The max number of rows to be returned after
+///   `offset`. Negative value means no limit.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFDataFrame` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_data_frame_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_data_frame_limit(
+    data_frame: &mut DFDataFrame,
+    offset: usize,
+    fetch: i64,
+    error: *mut *mut DFError,
+) -> Option<Box<DFDataFrame>> {
+    df_catch_panic(error, None, || {
+        let rs_fetch = if fetch < 0 {
+            None
+        } else {
+            Some(fetch as usize)
+        };
+        let rs_data_frame = data_frame
+            .data_frame
+            .clone()
+            .limit(offset, rs_fetch)
+            .into_df_error(error, None)?;
+        Some(Box::new(DFDataFrame::new(
+            rs_data_frame,
+            data_frame.executor.clone(),
+        )))
+    })
+}
+
+/// \enum DFOperator
+/// \brief Binary operators for `df_expr_new_binary()`
+///
+/// cbindgen:prefix-with-name
+/// cbindgen:rename-all=ScreamingSnakeCase
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub enum DFOperator {
+    #[allow(dead_code)]
+    Eq,
+    #[allow(dead_code)]
+    NotEq,
+    #[allow(dead_code)]
+    Lt,
+    #[allow(dead_code)]
+    LtEq,
+    #[allow(dead_code)]
+    Gt,
+    #[allow(dead_code)]
+    GtEq,
+    #[allow(dead_code)]
+    Plus,
+    #[allow(dead_code)]
+    Minus,
+    #[allow(dead_code)]
+    Multiply,
+    #[allow(dead_code)]
+    Divide,
+    #[allow(dead_code)]
+    Modulo,
+    #[allow(dead_code)]
+    And,
+    #[allow(dead_code)]
+    Or,
+    #[allow(dead_code)]
+    IsDistinctFrom,
+    #[allow(dead_code)]
+    IsNotDistinctFrom,
+    #[allow(dead_code)]
+    RegexMatch,
+    #[allow(dead_code)]
+    RegexIMatch,
+    #[allow(dead_code)]
+    RegexNotMatch,
+    #[allow(dead_code)]
+    RegexNotIMatch,
+    #[allow(dead_code)]
+    BitwiseAnd,
+    #[allow(dead_code)]
+    BitwiseOr,
+    #[allow(dead_code)]
+    BitwiseXor,
+    #[allow(dead_code)]
+    BitwiseShiftRight,
+    #[allow(dead_code)]
+    BitwiseShiftLeft,
+    #[allow(dead_code)]
+    StringConcat,
+}
+
+impl From<DFOperator> for Operator {
+    fn from(operator: DFOperator) -> Self {
+        match operator {
+            DFOperator::Eq => Operator::Eq,
+            DFOperator::NotEq => Operator::NotEq,
+            DFOperator::Lt => Operator::Lt,
+            DFOperator::LtEq => Operator::LtEq,
+            DFOperator::Gt => Operator::Gt,
+            DFOperator::GtEq => Operator::GtEq,
+            DFOperator::Plus => Operator::Plus,
+            DFOperator::Minus => Operator::Minus,
+            DFOperator::Multiply => Operator::Multiply,
+            DFOperator::Divide => Operator::Divide,
+            DFOperator::Modulo => Operator::Modulo,
+            DFOperator::And => Operator::And,
+            DFOperator::Or => Operator::Or,
+            DFOperator::IsDistinctFrom => Operator::IsDistinctFrom,
+            DFOperator::IsNotDistinctFrom => Operator::IsNotDistinctFrom,
+            DFOperator::RegexMatch => Operator::RegexMatch,
+            DFOperator::RegexIMatch => Operator::RegexIMatch,
+            DFOperator::RegexNotMatch => Operator::RegexNotMatch,
+            DFOperator::RegexNotIMatch => Operator::RegexNotIMatch,
+            DFOperator::BitwiseAnd => Operator::BitwiseAnd,
+            DFOperator::BitwiseOr => Operator::BitwiseOr,
+            DFOperator::BitwiseXor => Operator::BitwiseXor,
+            DFOperator::BitwiseShiftRight => Operator::BitwiseShiftRight,
+            DFOperator::BitwiseShiftLeft => Operator::BitwiseShiftLeft,
+            DFOperator::StringConcat => Operator::StringConcat,
+        }
+    }
+}
+
+/// \enum DFUnaryOperator
+/// \brief Unary operators for `df_expr_new_unary()`
+///
+/// cbindgen:prefix-with-name
+/// cbindgen:rename-all=ScreamingSnakeCase
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub enum DFUnaryOperator {
+    #[allow(dead_code)]
+    Not,
+    #[allow(dead_code)]
+    Negative,
+    #[allow(dead_code)]
+    IsNull,
+    #[allow(dead_code)]
+    IsNotNull,
+    #[allow(dead_code)]
+    IsTrue,
+    #[allow(dead_code)]
+    IsFalse,
+    #[allow(dead_code)]
+    IsUnknown,
+    #[allow(dead_code)]
+    IsNotTrue,
+    #[allow(dead_code)]
+    IsNotFalse,
+    #[allow(dead_code)]
+    IsNotUnknown,
+}
+
+/// \struct DFExpr
+/// \brief A struct for logical expression.
+///
+/// You can use this instead of SQL expression strings to transform
+/// `DFDataFrame`.
+///
+/// You need to free this by `df_expr_free()` when no longer needed.
+pub struct DFExpr {
+    expr: Expr,
+}
+
+impl DFExpr {
+    pub fn new(expr: Expr) -> Self {
+        Self { expr }
+    }
+}
+
+fn exprs_from_raw(exprs: *const *const DFExpr, n_exprs: usize) -> Vec<Expr> {
+    if n_exprs == 0 {
+        return vec![];
+    }
+    let expr_slice = unsafe { std::slice::from_raw_parts(exprs, n_exprs) };
+    expr_slice
+        .iter()
+        .map(|expr| unsafe { &**expr }.expr.clone())
+        .collect()
+}
+
+/// \brief Create a new column reference expression.
+///
+/// \param name A column name. It may be qualified by relation name
+///   such as `table.column`.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFExpr` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_expr_free()` when no longer needed.
+#[no_mangle]
+pub extern "C" fn df_expr_new_column(
+    name: *const libc::c_char,
+    error: *mut *mut DFError,
+) -> Option<Box<DFExpr>> {
+    df_catch_panic(error, None, || {
+        let rs_name = c_string_to_str(name).into_df_error(error, None)?;
+        Some(Box::new(DFExpr::new(col(rs_name))))
+    })
+}
+
+/// \brief Create a new literal expression.
+///
+/// \param c_abi_data_type The data type of `c_abi_array`. The content
+///   is moved. The caller must not release this.
+/// \param c_abi_array An array that has only one element. The
+///   element is used as the literal value. The content is moved. The
+///   caller must not release this.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFExpr` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_expr_free()` when no longer needed.
+#[no_mangle]
+pub extern "C" fn df_expr_new_literal(
+    c_abi_data_type: &mut DFArrowSchema,
+    c_abi_array: &mut DFArrowArray,
+    error: *mut *mut DFError,
+) -> Option<Box<DFExpr>> {
+    df_catch_panic(error, None, || {
+        let rs_ffi_array = unsafe {
+            std::ptr::replace(
+                (c_abi_array as *mut DFArrowArray) as *mut FFI_ArrowArray,
+                FFI_ArrowArray::empty(),
+            )
+        };
+        let rs_data_type =
+            import_data_type(c_abi_data_type).into_df_error(error, None)?;
+        let rs_array =
+            import_array(rs_ffi_array, &rs_data_type).into_df_error(error, None)?;
+        if rs_array.len() != 1 {
+            df_error_set(
+                error,
+                DFErrorCode::Plan,
+                &format!(
+                    "literal array must have only one element: {}",
+                    rs_array.len()
+                ),
+            );
+            return None;
+        }
+        let rs_value =
+            ScalarValue::try_from_array(&rs_array, 0).into_df_error(error, None)?;
+        Some(Box::new(DFExpr::new(lit(rs_value))))
+    })
+}
+
+/// \brief Create a new binary operator expression such as `left + right`.
+///
+/// \param left A left hand side `DFExpr`.
+/// \param operator An operator.
+/// \param right A right hand side `DFExpr`.
+/// \return A newly created `DFExpr`.
+///
+///   It should be freed by `df_expr_free()` when no longer needed.
+#[no_mangle]
+pub extern "C" fn df_expr_new_binary(
+    left: &DFExpr,
+    operator: DFOperator,
+    right: &DFExpr,
+) -> Box<DFExpr> {
+    df_abort_on_panic(|| {
+        Box::new(DFExpr::new(binary_expr(
+            left.expr.clone(),
+            operator.into(),
+            right.expr.clone(),
+        )))
+    })
+}
+
+/// \brief Create a new unary operator expression such as `NOT expr`
+/// and `expr IS NULL`.
+///
+/// \param operator An operator.
+/// \param expr An operand `DFExpr`.
+/// \return A newly created `DFExpr`.
+///
+///   It should be freed by `df_expr_free()` when no longer needed.
+#[no_mangle]
+pub extern "C" fn df_expr_new_unary(
+    operator: DFUnaryOperator,
+    expr: &DFExpr,
+) -> Box<DFExpr> {
+    df_abort_on_panic(|| {
+        let rs_expr = Box::new(expr.expr.clone());
+        let rs_unary_expr = match operator {
+            DFUnaryOperator::Not => Expr::Not(rs_expr),
+            DFUnaryOperator::Negative => Expr::Negative(rs_expr),
+            DFUnaryOperator::IsNull => Expr::IsNull(rs_expr),
+            DFUnaryOperator::IsNotNull => Expr::IsNotNull(rs_expr),
+            DFUnaryOperator::IsTrue => Expr::IsTrue(rs_expr),
+            DFUnaryOperator::IsFalse => Expr::IsFalse(rs_expr),
+            DFUnaryOperator::IsUnknown => Expr::IsUnknown(rs_expr),
+            DFUnaryOperator::IsNotTrue => Expr::IsNotTrue(rs_expr),
+            DFUnaryOperator::IsNotFalse => Expr::IsNotFalse(rs_expr),
+            DFUnaryOperator::IsNotUnknown => Expr::IsNotUnknown(rs_expr),
+        };
+        Box::new(DFExpr::new(rs_unary_expr))
+    })
+}
+
+/// \brief Create a new function call expression.
+///
+/// The function is looked up by name from UDFs and UDAFs registered
+/// to `context`, built-in scalar functions and built-in aggregate
+/// functions in this order.
+///
+/// \param context A `DFSessionContext` to look up UDFs and UDAFs or
+///   `NULL`. If this is `NULL`, only built-in functions are used.
+/// \param name A function name such as `abs` and `sum`.
+/// \param args Arguments of the function.
+/// \param n_args The number of `args`.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFExpr` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_expr_free()` when no longer needed.
+#[no_mangle]
+pub extern "C" fn df_expr_new_function(
+    context: Option<&DFSessionContext>,
+    name: *const libc::c_char,
+    args: *const *const DFExpr,
+    n_args: usize,
+    error: *mut *mut DFError,
+) -> Option<Box<DFExpr>> {
+    df_catch_panic(error, None, || {
+        let rs_name = c_string_to_str(name).into_df_error(error, None)?;
+        let rs_args = exprs_from_raw(args, n_args);
+        let rs_state = context.map(|context| context.context.state());
+        if let Some(rs_state) = rs_state.as_ref() {
+            if let Some(fun) = rs_state.scalar_functions().get(rs_name) {
+                let rs_expr = Expr::ScalarUDF {
+                    fun: fun.clone(),
+                    args: rs_args,
+                };
+                return Some(Box::new(DFExpr::new(rs_expr)));
+            }
+        }
+        if let Ok(fun) = BuiltinScalarFunction::from_str(rs_name) {
+            let rs_expr = Expr::ScalarFunction { fun, args: rs_args };
+            return Some(Box::new(DFExpr::new(rs_expr)));
+        }
+        if let Some(rs_state) = rs_state.as_ref() {
+            if let Some(fun) = rs_state.aggregate_functions().get(rs_name) {
+                let rs_expr = Expr::AggregateUDF {
+                    fun: fun.clone(),
+                    args: rs_args,
+                    filter: None,
+                };
+                return Some(Box::new(DFExpr::new(rs_expr)));
+            }
+        }
+        if let Ok(fun) = AggregateFunction::from_str(rs_name) {
+            let rs_expr = Expr::AggregateFunction(expr::AggregateFunction::new(
+                fun, rs_args, false, None,
+            ));
+            return Some(Box::new(DFExpr::new(rs_expr)));
+        }
+        df_error_set(
+            error,
+            DFErrorCode::Plan,
+            &format!("unknown function: {}", rs_name),
+        );
+        None
+    })
+}
+
+/// \brief Create a new `CASE` expression.
+///
+/// \param base A base `DFExpr` that is compared with `whens` or
+///   `NULL`. If this is `NULL`, `whens` must be boolean expressions.
+/// \param whens `WHEN` expressions.
+/// \param thens `THEN` expressions. The number of `thens` must be the
+///   same as `whens`.
+/// \param n_whens The number of `whens` and `thens`.
+/// \param else_ An `ELSE` expression or `NULL`.
+/// \return A newly created `DFExpr`.
+///
+///   It should be freed by `df_expr_free()` when no longer needed.
+#[no_mangle]
+pub extern "C" fn df_expr_new_case(
+    base: Option<&DFExpr>,
+    whens: *const *const DFExpr,
+    thens: *const *const DFExpr,
+    n_whens: usize,
+    else_: Option<&DFExpr>,
+) -> Box<DFExpr> {
+    df_abort_on_panic(|| {
+        let rs_when_then_exprs = exprs_from_raw(whens, n_whens)
+            .into_iter()
+            .zip(exprs_from_raw(thens, n_whens))
+            .map(|(when, then)| (Box::new(when), Box::new(then)))
+            .collect();
+        let rs_case = Case::new(
+            base.map(|base| Box::new(base.expr.clone())),
+            rs_when_then_exprs,
+            else_.map(|else_| Box::new(else_.expr.clone())),
+        );
+        Box::new(DFExpr::new(Expr::Case(rs_case)))
+    })
+}
+
+/// \brief Create a new `CAST` expression.
+///
+/// \param expr A `DFExpr` to be casted.
+/// \param c_abi_data_type The target data type. The content is
+///   moved. The caller must not release this.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFExpr` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_expr_free()` when no longer needed.
+#[no_mangle]
+pub extern "C" fn df_expr_new_cast(
+    expr: &DFExpr,
+    c_abi_data_type: &mut DFArrowSchema,
+    error: *mut *mut DFError,
+) -> Option<Box<DFExpr>> {
+    df_catch_panic(error, None, || {
+        let rs_data_type =
+            import_data_type(c_abi_data_type).into_df_error(error, None)?;
+        Some(Box::new(DFExpr::new(cast(expr.expr.clone(), rs_data_type))))
+    })
+}
+
+/// \brief Create a new `IN` list expression such as `expr IN (1, 2)`.
+///
+/// \param expr A `DFExpr` to be tested.
+/// \param list Candidate expressions.
+/// \param n_list The number of `list`.
+/// \param negated Whether `NOT IN` or not.
+/// \return A newly created `DFExpr`.
+///
+///   It should be freed by `df_expr_free()` when no longer needed.
+#[no_mangle]
+pub extern "C" fn df_expr_new_in_list(
+    expr: &DFExpr,
+    list: *const *const DFExpr,
+    n_list: usize,
+    negated: bool,
+) -> Box<DFExpr> {
+    df_abort_on_panic(|| {
+        Box::new(DFExpr::new(
+            expr.expr
+                .clone()
+                .in_list(exprs_from_raw(list, n_list), negated),
+        ))
+    })
+}
+
+/// \brief Create a new alias expression such as `expr AS name`.
+///
+/// \param expr A `DFExpr` to be aliased.
+/// \param name A new name.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFExpr` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_expr_free()` when no longer needed.
+#[no_mangle]
+pub extern "C" fn df_expr_new_alias(
+    expr: &DFExpr,
+    name: *const libc::c_char,
+    error: *mut *mut DFError,
+) -> Option<Box<DFExpr>> {
+    df_catch_panic(error, None, || {
+        let rs_name = c_string_to_str(name).into_df_error(error, None)?;
+        Some(Box::new(DFExpr::new(expr.expr.clone().alias(rs_name))))
+    })
+}
+
+/// \brief Create a new sort key expression for `df_data_frame_sort_exprs()`.
+///
+/// \param expr A `DFExpr` to be sorted by.
+/// \param ascending Whether ascending order or descending order.
+/// \param nulls_first Whether `NULL`s are placed at the first or last.
+/// \return A newly created `DFExpr`.
+///
+///   It should be freed by `df_expr_free()` when no longer needed.
+#[no_mangle]
+pub extern "C" fn df_expr_new_sort(
+    expr: &DFExpr,
+    ascending: bool,
+    nulls_first: bool,
+) -> Box<DFExpr> {
+    df_abort_on_panic(|| {
+        Box::new(DFExpr::new(expr.expr.clone().sort(ascending, nulls_first)))
+    })
+}
+
+/// \brief Create a new `CUBE` grouping set expression for
+/// `df_data_frame_aggregate()`.
+///
+/// \param exprs `DFExpr`s to be grouped by. All combinations of them
+///   are used as grouping sets.
+/// \param n_exprs The number of `exprs`.
+/// \return A newly created `DFExpr`.
+///
+///   It should be freed by `df_expr_free()` when no longer needed.
+#[no_mangle]
+pub extern "C" fn df_expr_new_cube(
+    exprs: *const *const DFExpr,
+    n_exprs: usize,
+) -> Box<DFExpr> {
+    df_abort_on_panic(|| Box::new(DFExpr::new(cube(exprs_from_raw(exprs, n_exprs)))))
+}
+
+/// \brief Create a new `ROLLUP` grouping set expression for
+/// `df_data_frame_aggregate()`.
+///
+/// \param exprs `DFExpr`s to be grouped by. All prefixes of them are
+///   used as grouping sets.
+/// \param n_exprs The number of `exprs`.
+/// \return A newly created `DFExpr`.
+///
+///   It should be freed by `df_expr_free()` when no longer needed.
+#[no_mangle]
+pub extern "C" fn df_expr_new_rollup(
+    exprs: *const *const DFExpr,
+    n_exprs: usize,
+) -> Box<DFExpr> {
+    df_abort_on_panic(|| Box::new(DFExpr::new(rollup(exprs_from_raw(exprs, n_exprs)))))
+}
+
+/// \brief Create a new `GROUPING SETS` expression for
+/// `df_data_frame_aggregate()`.
+///
+/// \param exprs `DFExpr`s of all grouping sets. They are
+///   concatenated. For example, `GROUPING SETS ((a, b), (c))` uses
+///   `{a, b, c}` for `exprs` and `{2, 1}` for `set_sizes`.
+/// \param set_sizes The number of `DFExpr`s in each grouping set.
+/// \param n_sets The number of grouping sets.
+/// \return A newly created `DFExpr`.
+///
+///   It should be freed by `df_expr_free()` when no longer needed.
+#[no_mangle]
+pub extern "C" fn df_expr_new_grouping_sets(
+    exprs: *const *const DFExpr,
+    set_sizes: *const usize,
+    n_sets: usize,
+) -> Box<DFExpr> {
+    df_abort_on_panic(|| {
+        let rs_set_sizes = if n_sets == 0 {
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts(set_sizes, n_sets) }
+        };
+        let n_exprs = rs_set_sizes.iter().sum();
+        let mut rs_exprs = exprs_from_raw(exprs, n_exprs).into_iter();
+        let rs_sets = rs_set_sizes
+            .iter()
+            .map(|size| rs_exprs.by_ref().take(*size).collect())
+            .collect();
+        Box::new(DFExpr::new(grouping_set(rs_sets)))
+    })
+}
+
+/// \brief Free the given `DFExpr`.
+///
+/// \param _expr A `DFExpr`.
+///
+/// # Safety
+///
+/// This function should not be called for the same `expr` multiple
+/// times.
+#[no_mangle]
+pub extern "C" fn df_expr_free(_expr: Option<Box<DFExpr>>) {}
+
+/// \brief Format the given `DFExpr` as string.
+///
+/// \param expr A `DFExpr`.
+/// \return A string representation of `expr` such as `a + Int64(1)`.
+///
+///   It should be freed by `free()` when no longer needed.
+#[no_mangle]
+pub extern "C" fn df_expr_to_string(expr: &DFExpr) -> *mut libc::c_char {
+    strdup(&expr.expr.to_string())
+}
+
+/// \brief Project the given expressions.
+///
+/// \param data_frame A `DFDataFrame`.
+/// \param exprs `DFExpr`s to be selected.
+/// \param n_exprs The number of `exprs`.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFDataFrame` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_data_frame_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_data_frame_select_exprs(
+    data_frame: &mut DFDataFrame,
+    exprs: *const *const DFExpr,
+    n_exprs: usize,
+    error: *mut *mut DFError,
+) -> Option<Box<DFDataFrame>> {
+    df_catch_panic(error, None, || {
+        let rs_data_frame = data_frame
+            .data_frame
+            .clone()
+            .select(exprs_from_raw(exprs, n_exprs))
+            .into_df_error(error, None)?;
+        Some(Box::new(DFDataFrame::new(
+            rs_data_frame,
+            data_frame.executor.clone(),
+        )))
+    })
+}
+
+/// \brief Filter rows by the given predicate.
+///
+/// \param data_frame A `DFDataFrame`.
+/// \param predicate A `DFExpr` that returns a boolean.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFDataFrame` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_data_frame_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_data_frame_filter_expr(
+    data_frame: &mut DFDataFrame,
+    predicate: &DFExpr,
+    error: *mut *mut DFError,
+) -> Option<Box<DFDataFrame>> {
+    df_catch_panic(error, None, || {
+        let rs_data_frame = data_frame
+            .data_frame
+            .clone()
+            .filter(predicate.expr.clone())
+            .into_df_error(error, None)?;
+        Some(Box::new(DFDataFrame::new(
+            rs_data_frame,
+            data_frame.executor.clone(),
+        )))
+    })
+}
+
+/// \brief Sort rows by the given sort keys.
+///
+/// \param data_frame A `DFDataFrame`.
+/// \param sort_exprs Sort keys created by `df_expr_new_sort()`.
+/// \param n_sort_exprs The number of `sort_exprs`.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFDataFrame` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_data_frame_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_data_frame_sort_exprs(
+    data_frame: &mut DFDataFrame,
+    sort_exprs: *const *const DFExpr,
+    n_sort_exprs: usize,
+    error: *mut *mut DFError,
+) -> Option<Box<DFDataFrame>> {
+    df_catch_panic(error, None, || {
+        let rs_data_frame = data_frame
+            .data_frame
+            .clone()
+            .sort(exprs_from_raw(sort_exprs, n_sort_exprs))
+            .into_df_error(error, None)?;
+        Some(Box::new(DFDataFrame::new(
+            rs_data_frame,
+            data_frame.executor.clone(),
+        )))
+    })
+}
+
+/// \brief Group rows and compute aggregations for each group.
+///
+/// \param data_frame A `DFDataFrame`.
+/// \param group_exprs `DFExpr`s to be grouped by. You can use
+///   `df_expr_new_cube()`, `df_expr_new_rollup()` and
+///   `df_expr_new_grouping_sets()` for `CUBE`, `ROLLUP` and `GROUPING
+///   SETS`.
+/// \param n_group_exprs The number of `group_exprs`. If this is `0`,
+///   all rows are aggregated as one group.
+/// \param aggr_exprs Aggregate function call `DFExpr`s created by
+///   `df_expr_new_function()` such as `count(a)` and `sum(b)`.
+/// \param n_aggr_exprs The number of `aggr_exprs`.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFDataFrame` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_data_frame_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_data_frame_aggregate(
+    data_frame: &mut DFDataFrame,
+    group_exprs: *const *const DFExpr,
+    n_group_exprs: usize,
+    aggr_exprs: *const *const DFExpr,
+    n_aggr_exprs: usize,
+    error: *mut *mut DFError,
+) -> Option<Box<DFDataFrame>> {
+    df_catch_panic(error, None, || {
+        let rs_data_frame = data_frame
+            .data_frame
+            .clone()
+            .aggregate(
+                exprs_from_raw(group_exprs, n_group_exprs),
+                exprs_from_raw(aggr_exprs, n_aggr_exprs),
+            )
+            .into_df_error(error, None)?;
+        Some(Box::new(DFDataFrame::new(
+            rs_data_frame,
+            data_frame.executor.clone(),
+        )))
+    })
+}
+
+/// \enum DFJoinType
+/// \brief Join types for `df_data_frame_join()`
+///
+/// cbindgen:prefix-with-name
+/// cbindgen:rename-all=ScreamingSnakeCase
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub enum DFJoinType {
+    #[allow(dead_code)]
+    Inner,
+    #[allow(dead_code)]
+    Left,
+    #[allow(dead_code)]
+    Right,
+    #[allow(dead_code)]
+    Full,
+    #[allow(dead_code)]
+    LeftSemi,
+    #[allow(dead_code)]
+    RightSemi,
+    #[allow(dead_code)]
+    LeftAnti,
+    #[allow(dead_code)]
+    RightAnti,
+}
+
+impl From<DFJoinType> for JoinType {
+    fn from(join_type: DFJoinType) -> Self {
+        match join_type {
+            DFJoinType::Inner => JoinType::Inner,
+            DFJoinType::Left => JoinType::Left,
+            DFJoinType::Right => JoinType::Right,
+            DFJoinType::Full => JoinType::Full,
+            DFJoinType::LeftSemi => JoinType::LeftSemi,
+            DFJoinType::RightSemi => JoinType::RightSemi,
+            DFJoinType::LeftAnti => JoinType::LeftAnti,
+            DFJoinType::RightAnti => JoinType::RightAnti,
+        }
+    }
+}
+
+/// \brief Join two data frames by equality of key columns.
+///
+/// \param left A left `DFDataFrame`.
+/// \param right A right `DFDataFrame`.
+/// \param join_type A join type.
+/// \param left_keys Key column names in `left`.
+/// \param right_keys Key column names in `right`. The number of
+///   `right_keys` must be the same as `left_keys`.
+/// \param n_keys The number of `left_keys` and `right_keys`.
+/// \param filter An additional join condition `DFExpr` or `NULL`.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFDataFrame` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_data_frame_free()` when no longer
+///   needed.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub extern "C" fn df_data_frame_join(
+    left: &mut DFDataFrame,
+    right: &mut DFDataFrame,
+    join_type: DFJoinType,
+    left_keys: *const *const libc::c_char,
+    right_keys: *const *const libc::c_char,
+    n_keys: usize,
+    filter: Option<&DFExpr>,
+    error: *mut *mut DFError,
+) -> Option<Box<DFDataFrame>> {
+    df_catch_panic(error, None, || {
+        let rs_left_keys =
+            c_strings_to_strs(left_keys, n_keys).into_df_error(error, None)?;
+        let rs_right_keys =
+            c_strings_to_strs(right_keys, n_keys).into_df_error(error, None)?;
+        let rs_data_frame = left
+            .data_frame
+            .clone()
+            .join(
+                right.data_frame.clone(),
+                join_type.into(),
+                &rs_left_keys,
+                &rs_right_keys,
+                filter.map(|filter| filter.expr.clone()),
+            )
+            .into_df_error(error, None)?;
+        Some(Box::new(DFDataFrame::new(
+            rs_data_frame,
+            left.executor.clone(),
+        )))
+    })
+}
+
+/// \brief Join two data frames by arbitrary conditions.
+///
+/// \param left A left `DFDataFrame`.
+/// \param right A right `DFDataFrame`.
+/// \param join_type A join type.
+/// \param on_exprs Join condition `DFExpr`s. They are combined by `AND`.
+/// \param n_on_exprs The number of `on_exprs`.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFDataFrame` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_data_frame_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_data_frame_join_on(
+    left: &mut DFDataFrame,
+    right: &mut DFDataFrame,
+    join_type: DFJoinType,
+    on_exprs: *const *const DFExpr,
+    n_on_exprs: usize,
+    error: *mut *mut DFError,
+) -> Option<Box<DFDataFrame>> {
+    df_catch_panic(error, None, || {
+        let rs_data_frame = left
+            .data_frame
+            .clone()
+            .join_on(
+                right.data_frame.clone(),
+                join_type.into(),
+                exprs_from_raw(on_exprs, n_on_exprs),
+            )
+            .into_df_error(error, None)?;
+        Some(Box::new(DFDataFrame::new(
+            rs_data_frame,
+            left.executor.clone(),
+        )))
+    })
+}
+
+fn check_set_operation_schemas(
+    left: &DataFrame,
+    right: &DataFrame,
+    operation: &str,
+) -> Result<(), DataFusionError> {
+    // DataFrame::intersect() and DataFrame::except() don't validate
+    // schemas. We validate them like DataFrame::union() does.
+    let left_schema = left.schema();
+    let right_schema = right.schema();
+    if left_schema.fields().len() != right_schema.fields().len() {
+        return Err(DataFusionError::Plan(format!(
+            "{} queries must have the same number of columns, (left is {}, right is {})",
+            operation,
+            left_schema.fields().len(),
+            right_schema.fields().len()
+        )));
+    }
+    for (left_field, right_field) in left_schema
+        .fields()
+        .iter()
+        .zip(right_schema.fields().iter())
+    {
+        if comparison_coercion(left_field.data_type(), right_field.data_type()).is_none()
+        {
+            return Err(DataFusionError::Plan(format!(
+                "{} Column {} (type: {}) is not compatible with column {} (type: {})",
+                operation,
+                right_field.name(),
+                right_field.data_type(),
+                left_field.name(),
+                left_field.data_type()
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// \brief Concatenate rows of two data frames.
+///
+/// Duplicated rows are kept. This is `UNION ALL` in SQL.
+///
+/// \param left A left `DFDataFrame`.
+/// \param right A right `DFDataFrame`. It must have the same number
+///   of columns as `left` and compatible column types. Otherwise, a
+///   `DF_ERROR_CODE_PLAN` error is reported.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFDataFrame` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_data_frame_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_data_frame_union(
+    left: &mut DFDataFrame,
+    right: &mut DFDataFrame,
+    error: *mut *mut DFError,
+) -> Option<Box<DFDataFrame>> {
+    df_catch_panic(error, None, || {
+        let rs_data_frame = left
+            .data_frame
+            .clone()
+            .union(right.data_frame.clone())
+            .into_df_error(error, None)?;
+        Some(Box::new(DFDataFrame::new(
+            rs_data_frame,
+            left.executor.clone(),
+        )))
+    })
+}
+
+/// \brief Concatenate rows of two data frames and remove duplicated
+/// rows.
+///
+/// This is `UNION` in SQL.
+///
+/// \param left A left `DFDataFrame`.
+/// \param right A right `DFDataFrame`. It must have the same number
+///   of columns as `left` and compatible column types. Otherwise, a
+///   `DF_ERROR_CODE_PLAN` error is reported.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFDataFrame` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_data_frame_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_data_frame_union_distinct(
+    left: &mut DFDataFrame,
+    right: &mut DFDataFrame,
+    error: *mut *mut DFError,
+) -> Option<Box<DFDataFrame>> {
+    df_catch_panic(error, None, || {
+        let rs_data_frame = left
+            .data_frame
+            .clone()
+            .union_distinct(right.data_frame.clone())
+            .into_df_error(error, None)?;
+        Some(Box::new(DFDataFrame::new(
+            rs_data_frame,
+            left.executor.clone(),
+        )))
+    })
+}
+
+/// \brief Compute rows in the left data frame that also exist in the
+/// right data frame.
+///
+/// All matched rows in `left` are kept even if they are duplicated.
+/// The number of matched rows in `right` isn't considered. For
+/// example, `[1, 1, 2]` and `[1, 3]` returns `[1, 1]`. Note that this
+/// isn't the same as `INTERSECT ALL` in SQL that returns `[1]`.
+///
+/// \param left A left `DFDataFrame`.
+/// \param right A right `DFDataFrame`. It must have the same number
+///   of columns as `left` and compatible column types. Otherwise, a
+///   `DF_ERROR_CODE_PLAN` error is reported.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFDataFrame` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_data_frame_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_data_frame_intersect(
+    left: &mut DFDataFrame,
+    right: &mut DFDataFrame,
+    error: *mut *mut DFError,
+) -> Option<Box<DFDataFrame>> {
+    df_catch_panic(error, None, || {
+        check_set_operation_schemas(&left.data_frame, &right.data_frame, "INTERSECT")
+            .into_df_error(error, None)?;
+        let rs_data_frame = left
+            .data_frame
+            .clone()
+            .intersect(right.data_frame.clone())
+            .into_df_error(error, None)?;
+        Some(Box::new(DFDataFrame::new(
+            rs_data_frame,
+            left.executor.clone(),
+        )))
+    })
+}
+
+/// \brief Compute rows in `left` that don't exist in `right`.
+///
+/// All rows in `left` that match any row in `right` are removed. Only
+/// duplicated rows that don't exist in `right` are kept. For example,
+/// `[1, 1, 2]` and `[1, 3]` returns `[2]`. Note that this isn't the
+/// same as `EXCEPT ALL` in SQL that returns `[1, 2]`.
+///
+/// \param left A left `DFDataFrame`.
+/// \param right A right `DFDataFrame`. It must have the same number
+///   of columns as `left` and compatible column types. Otherwise, a
+///   `DF_ERROR_CODE_PLAN` error is reported.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFDataFrame` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_data_frame_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_data_frame_except(
+    left: &mut DFDataFrame,
+    right: &mut DFDataFrame,
+    error: *mut *mut DFError,
+) -> Option<Box<DFDataFrame>> {
+    df_catch_panic(error, None, || {
+        check_set_operation_schemas(&left.data_frame, &right.data_frame, "EXCEPT")
+            .into_df_error(error, None)?;
+        let rs_data_frame = left
+            .data_frame
+            .clone()
+            .except(right.data_frame.clone())
+            .into_df_error(error, None)?;
+        Some(Box::new(DFDataFrame::new(
+            rs_data_frame,
+            left.executor.clone(),
+        )))
+    })
+}
+
+/// \brief Remove duplicated rows.
+///
+/// \param data_frame A `DFDataFrame`.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFDataFrame` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_data_frame_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_data_frame_distinct(
+    data_frame: &mut DFDataFrame,
+    error: *mut *mut DFError,
+) -> Option<Box<DFDataFrame>> {
+    df_catch_panic(error, None, || {
+        let rs_data_frame = data_frame
+            .data_frame
+            .clone()
+            .distinct()
+            .into_df_error(error, None)?;
+        Some(Box::new(DFDataFrame::new(
+            rs_data_frame,
+            data_frame.executor.clone(),
+        )))
+    })
+}
+
+/// \brief Add a new column or replace an existing column.
+///
+/// \param data_frame A `DFDataFrame`.
+/// \param name A column name. If `data_frame` already has a column
+///   with the same name, the column is replaced. Otherwise, a new column
+///   is added at the end.
+/// \param expr A `DFExpr` that computes the column values.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFDataFrame` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_data_frame_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_data_frame_with_column(
+    data_frame: &mut DFDataFrame,
+    name: *const libc::c_char,
+    expr: &DFExpr,
+    error: *mut *mut DFError,
+) -> Option<Box<DFDataFrame>> {
+    df_catch_panic(error, None, || {
+        let rs_name = c_string_to_str(name).into_df_error(error, None)?;
+        let rs_data_frame = data_frame
+            .data_frame
+            .clone()
+            .with_column(rs_name, expr.expr.clone())
+            .into_df_error(error, None)?;
+        Some(Box::new(DFDataFrame::new(
+            rs_data_frame,
+            data_frame.executor.clone(),
+        )))
+    })
+}
+
+/// \brief Rename a column.
+///
+/// \param data_frame A `DFDataFrame`.
+/// \param old_name The current column name. It may be qualified by
+///   relation name such as `table.column`. If there is no such
+///   column, a `DF_ERROR_CODE_SCHEMA` error is reported.
+/// \param new_name A new column name.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFDataFrame` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_data_frame_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_data_frame_with_column_renamed(
+    data_frame: &mut DFDataFrame,
+    old_name: *const libc::c_char,
+    new_name: *const libc::c_char,
+    error: *mut *mut DFError,
+) -> Option<Box<DFDataFrame>> {
+    df_catch_panic(error, None, || {
+        let rs_old_name = c_string_to_str(old_name).into_df_error(error, None)?;
+        let rs_new_name = c_string_to_str(new_name).into_df_error(error, None)?;
+        // DataFrame::with_column_renamed() only accepts a qualified
+        // name and ignores unknown names. We resolve the given name
+        // first to accept an unqualified name and report an unknown
+        // name.
+        let rs_old_qualified_name = data_frame
+            .data_frame
+            .schema()
+            .field_from_column(&Column::from_qualified_name(rs_old_name))
+            .into_df_error(error, None)?
+            .qualified_name();
+        let rs_data_frame = data_frame
+            .data_frame
+            .clone()
+            .with_column_renamed(&rs_old_qualified_name, rs_new_name)
+            .into_df_error(error, None)?;
+        Some(Box::new(DFDataFrame::new(
+            rs_data_frame,
+            data_frame.executor.clone(),
+        )))
+    })
+}
+
+/// \brief Remove the given columns.
+///
+/// \param data_frame A `DFDataFrame`.
+/// \param columns Column names to be removed. They may be qualified by
+///   relation name such as `table.column`. If there is an unknown
+///   column, a `DF_ERROR_CODE_SCHEMA` error is reported.
+/// \param n_columns The number of `columns`.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFDataFrame` on success, `NULL` otherwise.
+///
+///   It should be freed by `df_data_frame_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_data_frame_drop_columns(
+    data_frame: &mut DFDataFrame,
+    columns: *const *const libc::c_char,
+    n_columns: usize,
+    error: *mut *mut DFError,
+) -> Option<Box<DFDataFrame>> {
+    df_catch_panic(error, None, || {
+        let rs_columns =
+            c_strings_to_strs(columns, n_columns).into_df_error(error, None)?;
+        let rs_schema = data_frame.data_frame.schema();
+        let mut rs_drop_indices = vec![];
+        for rs_column in rs_columns {
+            let rs_index = rs_schema
+                .index_of_column(&Column::from_qualified_name(rs_column))
+                .into_df_error(error, None)?;
+            rs_drop_indices.push(rs_index);
+        }
+        let rs_exprs = rs_schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !rs_drop_indices.contains(i))
+            .map(|(_, field)| Expr::Column(field.qualified_column()))
+            .collect();
+        let rs_data_frame = data_frame
+            .data_frame
+            .clone()
+            .select(rs_exprs)
+            .into_df_error(error, None)?;
+        Some(Box::new(DFDataFrame::new(
+            rs_data_frame,
+            data_frame.executor.clone(),
+        )))
+    })
+}
+
+/// \brief Get the output schema of the given data frame.
+///
+/// This doesn't execute the query. The schema is computed from the
+/// logical plan. Field nullability and metadata are also
+/// exported. Note that DataFusion 21 doesn't keep metadata of
+/// top-level fields in projections such as `SELECT * FROM table`.
+///
+/// \param data_frame A `DFDataFrame`.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFArrowSchema` on success, `NULL` otherwise.
+///
+///   It should be released by the `release` callback and freed by
+///   `free()` when no longer needed.
+#[no_mangle]
+pub extern "C" fn df_data_frame_get_schema(
+    data_frame: &mut DFDataFrame,
+    error: *mut *mut DFError,
+) -> Option<Box<DFArrowSchema>> {
+    df_catch_panic(error, None, || {
+        let rs_schema = Schema::from(data_frame.data_frame.schema());
+        export_schema(&rs_schema).into_df_error(error, None)
+    })
+}
+
+/// \brief Explain the plan of the given data frame.
+///
+/// \param data_frame A `DFDataFrame`.
+/// \param verbose Whether all intermediate plans are included or not.
+/// \param analyze Whether the plan is executed to collect execution
+///   metrics or not. This is `EXPLAIN ANALYZE` in SQL.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFDataFrame` on success, `NULL` otherwise.
+///   It has `plan_type` and `plan` columns.
+///
+///   It should be freed by `df_data_frame_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_data_frame_explain(
+    data_frame: &mut DFDataFrame,
+    verbose: bool,
+    analyze: bool,
+    error: *mut *mut DFError,
+) -> Option<Box<DFDataFrame>> {
+    df_catch_panic(error, None, || {
+        let rs_data_frame = data_frame
+            .data_frame
+            .clone()
+            .explain(verbose, analyze)
+            .into_df_error(error, None)?;
+        Some(Box::new(DFDataFrame::new(
+            rs_data_frame,
+            data_frame.executor.clone(),
+        )))
+    })
+}
+
+/// \enum DFPlanFormat
+/// \brief Formats for `df_data_frame_format_logical_plan()` and so on.
+///
+/// cbindgen:prefix-with-name
+/// cbindgen:rename-all=ScreamingSnakeCase
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub enum DFPlanFormat {
+    /// Indented text.
+    #[allow(dead_code)]
+    Indent,
+    /// The DOT language for Graphviz.
+    #[allow(dead_code)]
+    Graphviz,
+}
+
+fn format_logical_plan(plan: &LogicalPlan, format: DFPlanFormat) -> String {
+    match format {
+        DFPlanFormat::Indent => plan.display_indent().to_string(),
+        DFPlanFormat::Graphviz => plan.display_graphviz().to_string(),
+    }
+}
+
+/// \brief Format the logical plan of the given data frame.
+///
+/// \param data_frame A `DFDataFrame`.
+/// \param format A format of the returned string.
+/// \return A string representation of the logical plan.
+///
+///   It should be freed by `free()` when no longer needed.
+#[no_mangle]
+pub extern "C" fn df_data_frame_format_logical_plan(
+    data_frame: &mut DFDataFrame,
+    format: DFPlanFormat,
+) -> *mut libc::c_char {
+    df_abort_on_panic(|| {
+        strdup(&format_logical_plan(
+            data_frame.data_frame.logical_plan(),
+            format,
+        ))
+    })
+}
+
+/// \brief Format the optimized logical plan of the given data frame.
+///
+/// \param data_frame A `DFDataFrame`.
+/// \param format A format of the returned string.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A string representation of the optimized logical plan on
+///   success, `NULL` otherwise.
+///
+///   It should be freed by `free()` when no longer needed.
+#[no_mangle]
+pub extern "C" fn df_data_frame_format_optimized_logical_plan(
+    data_frame: &mut DFDataFrame,
+    format: DFPlanFormat,
+    error: *mut *mut DFError,
+) -> *mut libc::c_char {
+    df_catch_panic(error, std::ptr::null_mut(), || {
+        let option = || -> Option<*mut libc::c_char> {
+            let rs_plan = data_frame
+                .data_frame
+                .clone()
+                .into_optimized_plan()
+                .into_df_error(error, None)?;
+            Some(strdup(&format_logical_plan(&rs_plan, format)))
+        }();
+        option.unwrap_or(std::ptr::null_mut())
+    })
+}
+
+fn append_physical_plan_graphviz(
+    output: &mut String,
+    plan: &Arc<dyn ExecutionPlan>,
+    next_id: &mut usize,
+    parent_id: Option<usize>,
+) {
+    let id = *next_id;
+    *next_id += 1;
+    let label = displayable(plan.as_ref()).one_line().to_string();
+    output.push_str(&format!(
+        "    {}[shape=box label=\"{}\"]\n",
+        id,
+        label.trim_end().replace('"', "_")
+    ));
+    if let Some(parent_id) = parent_id {
+        output.push_str(&format!(
+            "    {parent_id} -> {id} [arrowhead=none, arrowtail=normal, dir=back]\n"
+        ));
+    }
+    for child in plan.children() {
+        append_physical_plan_graphviz(output, &child, next_id, Some(id));
+    }
+}
+
+fn format_physical_plan(plan: &Arc<dyn ExecutionPlan>, format: DFPlanFormat) -> String {
+    match format {
+        DFPlanFormat::Indent => displayable(plan.as_ref()).indent().to_string(),
+        DFPlanFormat::Graphviz => {
+            // Use the same layout as LogicalPlan::display_graphviz().
+            let mut output = String::new();
+            output.push_str(
+                "// Begin DataFusion GraphViz Plan (see https://graphviz.org)\n",
+            );
+            output.push_str("digraph {\n");
+            output.push_str("  subgraph cluster_1\n");
+            output.push_str("  {\n");
+            output.push_str("    graph[label=\"ExecutionPlan\"]\n");
+            let mut next_id = 2;
+            append_physical_plan_graphviz(&mut output, plan, &mut next_id, None);
+            output.push_str("  }\n");
+            output.push_str("}\n");
+            output.push_str("// End DataFusion GraphViz Plan\n");
+            output
+        }
+    }
+}
+
+/// \brief Format the physical plan of the given data frame.
+///
+/// \param data_frame A `DFDataFrame`.
+/// \param format A format of the returned string.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A string representation of the physical plan on success,
+///   `NULL` otherwise.
+///
+///   It should be freed by `free()` when no longer needed.
+#[no_mangle]
+pub extern "C" fn df_data_frame_format_physical_plan(
+    data_frame: &mut DFDataFrame,
+    format: DFPlanFormat,
+    error: *mut *mut DFError,
+) -> *mut libc::c_char {
+    df_catch_panic(error, std::ptr::null_mut(), || {
+        let option = || -> Option<*mut libc::c_char> {
+            let future = data_frame.data_frame.clone().create_physical_plan();
+            let rs_plan = data_frame
+                .executor
+                .block_on(future)
+                .into_df_error(error, None)?;
+            Some(strdup(&format_physical_plan(&rs_plan, format)))
+        }();
+        option.unwrap_or(std::ptr::null_mut())
+    })
+}
+
+/// \brief Show the given data frame contents to the standard output.
+///
+/// \param data_frame A `DFDataFrame` to be shown.
+/// \param error Return location for a `DFError` or `NULL`.
+#[no_mangle]
+pub extern "C" fn df_data_frame_show(
+    data_frame: &mut DFDataFrame,
+    error: *mut *mut DFError,
+) {
+    df_catch_panic(error, (), || {
+        let future = data_frame.data_frame.clone().show();
+        data_frame
+            .executor
+            .block_on(future)
+            .into_df_error(error, None);
+    })
+}
+
+/// \brief Write the given data frame contents as Apache Parquet format.
+///
+/// \param data_frame A `DFDataFrame` to be written.
+/// \param path An output path.
+/// \param writer_properties Properties how to write Apache Parquet files.
+/// \param error Return location for a `DFError` or `NULL`.
+#[no_mangle]
+pub extern "C" fn df_data_frame_write_parquet(
+    data_frame: &mut DFDataFrame,
+    path: *const libc::c_char,
+    writer_properties: Option<&DFParquetWriterProperties>,
+    error: *mut *mut DFError,
+) -> bool {
+    df_catch_panic(error, false, || {
+        let maybe_success = || -> Option<bool> {
+            let cstr_path = unsafe { CStr::from_ptr(path) };
+            let rs_path = cstr_path.to_str().into_df_error(error, None)?;
+            let maybe_rs_writer_properties =
+                writer_properties.map(|properties| properties.build());
+            let future = data_frame
+                .data_frame
+                .clone()
+                .write_parquet(rs_path, maybe_rs_writer_properties);
+            data_frame
+                .executor
+                .block_on(future)
+                .into_df_error(error, None)?;
+            Some(true)
+        }();
+        maybe_success.unwrap_or(false)
+    })
+}
+
+/// \brief Execute the given data frame as a stream.
+///
+/// Record batches are computed on demand when the consumer calls
+/// `get_next` of the stream. So the whole result isn't materialized
+/// in memory.
+///
+/// \param data_frame A `DFDataFrame` to be executed.
+/// \param c_abi_stream_out Return location for the result stream.
+///   It must be released by its `release` callback when no longer
+///   needed.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return `true` on success, `false` otherwise.
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn df_data_frame_execute_stream(
+    data_frame: &mut DFDataFrame,
+    c_abi_stream_out: *mut DFArrowArrayStream,
+    error: *mut *mut DFError,
+) -> bool {
+    df_catch_panic(error, false, || {
+        let option = || -> Option<bool> {
+            let future = data_frame.data_frame.clone().execute_stream();
+            let rs_stream = data_frame
+                .executor
+                .block_on(future)
+                .into_df_error(error, None)?;
+            let rs_reader = DFRecordBatchReader {
+                schema: rs_stream.schema(),
+                stream: rs_stream,
+                executor: data_frame.executor.clone(),
+            };
+            unsafe {
+                export_reader_into_raw(
+                    Box::new(rs_reader),
+                    c_abi_stream_out as *mut FFI_ArrowArrayStream,
+                );
+            }
+            Some(true)
+        }();
+        option.unwrap_or(false)
+    })
+}
+
+// The returned record batches array is allocated by malloc(). The
+// schema is exported even when there are no record batches.
+fn export_record_batches(
+    rs_schema: &Schema,
+    rs_record_batches: Vec<RecordBatch>,
+) -> Result<(*mut DFArrowSchema, *mut *mut DFArrowArray, usize), ArrowError> {
+    let rs_ffi_schema =
+        FFI_ArrowSchema::try_from(DataType::Struct(rs_schema.fields().clone()))?;
+    let n = rs_record_batches.len();
+    let c_abi_record_batches = unsafe {
+        libc::malloc(std::mem::size_of::<*mut DFArrowArray>() * n)
+            as *mut *mut DFArrowArray
+    };
+    let c_abi_record_batch_slice =
+        unsafe { std::slice::from_raw_parts_mut(c_abi_record_batches, n) };
+    for (i, rs_record_batch) in rs_record_batches.into_iter().enumerate() {
+        let rs_struct_array = StructArray::from(rs_record_batch);
+        let rs_ffi_array = FFI_ArrowArray::new(&rs_struct_array.data().clone());
+        c_abi_record_batch_slice[i] =
+            Box::into_raw(Box::new(rs_ffi_array)) as *mut DFArrowArray;
+    }
+    let c_abi_schema = Box::into_raw(Box::new(rs_ffi_schema)) as *mut DFArrowSchema;
+    Ok((c_abi_schema, c_abi_record_batches, n))
+}
+
+fn data_frame_collect_schema(
+    data_frame: &DataFrame,
+    rs_record_batches: &[RecordBatch],
+) -> SchemaRef {
+    match rs_record_batches.first() {
+        Some(rs_record_batch) => rs_record_batch.schema(),
+        None => Arc::new(data_frame.schema().into()),
+    }
+}
+
+/// \brief Execute the given data frame and export the result.
+///
+/// \param data_frame A `DFDataFrame`.
+/// \param c_abi_schema_out Return location for the schema of the
+///   result. It's set even when the result has no record batch. It
+///   must be released and freed by the caller.
+/// \param c_abi_record_batches_out Return location for the record
+///   batches of the result. Each record batch must be released and
+///   freed by the caller. The array itself must be freed by `free()`.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return The number of record batches on success, `-1` otherwise.
+#[no_mangle]
+pub extern "C" fn df_data_frame_export(
+    data_frame: &mut DFDataFrame,
+    c_abi_schema_out: *mut *mut DFArrowSchema,
+    c_abi_record_batches_out: *mut *mut *mut DFArrowArray,
+    error: *mut *mut DFError,
+) -> i64 {
+    df_catch_panic(error, -1, || {
+        let option = || -> Option<i64> {
+            let future = data_frame.data_frame.clone().collect();
+            let rs_record_batches = data_frame
+                .executor
+                .block_on(future)
+                .into_df_error(error, None)?;
+            let rs_schema =
+                data_frame_collect_schema(&data_frame.data_frame, &rs_record_batches);
+            let (c_abi_schema, c_abi_record_batches, n) =
+                export_record_batches(&rs_schema, rs_record_batches)
+                    .into_df_error(error, None)?;
+            unsafe {
+                *c_abi_schema_out = c_abi_schema;
+                *c_abi_record_batches_out = c_abi_record_batches;
+            }
+            Some(n as i64)
+        }();
+        option.unwrap_or(-1)
+    })
+}
+
+/// \brief A callback that receives the result of
+/// `df_data_frame_collect_async()`.
+///
+/// \param schema The schema of `record_batches` on success, `NULL` on
+///   error. It must be released and freed by the callback.
+/// \param record_batches The collected record batches on success,
+///   `NULL` on error. Each record batch must be released and freed by
+///   the callback. The array itself must be freed by `free()`.
+/// \param n_record_batches The number of `record_batches` on success,
+///   `-1` on error.
+/// \param error A `DFError` on error, `NULL` on success. It must be
+///   freed by `df_error_free()`.
+/// \param user_data The `user_data` passed to
+///   `df_data_frame_collect_async()`.
+pub type DFDataFrameCollectCallback = extern "C" fn(
+    schema: *mut DFArrowSchema,
+    record_batches: *mut *mut DFArrowArray,
+    n_record_batches: i64,
+    error: *mut DFError,
+    user_data: *mut libc::c_void,
+);
+
+/// \brief Collect the given `DFDataFrame` asynchronously.
+///
+/// This returns immediately. The data frame is executed on the Tokio
+/// runtime of the data frame and `callback` is called with the result
+/// of `df_data_frame_export()` in a thread of the runtime. The
+/// `DFCancellationToken` of the data frame is respected.
+///
+/// `callback` must not call functions that block on a Tokio runtime
+/// such as `df_data_frame_export()` and `df_session_context_sql()`.
+/// They report a `DF_ERROR_CODE_PANIC` error in a thread of the
+/// runtime. Pass the result to another thread to use it for them.
+///
+/// \param data_frame A `DFDataFrame`.
+/// \param cancellation_token A `DFCancellationToken` only for this
+///   call or `NULL`. The `DFCancellationToken` of the data frame is
+///   also respected.
+/// \param callback A callback that receives the result. It's called
+///   only once when this function returns `true`. It's never called
+///   when this function returns `false`.
+/// \param user_data Data passed to `callback`.
+/// \param destroy_notify A callback to free `user_data` or `NULL`. It's
+///   called after `callback` is called or when this function returns
+///   `false`.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return `true` if the execution is started, `false` otherwise.
+#[no_mangle]
+pub extern "C" fn df_data_frame_collect_async(
+    data_frame: &mut DFDataFrame,
+    cancellation_token: Option<&DFCancellationToken>,
+    callback: DFDataFrameCollectCallback,
+    user_data: *mut libc::c_void,
+    destroy_notify: DFDestroyNotify,
+    error: *mut *mut DFError,
+) -> bool {
+    df_catch_panic(error, false, || {
+        let rs_user_data = DFUserData {
+            data: user_data,
+            destroy_notify,
+        };
+        let rs_data_frame = data_frame.data_frame.clone();
+        let future = run_with_cancellation_token(
+            cancellation_token.cloned(),
+            rs_data_frame.clone().collect(),
+        );
+        let result = data_frame.executor.spawn(future, move |result| {
+            let rs_user_data = rs_user_data;
+            let result = result.and_then(|rs_record_batches| {
+                let rs_schema =
+                    data_frame_collect_schema(&rs_data_frame, &rs_record_batches);
+                Ok(export_record_batches(&rs_schema, rs_record_batches)?)
+            });
+            match result {
+                Ok((c_abi_schema, c_abi_record_batches, n)) => callback(
+                    c_abi_schema,
+                    c_abi_record_batches,
+                    n as i64,
+                    std::ptr::null_mut(),
+                    rs_user_data.data,
+                ),
+                Err(e) => {
+                    let mut c_error = std::ptr::null_mut();
+                    Err::<(), _>(e).into_df_error(&mut c_error, None);
+                    callback(
+                        std::ptr::null_mut(),
+                        std::ptr::null_mut(),
+                        -1,
+                        c_error,
+                        rs_user_data.data,
+                    );
+                }
+            }
+        });
+        result.into_df_error(error, None).is_some()
+    })
+}
+
+/// \struct DFPhysicalPlan
+/// \brief A struct for physical plan created from `DFDataFrame`.
+///
+/// You can execute this by `df_physical_plan_execute_stream()`. You
+/// can get execution metrics by `df_physical_plan_export_metrics()`
+/// after the execution.
+///
+/// You need to free this by `df_physical_plan_free()` when no longer
+/// needed.
+pub struct DFPhysicalPlan {
+    plan: Arc<dyn ExecutionPlan>,
+    task_context: Arc<TaskContext>,
+    executor: DFExecutor,
+}
+
+impl DFPhysicalPlan {
+    pub fn new(
+        plan: Arc<dyn ExecutionPlan>,
+        task_context: Arc<TaskContext>,
+        executor: DFExecutor,
+    ) -> Self {
+        Self {
+            plan,
+            task_context,
+            executor,
+        }
+    }
+}
+
+/// \brief Create a physical plan of the given data frame.
+///
+/// \param data_frame A `DFDataFrame`.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFPhysicalPlan` on success, `NULL`
+///   otherwise.
+///
+///   It should be freed by `df_physical_plan_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_data_frame_create_physical_plan(
+    data_frame: &mut DFDataFrame,
+    error: *mut *mut DFError,
+) -> Option<Box<DFPhysicalPlan>> {
+    df_catch_panic(error, None, || {
+        let (rs_state, _) = data_frame.data_frame.clone().into_parts();
+        let future = data_frame.data_frame.clone().create_physical_plan();
+        let rs_plan = data_frame
+            .executor
+            .block_on(future)
+            .into_df_error(error, None)?;
+        Some(Box::new(DFPhysicalPlan::new(
+            rs_plan,
+            Arc::new(TaskContext::from(&rs_state)),
+            data_frame.executor.clone(),
+        )))
+    })
+}
+
+/// \brief Free the given `DFPhysicalPlan`.
+///
+/// \param _plan A `DFPhysicalPlan`.
+///
+/// # Safety
+///
+/// This function should not be called for the same `plan` multiple
+/// times.
+#[no_mangle]
+pub extern "C" fn df_physical_plan_free(_plan: Option<Box<DFPhysicalPlan>>) {}
+
+/// \brief Execute the given physical plan as a stream.
+///
+/// Execution metrics are collected while the stream is read. If you
+/// execute the same plan multiple times, metrics are accumulated.
+///
+/// \param plan A `DFPhysicalPlan` to be executed.
+/// \param c_abi_stream_out Return location for the result stream.
+///   It must be released by its `release` callback when no longer
+///   needed.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return `true` on success, `false` otherwise.
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn df_physical_plan_execute_stream(
+    plan: &mut DFPhysicalPlan,
+    c_abi_stream_out: *mut DFArrowArrayStream,
+    error: *mut *mut DFError,
+) -> bool {
+    df_catch_panic(error, false, || {
+        let option = || -> Option<bool> {
+            let rs_plan = plan.plan.clone();
+            let rs_task_context = plan.task_context.clone();
+            let rs_stream = plan
+                .executor
+                .block_on(async { execute_stream(rs_plan, rs_task_context) })
+                .into_df_error(error, None)?;
+            let rs_reader = DFRecordBatchReader {
+                schema: rs_stream.schema(),
+                stream: rs_stream,
+                executor: plan.executor.clone(),
+            };
+            unsafe {
+                export_reader_into_raw(
+                    Box::new(rs_reader),
+                    c_abi_stream_out as *mut FFI_ArrowArrayStream,
+                );
+            }
+            Some(true)
+        }();
+        option.unwrap_or(false)
+    })
+}
+
+struct DFMetricsBuilder {
+    operator_ids: UInt64Builder,
+    parent_ids: UInt64Builder,
+    operators: StringBuilder,
+    partitions: UInt64Builder,
+    names: StringBuilder,
+    labels: StringBuilder,
+    values: UInt64Builder,
+    next_operator_id: u64,
+}
+
+impl DFMetricsBuilder {
+    fn new() -> Self {
+        Self {
+            operator_ids: UInt64Builder::new(),
+            parent_ids: UInt64Builder::new(),
+            operators: StringBuilder::new(),
+            partitions: UInt64Builder::new(),
+            names: StringBuilder::new(),
+            labels: StringBuilder::new(),
+            values: UInt64Builder::new(),
+            next_operator_id: 0,
+        }
+    }
+
+    fn append(&mut self, plan: &Arc<dyn ExecutionPlan>, parent_id: Option<u64>) {
+        let operator_id = self.next_operator_id;
+        self.next_operator_id += 1;
+        if let Some(metrics) = plan.metrics() {
+            let operator = displayable(plan.as_ref()).one_line().to_string();
+            for metric in metrics.iter() {
+                self.operator_ids.append_value(operator_id);
+                self.parent_ids.append_option(parent_id);
+                self.operators.append_value(operator.trim_end());
+                self.partitions
+                    .append_option(metric.partition().map(|partition| partition as u64));
+                self.names.append_value(metric.value().name());
+                if metric.labels().is_empty() {
+                    self.labels.append_null();
+                } else {
+                    let metric_labels: Vec<String> = metric
+                        .labels()
+                        .iter()
+                        .map(|label| label.to_string())
+                        .collect();
+                    self.labels.append_value(metric_labels.join(", "));
+                }
+                self.values.append_value(metric.value().as_usize() as u64);
+            }
+        }
+        for child in plan.children() {
+            self.append(&child, Some(operator_id));
+        }
+    }
+
+    fn schema() -> Schema {
+        Schema::new(vec![
+            Field::new("operator_id", DataType::UInt64, false),
+            Field::new("parent_id", DataType::UInt64, true),
+            Field::new("operator", DataType::Utf8, false),
+            Field::new("partition", DataType::UInt64, true),
+            Field::new("name", DataType::Utf8, false),
+            Field::new("labels", DataType::Utf8, true),
+            Field::new("value", DataType::UInt64, false),
+        ])
+    }
+
+    fn finish(mut self) -> Result<RecordBatch, ArrowError> {
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(self.operator_ids.finish()),
+            Arc::new(self.parent_ids.finish()),
+            Arc::new(self.operators.finish()),
+            Arc::new(self.partitions.finish()),
+            Arc::new(self.names.finish()),
+            Arc::new(self.labels.finish()),
+            Arc::new(self.values.finish()),
+        ];
+        RecordBatch::try_new(Arc::new(Self::schema()), columns)
+    }
+}
+
+/// \brief Export execution metrics of the given physical plan.
+///
+/// Metrics are exported as a record batch that has the following
+/// columns. Each row is a metric value of an operator.
+///
+/// * `operator_id` (`uint64`): The ID of the operator. Operators are
+///   numbered in depth-first pre-order from `0` for the root.
+/// * `parent_id` (`uint64`): The ID of the parent operator. `NULL` for
+///   the root.
+/// * `operator` (`utf8`): The description of the operator such as
+///   `FilterExec: a@0 > 1`.
+/// * `partition` (`uint64`): The partition of the metric. `NULL` for
+///   a metric that isn't related to a partition.
+/// * `name` (`utf8`): The metric name such as `output_rows`,
+///   `elapsed_compute`, `spill_count`, `spilled_bytes` and
+///   `bytes_scanned`.
+/// * `labels` (`utf8`): The labels of the metric such as
+///   `filename=data.parquet`. `NULL` for a metric without labels.
+/// * `value` (`uint64`): The metric value. Elapsed times are
+///   nanoseconds. Timestamps are nanoseconds since the UNIX epoch.
+///
+/// \param plan A `DFPhysicalPlan`. It should be executed by
+///   `df_physical_plan_execute_stream()` and the stream should be
+///   read before this. Operators that aren't executed yet don't
+///   report metrics.
+/// \param c_abi_schema_out Return location for the schema of the
+///   metrics record batch. It should be released by the `release`
+///   callback and freed by `free()` when no longer needed.
+/// \param c_abi_record_batch_out Return location for the metrics
+///   record batch. It should be released by the `release` callback
+///   and freed by `free()` when no longer needed.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return `true` on success, `false` otherwise.
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn df_physical_plan_export_metrics(
+    plan: &mut DFPhysicalPlan,
+    c_abi_schema_out: *mut *mut DFArrowSchema,
+    c_abi_record_batch_out: *mut *mut DFArrowArray,
+    error: *mut *mut DFError,
+) -> bool {
+    df_catch_panic(error, false, || {
+        let option = || -> Option<bool> {
+            let mut builder = DFMetricsBuilder::new();
+            builder.append(&plan.plan, None);
+            let rs_record_batch = builder.finish().into_df_error(error, None)?;
+            let c_abi_schema =
+                export_schema(&rs_record_batch.schema()).into_df_error(error, None)?;
+            let rs_struct_array = StructArray::from(rs_record_batch);
+            let rs_ffi_array = FFI_ArrowArray::new(&rs_struct_array.data().clone());
+            unsafe {
+                *c_abi_schema_out = Box::into_raw(c_abi_schema);
+                *c_abi_record_batch_out =
+                    Box::into_raw(Box::new(rs_ffi_array)) as *mut DFArrowArray;
+            }
+            Some(true)
+        }();
+        option.unwrap_or(false)
+    })
+}
+
+/// \struct DFSessionConfig
+/// \brief A struct to customize `DFSessionContext`.
+///
+/// You can create `DFSessionContext` with this by
+/// `df_session_context_new_with_config()`.
+///
+/// You need to free this by `df_session_config_free()` when no
+/// longer needed.
+pub struct DFSessionConfig {
+    config: SessionConfig,
+}
+
+impl DFSessionConfig {
+    pub fn new() -> Self {
+        Self {
+            config: SessionConfig::new(),
+        }
+    }
+}
+
+/// \brief Create a new `DFSessionConfig` with the default values.
+///
+/// \return A newly created `DFSessionConfig`.
+///
+///   It should be freed by `df_session_config_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_session_config_new() -> Box<DFSessionConfig> {
+    Box::new(DFSessionConfig::new())
+}
+
+/// \brief Free the given `DFSessionConfig`.
+///
+/// \param _config A `DFSessionConfig`.
+///
+/// # Safety
+///
+/// This function should not be called for the same `config`
+/// multiple times.
+#[no_mangle]
+pub extern "C" fn df_session_config_free(_config: Option<Box<DFSessionConfig>>) {}
+
+/// \brief Set the number of partitions for query execution.
+///
+/// \param config A `DFSessionConfig`.
+/// \param n The number of partitions. `0` uses the default value
+///   that is the number of CPU cores.
+#[no_mangle]
+pub extern "C" fn df_session_config_set_target_partitions(
+    config: &mut DFSessionConfig,
+    n: usize,
+) {
+    df_abort_on_panic(|| {
+        let n = if n == 0 {
+            ConfigOptions::new().execution.target_partitions
+        } else {
+            n
+        };
+        config.config.options_mut().execution.target_partitions = n;
+    })
+}
+
+/// \brief Get the number of partitions for query execution.
+///
+/// \param config A `DFSessionConfig`.
+/// \return The number of partitions.
+#[no_mangle]
+pub extern "C" fn df_session_config_get_target_partitions(
+    config: &mut DFSessionConfig,
+) -> usize {
+    df_abort_on_panic(|| config.config.target_partitions())
+}
+
+/// \brief Set the default batch size while creating new batches.
+///
+/// \param config A `DFSessionConfig`.
+/// \param size The number of rows in a batch. `0` uses the default
+///   value that is `8192`.
+#[no_mangle]
+pub extern "C" fn df_session_config_set_batch_size(
+    config: &mut DFSessionConfig,
+    size: usize,
+) {
+    df_abort_on_panic(|| {
+        let size = if size == 0 {
+            ConfigOptions::new().execution.batch_size
+        } else {
+            size
+        };
+        config.config.options_mut().execution.batch_size = size;
+    })
+}
+
+/// \brief Get the default batch size while creating new batches.
+///
+/// \param config A `DFSessionConfig`.
+/// \return The number of rows in a batch.
+#[no_mangle]
+pub extern "C" fn df_session_config_get_batch_size(
+    config: &mut DFSessionConfig,
+) -> usize {
+    df_abort_on_panic(|| config.config.batch_size())
+}
+
+/// \brief Set whether the `information_schema` virtual tables are
+/// provided or not.
+///
+/// \param config A `DFSessionConfig`.
+/// \param enabled `true` to provide `information_schema`.
+#[no_mangle]
+pub extern "C" fn df_session_config_set_information_schema(
+    config: &mut DFSessionConfig,
+    enabled: bool,
+) {
+    df_abort_on_panic(|| {
+        config.config.options_mut().catalog.information_schema = enabled;
+    })
+}
+
+/// \brief Get whether the `information_schema` virtual tables are
+/// provided or not.
+///
+/// \param config A `DFSessionConfig`.
+/// \return `true` if `information_schema` is provided, `false`
+///   otherwise.
+#[no_mangle]
+pub extern "C" fn df_session_config_get_information_schema(
+    config: &mut DFSessionConfig,
+) -> bool {
+    df_abort_on_panic(|| config.config.information_schema())
+}
+
+/// \brief Set the default catalog and schema.
+///
+/// \param config A `DFSessionConfig`.
+/// \param catalog A name of the default catalog.
+/// \param schema A name of the default schema.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return `true` on success, `false` otherwise.
+#[no_mangle]
+pub extern "C" fn df_session_config_set_default_catalog_and_schema(
+    config: &mut DFSessionConfig,
+    catalog: *const libc::c_char,
+    schema: *const libc::c_char,
+    error: *mut *mut DFError,
+) -> bool {
+    df_catch_panic(error, false, || {
+        let option = || -> Option<bool> {
+            let rs_catalog = c_string_to_str(catalog).into_df_error(error, None)?;
+            let rs_schema = c_string_to_str(schema).into_df_error(error, None)?;
+            let rs_catalog_options = &mut config.config.options_mut().catalog;
+            rs_catalog_options.default_catalog = rs_catalog.to_string();
+            rs_catalog_options.default_schema = rs_schema.to_string();
+            Some(true)
+        }();
+        option.unwrap_or(false)
+    })
+}
+
+/// \brief Get the name of the default catalog.
+///
+/// \param config A `DFSessionConfig`.
+/// \return The name of the default catalog.
+///
+///   It should be freed by `free()` when no longer needed.
+#[no_mangle]
+pub extern "C" fn df_session_config_get_default_catalog(
+    config: &mut DFSessionConfig,
+) -> *mut libc::c_char {
+    df_abort_on_panic(|| strdup(&config.config.options().catalog.default_catalog))
+}
+
+/// \brief Get the name of the default schema.
+///
+/// \param config A `DFSessionConfig`.
+/// \return The name of the default schema.
+///
+///   It should be freed by `free()` when no longer needed.
+#[no_mangle]
+pub extern "C" fn df_session_config_get_default_schema(
+    config: &mut DFSessionConfig,
+) -> *mut libc::c_char {
+    df_abort_on_panic(|| strdup(&config.config.options().catalog.default_schema))
+}
+
+/// \brief Set whether joins are repartitioned to increase
+/// parallelism or not.
+///
+/// \param config A `DFSessionConfig`.
+/// \param enabled `true` to repartition joins.
+#[no_mangle]
+pub extern "C" fn df_session_config_set_repartition_joins(
+    config: &mut DFSessionConfig,
+    enabled: bool,
+) {
+    df_abort_on_panic(|| {
+        config.config.options_mut().optimizer.repartition_joins = enabled;
+    })
+}
+
+/// \brief Get whether joins are repartitioned to increase
+/// parallelism or not.
+///
+/// \param config A `DFSessionConfig`.
+/// \return `true` if joins are repartitioned, `false` otherwise.
+#[no_mangle]
+pub extern "C" fn df_session_config_get_repartition_joins(
+    config: &mut DFSessionConfig,
+) -> bool {
+    df_abort_on_panic(|| config.config.repartition_joins())
+}
+
+/// \brief Set whether aggregations are repartitioned to increase
+/// parallelism or not.
+///
+/// \param config A `DFSessionConfig`.
+/// \param enabled `true` to repartition aggregations.
+#[no_mangle]
+pub extern "C" fn df_session_config_set_repartition_aggregations(
+    config: &mut DFSessionConfig,
+    enabled: bool,
+) {
+    df_abort_on_panic(|| {
+        config
+            .config
+            .options_mut()
+            .optimizer
+            .repartition_aggregations = enabled;
+    })
+}
+
+/// \brief Get whether aggregations are repartitioned to increase
+/// parallelism or not.
+///
+/// \param config A `DFSessionConfig`.
+/// \return `true` if aggregations are repartitioned, `false` otherwise.
+#[no_mangle]
+pub extern "C" fn df_session_config_get_repartition_aggregations(
+    config: &mut DFSessionConfig,
+) -> bool {
+    df_abort_on_panic(|| config.config.repartition_aggregations())
+}
+
+/// \brief Set whether window functions are repartitioned to increase
+/// parallelism or not.
+///
+/// \param config A `DFSessionConfig`.
+/// \param enabled `true` to repartition window functions.
+#[no_mangle]
+pub extern "C" fn df_session_config_set_repartition_windows(
+    config: &mut DFSessionConfig,
+    enabled: bool,
+) {
+    df_abort_on_panic(|| {
+        config.config.options_mut().optimizer.repartition_windows = enabled;
+    })
+}
+
+/// \brief Get whether window functions are repartitioned to increase
+/// parallelism or not.
+///
+/// \param config A `DFSessionConfig`.
+/// \return `true` if window functions are repartitioned, `false` otherwise.
+#[no_mangle]
+pub extern "C" fn df_session_config_get_repartition_windows(
+    config: &mut DFSessionConfig,
+) -> bool {
+    df_abort_on_panic(|| config.config.repartition_window_functions())
+}
+
+/// \brief Set whether sorts are executed in parallel per partition
+/// or not.
+///
+/// \param config A `DFSessionConfig`.
+/// \param enabled `true` to sort each partition in parallel.
+#[no_mangle]
+pub extern "C" fn df_session_config_set_repartition_sorts(
+    config: &mut DFSessionConfig,
+    enabled: bool,
+) {
+    df_abort_on_panic(|| {
+        config.config.options_mut().optimizer.repartition_sorts = enabled;
+    })
+}
+
+/// \brief Get whether sorts are executed in parallel per partition
+/// or not.
+///
+/// \param config A `DFSessionConfig`.
+/// \return `true` if each partition is sorted in parallel, `false`
+///   otherwise.
+#[no_mangle]
+pub extern "C" fn df_session_config_get_repartition_sorts(
+    config: &mut DFSessionConfig,
+) -> bool {
+    df_abort_on_panic(|| config.config.repartition_sorts())
+}
+
+/// \brief Set whether file scans are repartitioned to increase
+/// parallelism or not.
+///
+/// \param config A `DFSessionConfig`.
+/// \param enabled `true` to repartition file scans.
+#[no_mangle]
+pub extern "C" fn df_session_config_set_repartition_file_scans(
+    config: &mut DFSessionConfig,
+    enabled: bool,
+) {
+    df_abort_on_panic(|| {
+        config.config.options_mut().optimizer.repartition_file_scans = enabled;
+    })
+}
+
+/// \brief Get whether file scans are repartitioned to increase
+/// parallelism or not.
+///
+/// \param config A `DFSessionConfig`.
+/// \return `true` if file scans are repartitioned, `false` otherwise.
+#[no_mangle]
+pub extern "C" fn df_session_config_get_repartition_file_scans(
+    config: &mut DFSessionConfig,
+) -> bool {
+    df_abort_on_panic(|| config.config.options().optimizer.repartition_file_scans)
+}
+
+/// \brief Set a configuration option by key.
+///
+/// \param config A `DFSessionConfig`.
+/// \param key A key of the option such as `datafusion.execution.batch_size`.
+/// \param value A value of the option as string.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return `true` on success, `false` otherwise.
+#[no_mangle]
+pub extern "C" fn df_session_config_set(
+    config: &mut DFSessionConfig,
+    key: *const libc::c_char,
+    value: *const libc::c_char,
+    error: *mut *mut DFError,
+) -> bool {
+    df_catch_panic(error, false, || {
+        let option = || -> Option<bool> {
+            let rs_key = c_string_to_str(key).into_df_error(error, None)?;
+            let rs_value = c_string_to_str(value).into_df_error(error, None)?;
+            config
+                .config
+                .options_mut()
+                .set(rs_key, rs_value)
+                .into_df_error(error, None)?;
+            Some(true)
+        }();
+        option.unwrap_or(false)
+    })
+}
+
+/// \brief Get a configuration option by key.
+///
+/// \param config A `DFSessionConfig`.
+/// \param key A key of the option such as `datafusion.execution.batch_size`.
+/// \return The value of the option as string, `NULL` if the option
+///   doesn't exist or doesn't have value.
+///
+///   It should be freed by `free()` when no longer needed.
+#[no_mangle]
+pub extern "C" fn df_session_config_get(
+    config: &mut DFSessionConfig,
+    key: *const libc::c_char,
+) -> *mut libc::c_char {
+    df_abort_on_panic(|| {
+        let rs_key = match c_string_to_str(key) {
+            Ok(rs_key) => rs_key,
+            Err(_) => return std::ptr::null_mut(),
+        };
+        config
+            .config
+            .options()
+            .entries()
+            .into_iter()
+            .find(|entry| entry.key == rs_key)
+            .and_then(|entry| entry.value)
+            .map(|value| strdup(&value))
+            .unwrap_or(std::ptr::null_mut())
+    })
+}
+
+/// \struct DFRuntimeConfig
+/// \brief A struct to customize the runtime environment of
+/// `DFSessionContext` such as memory limit and temporary files.
+///
+/// You can create `DFSessionContext` with this by
+/// `df_session_context_new_with_runtime_config()`.
+///
+/// You need to free this by `df_runtime_config_free()` when no
+/// longer needed.
+pub struct DFRuntimeConfig {
+    config: RuntimeConfig,
+    memory_pool: Option<DFMemoryPool>,
+}
+
+enum DFMemoryPool {
+    Greedy(usize),
+    FairSpill(usize),
+}
+
+impl DFRuntimeConfig {
+    pub fn new() -> Self {
+        Self {
+            config: RuntimeConfig::new(),
+            memory_pool: None,
+        }
+    }
+
+    // Create a new memory pool for each RuntimeEnv. If we shared a
+    // memory pool, all DFSessionContexts created from the same
+    // DFRuntimeConfig would share the limit.
+    fn runtime_config(&self) -> RuntimeConfig {
+        let mut rs_config = self.config.clone();
+        rs_config.memory_pool = self.memory_pool.as_ref().map(|memory_pool| {
+            let rs_memory_pool: Arc<dyn MemoryPool> = match memory_pool {
+                DFMemoryPool::Greedy(pool_size) => {
+                    Arc::new(GreedyMemoryPool::new(*pool_size))
+                }
+                DFMemoryPool::FairSpill(pool_size) => {
+                    Arc::new(FairSpillPool::new(*pool_size))
+                }
+            };
+            rs_memory_pool
+        });
+        rs_config
+    }
+}
+
+/// \brief Create a new `DFRuntimeConfig` with the default values.
+///
+/// The default memory pool is unbounded and temporary files are
+/// created in a temporary directory chosen by the OS.
+///
+/// \return A newly created `DFRuntimeConfig`.
+///
+///   It should be freed by `df_runtime_config_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_runtime_config_new() -> Box<DFRuntimeConfig> {
+    Box::new(DFRuntimeConfig::new())
+}
+
+/// \brief Free the given `DFRuntimeConfig`.
+///
+/// \param _config A `DFRuntimeConfig`.
+///
+/// # Safety
+///
+/// This function should not be called for the same `config`
+/// multiple times.
+#[no_mangle]
+pub extern "C" fn df_runtime_config_free(_config: Option<Box<DFRuntimeConfig>>) {}
+
+/// \brief Use a greedy memory pool.
+///
+/// The greedy memory pool grants memory requests until the total
+/// reserved memory reaches the given limit. A query that needs more
+/// memory fails with `DF_ERROR_CODE_RESOURCES_EXHAUSTED`.
+///
+/// Each `DFSessionContext` created with `config` has its own pool.
+///
+/// \param config A `DFRuntimeConfig`.
+/// \param pool_size The max number of bytes for the pool.
+#[no_mangle]
+pub extern "C" fn df_runtime_config_set_greedy_memory_pool(
+    config: &mut DFRuntimeConfig,
+    pool_size: usize,
+) {
+    df_abort_on_panic(|| {
+        config.memory_pool = Some(DFMemoryPool::Greedy(pool_size));
+    })
+}
+
+/// \brief Use a fair spill memory pool.
+///
+/// The fair spill memory pool divides the given limit evenly between
+/// operators that can spill to disk such as sort. They spill to disk
+/// instead of failing when they reach their share. A query fails
+/// with `DF_ERROR_CODE_RESOURCES_EXHAUSTED` only when the limit is
+/// reached by memory that can't be spilled.
+///
+/// Each `DFSessionContext` created with `config` has its own pool.
+///
+/// \param config A `DFRuntimeConfig`.
+/// \param pool_size The max number of bytes for the pool.
+#[no_mangle]
+pub extern "C" fn df_runtime_config_set_fair_spill_memory_pool(
+    config: &mut DFRuntimeConfig,
+    pool_size: usize,
+) {
+    df_abort_on_panic(|| {
+        config.memory_pool = Some(DFMemoryPool::FairSpill(pool_size));
+    })
+}
+
+/// \brief Create temporary files such as spill files in the given
+/// directory.
+///
+/// \param config A `DFRuntimeConfig`.
+/// \param path A path of a directory for temporary files.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return `true` on success, `false` otherwise.
+#[no_mangle]
+pub extern "C" fn df_runtime_config_set_temporary_directory(
+    config: &mut DFRuntimeConfig,
+    path: *const libc::c_char,
+    error: *mut *mut DFError,
+) -> bool {
+    df_catch_panic(error, false, || {
+        let option = || -> Option<bool> {
+            let rs_path = c_string_to_str(path).into_df_error(error, None)?;
+            config.config.disk_manager =
+                DiskManagerConfig::new_specified(vec![rs_path.into()]);
+            Some(true)
+        }();
+        option.unwrap_or(false)
+    })
+}
+
+/// \brief Disable temporary files.
+///
+/// Operators that need to spill to disk fail instead.
+///
+/// \param config A `DFRuntimeConfig`.
+#[no_mangle]
+pub extern "C" fn df_runtime_config_disable_disk_manager(config: &mut DFRuntimeConfig) {
+    df_abort_on_panic(|| {
+        config.config.disk_manager = DiskManagerConfig::Disabled;
+    })
+}
+
+/// \struct DFSessionContext
+/// \brief An entry point of DataFusion API.
+///
+/// You need to create `DFSessionContext` to use DataFusion API.
+pub struct DFSessionContext {
+    context: SessionContext,
+    runtime: Option<DFTokioRuntime>,
+    cancellation_token: Option<DFCancellationToken>,
+    timeout_milliseconds: u64,
+}
+
+impl DFSessionContext {
+    pub fn new(context: SessionContext) -> Self {
+        Self {
+            context,
+            runtime: None,
+            cancellation_token: None,
+            timeout_milliseconds: 0,
+        }
+    }
+
+    fn runtime(&self) -> Result<DFTokioRuntime, DataFusionError> {
+        match &self.runtime {
+            Some(runtime) => Ok(runtime.clone()),
+            None => DFTokioRuntime::default_runtime(),
+        }
+    }
+
+    fn executor(&self) -> Result<DFExecutor, DataFusionError> {
+        let mut executor = DFExecutor::new(self.runtime()?);
+        executor.cancellation_token = self.cancellation_token.clone();
+        executor.set_timeout(self.timeout_milliseconds);
+        Ok(executor)
+    }
+}
+
+/// \brief Create a new `DFSessionContext`.
+///
+/// \return A newly created `DFSessionContext`.
+///
+///   It should be freed by `df_session_context_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_session_context_new() -> Box<DFSessionContext> {
+    df_abort_on_panic(|| Box::new(DFSessionContext::new(SessionContext::new())))
+}
+
+/// \brief Create a new `DFSessionContext` with the given configuration.
+///
+/// \param config A `DFSessionConfig`. This is copied. So you can
+///   free this after this call.
+/// \return A newly created `DFSessionContext`.
+///
+///   It should be freed by `df_session_context_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_session_context_new_with_config(
+    config: &DFSessionConfig,
+) -> Box<DFSessionContext> {
+    df_abort_on_panic(|| {
+        Box::new(DFSessionContext::new(SessionContext::with_config(
+            config.config.clone(),
+        )))
+    })
+}
+
+/// \brief Create a new `DFSessionContext` with the given runtime
+/// configuration.
+///
+/// \param config A `DFSessionConfig` or `NULL`. If this is `NULL`,
+///   the default configuration is used. This is copied. So you can
+///   free this after this call.
+/// \param runtime_config A `DFRuntimeConfig`. This is copied. So you
+///   can free this after this call. The new context has its own
+///   memory pool. Memory used by other contexts created with the same
+///   `runtime_config` isn't counted.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFSessionContext` on success, `NULL`
+///   otherwise.
+///
+///   It should be freed by `df_session_context_free()` when no longer
+///   needed.
+#[no_mangle]
+pub extern "C" fn df_session_context_new_with_runtime_config(
+    config: Option<&DFSessionConfig>,
+    runtime_config: &DFRuntimeConfig,
+    error: *mut *mut DFError,
+) -> Option<Box<DFSessionContext>> {
+    df_catch_panic(error, None, || {
+        let rs_config = match config {
+            Some(config) => config.config.clone(),
+            None => SessionConfig::new(),
+        };
+        let runtime_env = RuntimeEnv::new(runtime_config.runtime_config())
+            .into_df_error(error, None)?;
+        let context = SessionContext::with_config_rt(rs_config, Arc::new(runtime_env));
+        Some(Box::new(DFSessionContext::new(context)))
+    })
+}
+
+/// \brief Free the given `DFSessionContext`.
+///
+/// \param _context A `DFSessionContext` created by
+///   `df_session_context_new()`.
+///
+/// # Safety
+///
+/// This function should not be called with `context` that is not
+/// created by `df_session_context_new()`.
+///
+/// This function should not be called for the same `context`
+/// multiple times.
+#[no_mangle]
+pub extern "C" fn df_session_context_free(_context: Option<Box<DFSessionContext>>) {}
+
+/// \brief Set a `DFTokioRuntime` that is used by the given `DFSessionContext`.
+///
+/// `DFDataFrame`s created by the context use the runtime too.
+///
+/// \param context A `DFSessionContext`.
+/// \param runtime A `DFTokioRuntime` or `NULL`. If this is `NULL`, the
+///   process-wide default runtime is used.
+#[no_mangle]
+pub extern "C" fn df_session_context_set_tokio_runtime(
+    context: &mut DFSessionContext,
+    runtime: Option<&DFTokioRuntime>,
+) {
+    context.runtime = runtime.cloned();
+}
+
+/// \brief Set a `DFCancellationToken` that is used by the given
+/// `DFSessionContext`.
+///
+/// `DFDataFrame`s created by the context use the token too.
+///
+/// \param context A `DFSessionContext`.
+/// \param token A `DFCancellationToken` or `NULL`. If this is `NULL`,
+///   queries of the context can't be cancelled.
+#[no_mangle]
+pub extern "C" fn df_session_context_set_cancellation_token(
+    context: &mut DFSessionContext,
+    token: Option<&DFCancellationToken>,
+) {
+    context.cancellation_token = token.cloned();
+}
+
+/// \brief Set a timeout for each execution of the given
+/// `DFSessionContext`.
+///
+/// The timeout is applied to SQL planning by
+/// `df_session_context_sql()` and to each execution such as
+/// `df_data_frame_export()`, `df_data_frame_show()` and
+/// `df_data_frame_write_parquet()`. A timed out execution is aborted
+/// and reports a `DF_ERROR_CODE_TIMEOUT` error. `DFDataFrame`s
+/// created by the context use the timeout too.
+///
+/// An execution is aborted when it waits for other Tokio tasks or
+/// I/O. An execution in a single partition that never waits may not
+/// be aborted until it finishes.
+///
+/// \param context A `DFSessionContext`.
+/// \param timeout_milliseconds The timeout in milliseconds. `0` means
+///   no timeout.
+#[no_mangle]
+pub extern "C" fn df_session_context_set_timeout(
+    context: &mut DFSessionContext,
+    timeout_milliseconds: u64,
+) {
+    context.timeout_milliseconds = timeout_milliseconds;
+}
+
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn df_session_context_sql(
+    context: &mut DFSessionContext,
+    sql: *const libc::c_char,
+    error: *mut *mut DFError,
+) -> Option<Box<DFDataFrame>> {
+    df_catch_panic(error, None, || {
+        let cstr_sql = unsafe { CStr::from_ptr(sql) };
+        let rs_sql = cstr_sql.to_str().into_df_error(error, None)?;
+        let executor = context.executor().into_df_error(error, None)?;
+        let result = executor.block_on(context.context.sql(rs_sql));
+        let data_frame = result.into_df_error(error, None)?;
+        Some(Box::new(DFDataFrame::new(data_frame, executor)))
+    })
+}
+
+/// \brief A callback that receives the result of
+/// `df_session_context_sql_async()`.
+///
+/// \param data_frame A `DFDataFrame` on success, `NULL` on error. It
+///   must be freed by `df_data_frame_free()`.
+/// \param error A `DFError` on error, `NULL` on success. It must be
+///   freed by `df_error_free()`.
+/// \param user_data The `user_data` passed to
+///   `df_session_context_sql_async()`.
+pub type DFSessionContextSQLCallback = extern "C" fn(
+    data_frame: *mut DFDataFrame,
+    error: *mut DFError,
+    user_data: *mut libc::c_void,
+);
+
+/// \brief Create a `DFDataFrame` from the given SQL asynchronously.
+///
+/// This returns immediately. The SQL is planned on the Tokio runtime
+/// of the context and `callback` is called with the result in a
+/// thread of the runtime. The `DFCancellationToken` of the context is
+/// respected.
+///
+/// `callback` must not call functions that block on a Tokio runtime
+/// such as `df_data_frame_export()` and `df_session_context_sql()`.
+/// They report a `DF_ERROR_CODE_PANIC` error in a thread of the
+/// runtime. Pass the result to another thread to use it for them.
+///
+/// \param context A `DFSessionContext`.
+/// \param sql A SQL.
+/// \param cancellation_token A `DFCancellationToken` only for this
+///   call or `NULL`. The `DFCancellationToken` of the context is also
+///   respected.
+/// \param callback A callback that receives the result. It's called
+///   only once when this function returns `true`. It's never called
+///   when this function returns `false`.
+/// \param user_data Data passed to `callback`.
+/// \param destroy_notify A callback to free `user_data` or `NULL`. It's
+///   called after `callback` is called or when this function returns
+///   `false`.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return `true` if the planning is started, `false` otherwise.
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn df_session_context_sql_async(
+    context: &mut DFSessionContext,
+    sql: *const libc::c_char,
+    cancellation_token: Option<&DFCancellationToken>,
+    callback: DFSessionContextSQLCallback,
+    user_data: *mut libc::c_void,
+    destroy_notify: DFDestroyNotify,
+    error: *mut *mut DFError,
+) -> bool {
+    df_catch_panic(error, false, || {
+        let option = || -> Option<bool> {
+            let rs_user_data = DFUserData {
+                data: user_data,
+                destroy_notify,
+            };
+            let cstr_sql = unsafe { CStr::from_ptr(sql) };
+            let rs_sql = cstr_sql.to_str().into_df_error(error, None)?.to_string();
+            let executor = context.executor().into_df_error(error, None)?;
+            let rs_context = context.context.clone();
+            let future =
+                run_with_cancellation_token(cancellation_token.cloned(), async move {
+                    rs_context.sql(&rs_sql).await
+                });
+            let data_frame_executor = executor.clone();
+            let result = executor.spawn(future, move |result| {
+                let rs_user_data = rs_user_data;
+                match result {
+                    Ok(data_frame) => {
+                        let data_frame =
+                            DFDataFrame::new(data_frame, data_frame_executor);
+                        callback(
+                            Box::into_raw(Box::new(data_frame)),
+                            std::ptr::null_mut(),
+                            rs_user_data.data,
+                        );
+                    }
+                    Err(e) => {
+                        let mut c_error = std::ptr::null_mut();
+                        Err::<(), _>(e).into_df_error(&mut c_error, None);
+                        callback(std::ptr::null_mut(), c_error, rs_user_data.data);
+                    }
+                }
+            });
+            result.into_df_error(error, None)?;
+            Some(true)
+        }();
+        option.unwrap_or(false)
+    })
+}
+
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub extern "C" fn df_session_context_deregister(
     context: &mut DFSessionContext,
     name: *const libc::c_char,
     error: *mut *mut DFError,
 ) -> bool {
-    let option = || -> Option<bool> {
-        let rs_name = c_string_to_str(name).into_df_error(error, None)?;
-        context
-            .context
-            .deregister_table(rs_name)
-            .into_df_error(error, None)?;
-        Some(true)
-    }();
-    option.unwrap_or(false)
+    df_catch_panic(error, false, || {
+        let option = || -> Option<bool> {
+            let rs_name = c_string_to_str(name).into_df_error(error, None)?;
+            context
+                .context
+                .deregister_table(rs_name)
+                .into_df_error(error, None)?;
+            Some(true)
+        }();
+        option.unwrap_or(false)
+    })
+}
+
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn df_session_context_register_record_batches(
+    context: &mut DFSessionContext,
+    name: *const libc::c_char,
+    c_abi_schema: &mut DFArrowSchema,
+    c_abi_record_batches: &mut *mut DFArrowArray,
+    n_record_batches: libc::size_t,
+    error: *mut *mut DFError,
+) -> bool {
+    df_catch_panic(error, false, || {
+        let option = || -> Option<bool> {
+            let cstr_name = unsafe { CStr::from_ptr(name) };
+            let rs_name = cstr_name.to_str().into_df_error(error, None)?;
+            let rs_ffi_schema = unsafe {
+                std::ptr::replace(
+                    (c_abi_schema as *mut DFArrowSchema) as *mut FFI_ArrowSchema,
+                    FFI_ArrowSchema::empty(),
+                )
+            };
+            let rs_schema = import_schema(&rs_ffi_schema).into_df_error(error, None)?;
+            let mut rs_record_batches = Vec::new();
+            let c_abi_record_batch_slice = unsafe {
+                std::slice::from_raw_parts(c_abi_record_batches, n_record_batches)
+            };
+            for c_abi_record_batch in c_abi_record_batch_slice {
+                let rs_ffi_record_batch = unsafe {
+                    std::ptr::replace(
+                        *c_abi_record_batch as *mut FFI_ArrowArray,
+                        FFI_ArrowArray::empty(),
+                    )
+                };
+                // We want to share rs_ffi_schema by passing
+                // Arc<FFI_ArrowSchema> but arrow-rs's ArrowArray API
+                // doesn't accept it. So we export schema.
+                let rs_ffi_schema = FFI_ArrowSchema::try_from(rs_schema.clone())
+                    .into_df_error(error, None)?;
+                let rs_record_batch_array =
+                    ArrowArray::new(rs_ffi_record_batch, rs_ffi_schema);
+                let rs_record_batch_data = ArrayData::try_from(rs_record_batch_array)
+                    .into_df_error(error, None)?;
+                // RecordBatch::from(&StructArray) drops schema metadata.
+                let rs_columns =
+                    StructArray::from(rs_record_batch_data).columns().to_vec();
+                let rs_record_batch =
+                    RecordBatch::try_new(Arc::new(rs_schema.clone()), rs_columns)
+                        .into_df_error(error, None)?;
+                rs_record_batches.push(rs_record_batch);
+            }
+            let rs_table = Arc::new(
+                MemTable::try_new(Arc::new(rs_schema), vec![rs_record_batches])
+                    .into_df_error(error, None)?,
+            );
+            context
+                .context
+                .register_table(rs_name, rs_table)
+                .into_df_error(error, None)?;
+            Some(true)
+        }();
+        option.unwrap_or(false)
+    })
+}
+
+struct DFArrowArrayStreamReader {
+    stream: FFI_ArrowArrayStream,
+    schema: SchemaRef,
+}
+
+// The Arrow C stream interface allows calling callbacks from any
+// thread as long as they aren't called concurrently.
+unsafe impl Send for DFArrowArrayStreamReader {}
+
+impl DFArrowArrayStreamReader {
+    fn try_new(mut stream: FFI_ArrowArrayStream) -> Result<Self, ArrowError> {
+        let get_schema = match stream.get_schema {
+            Some(get_schema) => get_schema,
+            None => {
+                return Err(ArrowError::CDataInterface(
+                    "input stream is already released".to_string(),
+                ))
+            }
+        };
+        let mut rs_ffi_schema = FFI_ArrowSchema::empty();
+        let status = unsafe { get_schema(&mut stream, &mut rs_ffi_schema) };
+        let mut reader = Self {
+            stream,
+            schema: Arc::new(Schema::empty()),
+        };
+        if status != 0 {
+            return Err(reader.last_error(status));
+        }
+        reader.schema = Arc::new(import_schema(&rs_ffi_schema)?);
+        Ok(reader)
+    }
+
+    fn last_error(&mut self, status: libc::c_int) -> ArrowError {
+        let maybe_message = self.stream.get_last_error.and_then(|get_last_error| {
+            let message = unsafe { get_last_error(&mut self.stream) };
+            if message.is_null() {
+                None
+            } else {
+                let cstr_message = unsafe { CStr::from_ptr(message) };
+                Some(cstr_message.to_string_lossy().into_owned())
+            }
+        });
+        let message = maybe_message.unwrap_or_else(|| {
+            format!("failed to read input stream: error code: {status}")
+        });
+        ArrowError::CDataInterface(message)
+    }
+
+    fn read_next(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        let get_next = match self.stream.get_next {
+            Some(get_next) => get_next,
+            None => return Ok(None),
+        };
+        let mut rs_ffi_array = FFI_ArrowArray::empty();
+        let status = unsafe { get_next(&mut self.stream, &mut rs_ffi_array) };
+        if status != 0 {
+            return Err(self.last_error(status));
+        }
+        if rs_ffi_array.is_released() {
+            return Ok(None);
+        }
+        let rs_ffi_schema = FFI_ArrowSchema::try_from(self.schema.as_ref())?;
+        let rs_array = ArrowArray::new(rs_ffi_array, rs_ffi_schema);
+        let rs_array_data = ArrayData::try_from(rs_array)?;
+        // RecordBatch::from(&StructArray) drops schema metadata.
+        let rs_columns = StructArray::from(rs_array_data).columns().to_vec();
+        Ok(Some(RecordBatch::try_new(self.schema.clone(), rs_columns)?))
+    }
+}
+
+struct DFArrowArrayStreamPartition {
+    schema: SchemaRef,
+    reader: Mutex<Option<DFArrowArrayStreamReader>>,
+}
+
+impl PartitionStream for DFArrowArrayStreamPartition {
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    fn execute(&self, _context: Arc<TaskContext>) -> SendableRecordBatchStream {
+        let maybe_reader = match self.reader.lock() {
+            Ok(mut reader) => reader.take(),
+            Err(_) => None,
+        };
+        let rs_stream = match maybe_reader {
+            Some(reader) => futures::stream::unfold(Some(reader), |state| async move {
+                let mut reader = state?;
+                let result = tokio::task::spawn_blocking(move || {
+                    let result = reader.read_next();
+                    (result, reader)
+                })
+                .await;
+                match result {
+                    Ok((Ok(Some(record_batch)), reader)) => {
+                        Some((Ok(record_batch), Some(reader)))
+                    }
+                    Ok((Ok(None), _)) => None,
+                    Ok((Err(e), _)) => Some((Err(DataFusionError::ArrowError(e)), None)),
+                    Err(e) => Some((Err(DataFusionError::External(Box::new(e))), None)),
+                }
+            })
+            .boxed(),
+            None => futures::stream::once(async {
+                Err(DataFusionError::Execution(
+                    "record batch stream is already consumed".to_string(),
+                ))
+            })
+            .boxed(),
+        };
+        Box::pin(RecordBatchStreamAdapter::new(
+            self.schema.clone(),
+            rs_stream,
+        ))
+    }
+}
+
+/// \brief Register a table that reads record batches from the given stream.
+///
+/// The registered table can be scanned only once because the given
+/// stream can't be rewound. The second scan reports an error. The
+/// stream's callbacks may be called from a thread that is different
+/// from the caller's thread.
+///
+/// \param context A `DFSessionContext`.
+/// \param name A name for the table in the context.
+/// \param c_abi_stream A `DFArrowArrayStream` that provides record
+///   batches. The content of this is always moved, even on
+///   error. So the caller must not release this after this call.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return `true` on success, `false` otherwise.
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn df_session_context_register_record_batch_stream(
+    context: &mut DFSessionContext,
+    name: *const libc::c_char,
+    c_abi_stream: &mut DFArrowArrayStream,
+    error: *mut *mut DFError,
+) -> bool {
+    df_catch_panic(error, false, || {
+        let option = || -> Option<bool> {
+            let rs_ffi_stream = unsafe {
+                std::ptr::replace(
+                    (c_abi_stream as *mut DFArrowArrayStream)
+                        as *mut FFI_ArrowArrayStream,
+                    FFI_ArrowArrayStream::empty(),
+                )
+            };
+            let rs_name = c_string_to_str(name).into_df_error(error, None)?;
+            let rs_reader = DFArrowArrayStreamReader::try_new(rs_ffi_stream)
+                .into_df_error(error, None)?;
+            let rs_schema = rs_reader.schema.clone();
+            let rs_partition = DFArrowArrayStreamPartition {
+                schema: rs_schema.clone(),
+                reader: Mutex::new(Some(rs_reader)),
+            };
+            let rs_table = Arc::new(
+                StreamingTable::try_new(rs_schema, vec![Arc::new(rs_partition)])
+                    .into_df_error(error, None)?,
+            );
+            context
+                .context
+                .register_table(rs_name, rs_table)
+                .into_df_error(error, None)?;
+            Some(true)
+        }();
+        option.unwrap_or(false)
+    })
+}
+
+fn import_data_type(c_abi_data_type: &mut DFArrowSchema) -> Result<DataType, ArrowError> {
+    let rs_ffi_data_type = unsafe {
+        std::ptr::replace(
+            (c_abi_data_type as *mut DFArrowSchema) as *mut FFI_ArrowSchema,
+            FFI_ArrowSchema::empty(),
+        )
+    };
+    DataType::try_from(&rs_ffi_data_type)
+}
+
+fn import_data_types(
+    c_abi_schema: &mut DFArrowSchema,
+) -> Result<Vec<DataType>, ArrowError> {
+    let rs_ffi_schema = unsafe {
+        std::ptr::replace(
+            (c_abi_schema as *mut DFArrowSchema) as *mut FFI_ArrowSchema,
+            FFI_ArrowSchema::empty(),
+        )
+    };
+    let rs_schema = Schema::try_from(&rs_ffi_schema)?;
+    Ok(rs_schema
+        .fields
+        .iter()
+        .map(|rs_field| rs_field.data_type().clone())
+        .collect())
+}
+
+/// \enum DFVolatility
+/// \brief How a function's output changes with the same input
+///
+/// cbindgen:prefix-with-name
+/// cbindgen:rename-all=ScreamingSnakeCase
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub enum DFVolatility {
+    #[allow(dead_code)]
+    Immutable,
+    #[allow(dead_code)]
+    Stable,
+    #[allow(dead_code)]
+    Volatile,
+}
+
+impl From<DFVolatility> for Volatility {
+    fn from(volatility: DFVolatility) -> Self {
+        match volatility {
+            DFVolatility::Immutable => Volatility::Immutable,
+            DFVolatility::Stable => Volatility::Stable,
+            DFVolatility::Volatile => Volatility::Volatile,
+        }
+    }
+}
+
+/// \struct DFSignature
+/// \brief A struct that describes acceptable arguments of a function.
+///
+/// Argument types are passed as fields of a `DFArrowSchema`. Field
+/// names are ignored.
+///
+/// You need to free signature by `df_signature_free()` when no
+/// longer needed.
+pub struct DFSignature {
+    signature: Signature,
+}
+
+impl DFSignature {
+    pub fn new(signature: Signature) -> Self {
+        Self { signature }
+    }
+}
+
+fn import_signature_types(
+    c_abi_types: Option<&mut DFArrowSchema>,
+) -> Result<Vec<DataType>, DataFusionError> {
+    let c_abi_types = c_abi_types.ok_or_else(|| {
+        DataFusionError::Plan("signature types must not be NULL".to_string())
+    })?;
+    Ok(import_data_types(c_abi_types)?)
+}
+
+fn type_signatures_from_raw(
+    signatures: *const *const DFSignature,
+    n_signatures: usize,
+) -> Result<Vec<TypeSignature>, DataFusionError> {
+    if n_signatures == 0 {
+        return Ok(vec![]);
+    }
+    if signatures.is_null() {
+        return Err(DataFusionError::Plan(
+            "signatures must not be NULL".to_string(),
+        ));
+    }
+    let signature_slice = unsafe { std::slice::from_raw_parts(signatures, n_signatures) };
+    signature_slice
+        .iter()
+        .map(|signature| match unsafe { signature.as_ref() } {
+            Some(signature) => Ok(signature.signature.type_signature.clone()),
+            None => Err(DataFusionError::Plan(
+                "signature must not be NULL".to_string(),
+            )),
+        })
+        .collect()
+}
+
+/// \brief Create a new `DFSignature` that accepts arguments of the
+///   exact types.
+///
+/// \param c_abi_types Fields in this schema are the argument types.
+///   The content of this is moved.
+/// \param volatility A volatility of the function.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFSignature` on success, `NULL` otherwise.
+#[no_mangle]
+pub extern "C" fn df_signature_new_exact(
+    c_abi_types: Option<&mut DFArrowSchema>,
+    volatility: DFVolatility,
+    error: *mut *mut DFError,
+) -> Option<Box<DFSignature>> {
+    df_catch_panic(error, None, || {
+        let rs_types = import_signature_types(c_abi_types).into_df_error(error, None)?;
+        Some(Box::new(DFSignature::new(Signature::exact(
+            rs_types,
+            volatility.into(),
+        ))))
+    })
+}
+
+/// \brief Create a new `DFSignature` that accepts the fixed number of
+///   arguments of the same type that is one of the given types.
+///
+/// \param n_arguments The number of arguments.
+/// \param c_abi_types Fields in this schema are the acceptable types.
+///   The content of this is moved.
+/// \param volatility A volatility of the function.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFSignature` on success, `NULL` otherwise.
+#[no_mangle]
+pub extern "C" fn df_signature_new_uniform(
+    n_arguments: usize,
+    c_abi_types: Option<&mut DFArrowSchema>,
+    volatility: DFVolatility,
+    error: *mut *mut DFError,
+) -> Option<Box<DFSignature>> {
+    df_catch_panic(error, None, || {
+        let rs_types = import_signature_types(c_abi_types).into_df_error(error, None)?;
+        Some(Box::new(DFSignature::new(Signature::uniform(
+            n_arguments,
+            rs_types,
+            volatility.into(),
+        ))))
+    })
+}
+
+/// \brief Create a new `DFSignature` that accepts one or more arguments
+///   of the same type that is one of the given types.
+///
+/// \param c_abi_types Fields in this schema are the acceptable types.
+///   The content of this is moved.
+/// \param volatility A volatility of the function.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFSignature` on success, `NULL` otherwise.
+#[no_mangle]
+pub extern "C" fn df_signature_new_variadic(
+    c_abi_types: Option<&mut DFArrowSchema>,
+    volatility: DFVolatility,
+    error: *mut *mut DFError,
+) -> Option<Box<DFSignature>> {
+    df_catch_panic(error, None, || {
+        let rs_types = import_signature_types(c_abi_types).into_df_error(error, None)?;
+        Some(Box::new(DFSignature::new(Signature::variadic(
+            rs_types,
+            volatility.into(),
+        ))))
+    })
+}
+
+/// \brief Create a new `DFSignature` that accepts one or more arguments
+///   of the same arbitrary type.
+///
+/// \param volatility A volatility of the function.
+/// \return A newly created `DFSignature`.
+#[no_mangle]
+pub extern "C" fn df_signature_new_variadic_equal(
+    volatility: DFVolatility,
+) -> Box<DFSignature> {
+    Box::new(DFSignature::new(Signature::variadic_equal(
+        volatility.into(),
+    )))
+}
+
+/// \brief Create a new `DFSignature` that accepts the fixed number of
+///   arguments of arbitrary types.
+///
+/// \param n_arguments The number of arguments.
+/// \param volatility A volatility of the function.
+/// \return A newly created `DFSignature`.
+#[no_mangle]
+pub extern "C" fn df_signature_new_any(
+    n_arguments: usize,
+    volatility: DFVolatility,
+) -> Box<DFSignature> {
+    Box::new(DFSignature::new(Signature::any(
+        n_arguments,
+        volatility.into(),
+    )))
+}
+
+/// \brief Create a new `DFSignature` that accepts arguments that
+///   match one of the given signatures.
+///
+/// Volatilities of the given signatures are ignored.
+///
+/// \param signatures Candidate signatures. This and its elements must
+///   not be `NULL` unless `n_signatures` is `0`.
+/// \param n_signatures The number of `signatures`.
+/// \param volatility A volatility of the function.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFSignature` on success, `NULL` otherwise.
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn df_signature_new_one_of(
+    signatures: *const *const DFSignature,
+    n_signatures: usize,
+    volatility: DFVolatility,
+    error: *mut *mut DFError,
+) -> Option<Box<DFSignature>> {
+    df_catch_panic(error, None, || {
+        let rs_type_signatures = type_signatures_from_raw(signatures, n_signatures)
+            .into_df_error(error, None)?;
+        Some(Box::new(DFSignature::new(Signature::one_of(
+            rs_type_signatures,
+            volatility.into(),
+        ))))
+    })
+}
+
+/// \brief Free the given `DFSignature`.
+///
+/// \param _signature A `DFSignature`.
+///
+/// # Safety
+///
+/// This function should not be called for the same `signature`
+/// multiple times.
+#[no_mangle]
+pub extern "C" fn df_signature_free(_signature: Option<Box<DFSignature>>) {}
+
+/// \brief A callback to free user data.
+pub type DFDestroyNotify = Option<extern "C" fn(data: *mut libc::c_void)>;
+
+struct DFUserData {
+    data: *mut libc::c_void,
+    destroy_notify: DFDestroyNotify,
+}
+
+// Callbacks may be called from any threads in Tokio runtime. Users
+// are responsible for making user data thread safe.
+unsafe impl Send for DFUserData {}
+unsafe impl Sync for DFUserData {}
+
+impl Drop for DFUserData {
+    fn drop(&mut self) {
+        if let Some(destroy_notify) = self.destroy_notify {
+            destroy_notify(self.data);
+        }
+    }
+}
+
+fn take_callback_error(c_error: *mut DFError, default_message: &str) -> DataFusionError {
+    if c_error.is_null() {
+        DataFusionError::Execution(default_message.to_string())
+    } else {
+        let df_error = unsafe { Box::from_raw(c_error) };
+        DataFusionError::External(df_error)
+    }
+}
+
+fn export_arrays(
+    rs_arrays: &[ArrayRef],
+    n_rows: usize,
+) -> Result<(FFI_ArrowSchema, FFI_ArrowArray), ArrowError> {
+    let rs_fields = rs_arrays
+        .iter()
+        .enumerate()
+        .map(|(i, rs_array)| {
+            Field::new(format!("c{i}"), rs_array.data_type().clone(), true)
+        })
+        .collect();
+    let rs_data = ArrayData::builder(DataType::Struct(rs_fields))
+        .len(n_rows)
+        .child_data(
+            rs_arrays
+                .iter()
+                .map(|rs_array| rs_array.data().clone())
+                .collect(),
+        )
+        .build()?;
+    let rs_ffi_schema = FFI_ArrowSchema::try_from(rs_data.data_type())?;
+    let rs_ffi_array = FFI_ArrowArray::new(&rs_data);
+    Ok((rs_ffi_schema, rs_ffi_array))
+}
+
+fn import_array(
+    rs_ffi_array: FFI_ArrowArray,
+    data_type: &DataType,
+) -> Result<ArrayRef, DataFusionError> {
+    if rs_ffi_array.is_released() {
+        return Err(DataFusionError::Execution(
+            "callback didn't set result array".to_string(),
+        ));
+    }
+    let rs_ffi_schema = FFI_ArrowSchema::try_from(data_type)?;
+    let rs_array = ArrowArray::new(rs_ffi_array, rs_ffi_schema);
+    Ok(make_array(ArrayData::try_from(rs_array)?))
+}
+
+/// \brief A callback to compute a scalar UDF.
+///
+/// \param args_schema The schema of `args`. Each field is
+///   corresponding to an argument. The callback may move the content.
+/// \param args A struct array that has arguments as its children.
+///   The callback may move the content.
+/// \param result Return location for the result array. The result
+///   array must have the same length as `args` and the return type of
+///   the UDF.
+/// \param user_data The `user_data` passed on registration.
+/// \param error Return location for a `DFError` created by
+///   `df_error_new()`.
+/// \return `true` on success, `false` otherwise.
+pub type DFScalarUDFFunction = extern "C" fn(
+    args_schema: *mut DFArrowSchema,
+    args: *mut DFArrowArray,
+    result: *mut DFArrowArray,
+    user_data: *mut libc::c_void,
+    error: *mut *mut DFError,
+) -> bool;
+
+fn call_scalar_udf(
+    function: DFScalarUDFFunction,
+    user_data: &DFUserData,
+    return_type: &DataType,
+    args: &[ColumnarValue],
+) -> Result<ColumnarValue, DataFusionError> {
+    let maybe_n_rows = args.iter().find_map(|arg| match arg {
+        ColumnarValue::Array(rs_array) => Some(rs_array.len()),
+        ColumnarValue::Scalar(_) => None,
+    });
+    // All arguments are scalars. We compute only one row for them.
+    let is_scalar = maybe_n_rows.is_none();
+    let n_rows = maybe_n_rows.unwrap_or(1);
+    let rs_arrays = args
+        .iter()
+        .map(|arg| arg.clone().into_array(n_rows))
+        .collect::<Vec<_>>();
+    let (mut rs_ffi_args_schema, mut rs_ffi_args) = export_arrays(&rs_arrays, n_rows)?;
+    let mut rs_ffi_result = FFI_ArrowArray::empty();
+    let mut c_error: *mut DFError = std::ptr::null_mut();
+    let success = function(
+        (&mut rs_ffi_args_schema as *mut FFI_ArrowSchema) as *mut DFArrowSchema,
+        (&mut rs_ffi_args as *mut FFI_ArrowArray) as *mut DFArrowArray,
+        (&mut rs_ffi_result as *mut FFI_ArrowArray) as *mut DFArrowArray,
+        user_data.data,
+        &mut c_error,
+    );
+    if !success {
+        return Err(take_callback_error(c_error, "scalar UDF failed"));
+    }
+    let rs_result = import_array(rs_ffi_result, return_type)?;
+    if rs_result.len() != n_rows {
+        return Err(DataFusionError::Execution(format!(
+            "scalar UDF must return {} rows: {}",
+            n_rows,
+            rs_result.len()
+        )));
+    }
+    if is_scalar {
+        Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+            &rs_result, 0,
+        )?))
+    } else {
+        Ok(ColumnarValue::Array(rs_result))
+    }
+}
+
+/// \brief Register a scalar UDF that is implemented by a C callback.
+///
+/// If all arguments are scalar values or the UDF has no argument,
+/// `function` is called only with one row and the result is used as
+/// a scalar value.
+///
+/// \param context A `DFSessionContext`.
+/// \param name A name of the UDF.
+/// \param signature Acceptable arguments of the UDF.
+/// \param c_abi_return_type The return type of the UDF. The content
+///   of this is moved.
+/// \param function A callback that computes the UDF. This may be
+///   called from multiple threads at the same time.
+/// \param user_data Data passed to `function`.
+/// \param destroy_notify A callback to free `user_data` or `NULL`.
+///   This is called when `user_data` is no longer needed. This is
+///   also called on failure.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return `true` on success, `false` otherwise.
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+#[allow(clippy::too_many_arguments)]
+pub extern "C" fn df_session_context_register_scalar_udf(
+    context: &mut DFSessionContext,
+    name: *const libc::c_char,
+    signature: &DFSignature,
+    c_abi_return_type: &mut DFArrowSchema,
+    function: DFScalarUDFFunction,
+    user_data: *mut libc::c_void,
+    destroy_notify: DFDestroyNotify,
+    error: *mut *mut DFError,
+) -> bool {
+    df_catch_panic(error, false, || {
+        let rs_user_data = DFUserData {
+            data: user_data,
+            destroy_notify,
+        };
+        let option = || -> Option<bool> {
+            // The return type must be moved even when name is invalid.
+            let rs_return_type = import_data_type(c_abi_return_type);
+            let rs_name = c_string_to_str(name).into_df_error(error, None)?;
+            let rs_return_type = rs_return_type.into_df_error(error, None)?;
+            let rs_return_type_function: ReturnTypeFunction = {
+                let rs_return_type = Arc::new(rs_return_type.clone());
+                Arc::new(move |_| Ok(rs_return_type.clone()))
+            };
+            let rs_function: ScalarFunctionImplementation =
+                Arc::new(move |args: &[ColumnarValue]| {
+                    call_scalar_udf(function, &rs_user_data, &rs_return_type, args)
+                });
+            let rs_udf = ScalarUDF::new(
+                rs_name,
+                &signature.signature,
+                &rs_return_type_function,
+                &rs_function,
+            );
+            context.context.register_udf(rs_udf);
+            Some(true)
+        }();
+        option.unwrap_or(false)
+    })
+}
+
+/// \struct DFAccumulatorFunctions
+/// \brief Callbacks that implement an aggregate UDF.
+///
+/// All callbacks receive the `user_data` passed on registration. All
+/// callbacks except `free` return `true` on success. They must set a
+/// `DFError` created by `df_error_new()` to `error` and return
+/// `false` on failure.
+///
+/// Arguments and states are passed as a struct array and its
+/// schema. Each child is corresponding to an argument or a state. The
+/// callbacks may move their contents.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct DFAccumulatorFunctions {
+    /// Create a new accumulator and set it to `accumulator`.
+    create: extern "C" fn(
+        accumulator: *mut *mut libc::c_void,
+        user_data: *mut libc::c_void,
+        error: *mut *mut DFError,
+    ) -> bool,
+    /// Update the accumulator with a batch of arguments.
+    update_batch: extern "C" fn(
+        accumulator: *mut libc::c_void,
+        values_schema: *mut DFArrowSchema,
+        values: *mut DFArrowArray,
+        user_data: *mut libc::c_void,
+        error: *mut *mut DFError,
+    ) -> bool,
+    /// Merge a batch of states computed by other accumulators.
+    merge_batch: extern "C" fn(
+        accumulator: *mut libc::c_void,
+        states_schema: *mut DFArrowSchema,
+        states: *mut DFArrowArray,
+        user_data: *mut libc::c_void,
+        error: *mut *mut DFError,
+    ) -> bool,
+    /// Set the current state to `result` as a struct array that has
+    /// one row. Its children must have the declared state types.
+    state: extern "C" fn(
+        accumulator: *mut libc::c_void,
+        result: *mut DFArrowArray,
+        user_data: *mut libc::c_void,
+        error: *mut *mut DFError,
+    ) -> bool,
+    /// Set the aggregated value to `result` as an array that has one
+    /// row. It must have the return type.
+    evaluate: extern "C" fn(
+        accumulator: *mut libc::c_void,
+        result: *mut DFArrowArray,
+        user_data: *mut libc::c_void,
+        error: *mut *mut DFError,
+    ) -> bool,
+    /// Free the accumulator.
+    free: extern "C" fn(accumulator: *mut libc::c_void, user_data: *mut libc::c_void),
+    /// Return the number of bytes allocated by the accumulator. This
+    /// is used for memory accounting. This may be `NULL`. If this is
+    /// `NULL`, memory allocated by the accumulator isn't accounted.
+    size: Option<
+        extern "C" fn(
+            accumulator: *mut libc::c_void,
+            user_data: *mut libc::c_void,
+        ) -> usize,
+    >,
+}
+
+struct DFCallbackAccumulator {
+    accumulator: *mut libc::c_void,
+    functions: DFAccumulatorFunctions,
+    user_data: Arc<DFUserData>,
+    return_type: DataType,
+    state_types: Arc<Vec<DataType>>,
+}
+
+// See DFUserData.
+unsafe impl Send for DFCallbackAccumulator {}
+unsafe impl Sync for DFCallbackAccumulator {}
+
+impl std::fmt::Debug for DFCallbackAccumulator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DFCallbackAccumulator")
+            .field("accumulator", &self.accumulator)
+            .field("return_type", &self.return_type)
+            .field("state_types", &self.state_types)
+            .finish()
+    }
+}
+
+impl Drop for DFCallbackAccumulator {
+    fn drop(&mut self) {
+        (self.functions.free)(self.accumulator, self.user_data.data);
+    }
+}
+
+impl DFCallbackAccumulator {
+    fn call_batch(
+        &mut self,
+        function: extern "C" fn(
+            *mut libc::c_void,
+            *mut DFArrowSchema,
+            *mut DFArrowArray,
+            *mut libc::c_void,
+            *mut *mut DFError,
+        ) -> bool,
+        rs_arrays: &[ArrayRef],
+        default_error_message: &str,
+    ) -> Result<(), DataFusionError> {
+        let n_rows = rs_arrays
+            .first()
+            .map(|rs_array| rs_array.len())
+            .unwrap_or(0);
+        let (mut rs_ffi_schema, mut rs_ffi_array) = export_arrays(rs_arrays, n_rows)?;
+        let mut c_error: *mut DFError = std::ptr::null_mut();
+        let success = function(
+            self.accumulator,
+            (&mut rs_ffi_schema as *mut FFI_ArrowSchema) as *mut DFArrowSchema,
+            (&mut rs_ffi_array as *mut FFI_ArrowArray) as *mut DFArrowArray,
+            self.user_data.data,
+            &mut c_error,
+        );
+        if success {
+            Ok(())
+        } else {
+            Err(take_callback_error(c_error, default_error_message))
+        }
+    }
+
+    fn call_result(
+        &self,
+        function: extern "C" fn(
+            *mut libc::c_void,
+            *mut DFArrowArray,
+            *mut libc::c_void,
+            *mut *mut DFError,
+        ) -> bool,
+        data_type: &DataType,
+        default_error_message: &str,
+    ) -> Result<ArrayRef, DataFusionError> {
+        let mut rs_ffi_result = FFI_ArrowArray::empty();
+        let mut c_error: *mut DFError = std::ptr::null_mut();
+        let success = function(
+            self.accumulator,
+            (&mut rs_ffi_result as *mut FFI_ArrowArray) as *mut DFArrowArray,
+            self.user_data.data,
+            &mut c_error,
+        );
+        if !success {
+            return Err(take_callback_error(c_error, default_error_message));
+        }
+        let rs_result = import_array(rs_ffi_result, data_type)?;
+        if rs_result.len() != 1 {
+            return Err(DataFusionError::Execution(format!(
+                "{} must return 1 row: {}",
+                default_error_message,
+                rs_result.len()
+            )));
+        }
+        Ok(rs_result)
+    }
+}
+
+impl Accumulator for DFCallbackAccumulator {
+    fn state(&self) -> Result<Vec<ScalarValue>, DataFusionError> {
+        let rs_fields = self
+            .state_types
+            .iter()
+            .enumerate()
+            .map(|(i, data_type)| Field::new(format!("c{i}"), data_type.clone(), true))
+            .collect();
+        let rs_state = self.call_result(
+            self.functions.state,
+            &DataType::Struct(rs_fields),
+            "aggregate UDF state",
+        )?;
+        let rs_struct_array = StructArray::from(rs_state.data().clone());
+        rs_struct_array
+            .columns()
+            .iter()
+            .map(|rs_column| ScalarValue::try_from_array(rs_column, 0))
+            .collect()
+    }
+
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<(), DataFusionError> {
+        self.call_batch(
+            self.functions.update_batch,
+            values,
+            "aggregate UDF update batch failed",
+        )
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<(), DataFusionError> {
+        self.call_batch(
+            self.functions.merge_batch,
+            states,
+            "aggregate UDF merge batch failed",
+        )
+    }
+
+    fn evaluate(&self) -> Result<ScalarValue, DataFusionError> {
+        let rs_result = self.call_result(
+            self.functions.evaluate,
+            &self.return_type,
+            "aggregate UDF evaluate",
+        )?;
+        ScalarValue::try_from_array(&rs_result, 0)
+    }
+
+    fn size(&self) -> usize {
+        let c_size = self
+            .functions
+            .size
+            .map(|size| size(self.accumulator, self.user_data.data))
+            .unwrap_or(0);
+        std::mem::size_of_val(self) + c_size
+    }
+}
+
+fn type_signature_accepts_no_argument(type_signature: &TypeSignature) -> bool {
+    match type_signature {
+        TypeSignature::Exact(types) => types.is_empty(),
+        TypeSignature::Uniform(n_arguments, _) => *n_arguments == 0,
+        TypeSignature::Any(n_arguments) => *n_arguments == 0,
+        TypeSignature::OneOf(type_signatures) => type_signatures
+            .iter()
+            .any(type_signature_accepts_no_argument),
+        TypeSignature::Variadic(_) | TypeSignature::VariadicEqual => false,
+    }
+}
+
+/// \brief Register an aggregate UDF that is implemented by C callbacks.
+///
+/// \param context A `DFSessionContext`.
+/// \param name A name of the UDF.
+/// \param signature Acceptable arguments of the UDF. It must not
+///   accept zero arguments because `update_batch` can't know the
+///   number of rows without arguments.
+/// \param c_abi_return_type The return type of the UDF. The content
+///   of this is moved.
+/// \param c_abi_state_types Fields in this schema are the types of
+///   the accumulator's state. The content of this is moved.
+/// \param functions Callbacks that implement the UDF. This is copied.
+///   They may be called from multiple threads at the same time for
+///   different accumulators.
+/// \param user_data Data passed to `functions`.
+/// \param destroy_notify A callback to free `user_data` or `NULL`.
+///   This is called when `user_data` is no longer needed. This is
+///   also called on failure.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return `true` on success, `false` otherwise.
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+#[allow(clippy::too_many_arguments)]
+pub extern "C" fn df_session_context_register_aggregate_udf(
+    context: &mut DFSessionContext,
+    name: *const libc::c_char,
+    signature: &DFSignature,
+    c_abi_return_type: &mut DFArrowSchema,
+    c_abi_state_types: &mut DFArrowSchema,
+    functions: &DFAccumulatorFunctions,
+    user_data: *mut libc::c_void,
+    destroy_notify: DFDestroyNotify,
+    error: *mut *mut DFError,
+) -> bool {
+    df_catch_panic(error, false, || {
+        let rs_user_data = Arc::new(DFUserData {
+            data: user_data,
+            destroy_notify,
+        });
+        let option = || -> Option<bool> {
+            // Both types must be moved even when any argument is
+            // invalid.
+            let rs_return_type = import_data_type(c_abi_return_type);
+            let rs_state_types = import_data_types(c_abi_state_types);
+            let rs_name = c_string_to_str(name).into_df_error(error, None)?;
+            let rs_return_type = rs_return_type.into_df_error(error, None)?;
+            let rs_state_types = Arc::new(rs_state_types.into_df_error(error, None)?);
+            if type_signature_accepts_no_argument(&signature.signature.type_signature) {
+                df_error_set(
+                    error,
+                    DFErrorCode::Plan,
+                    "aggregate UDF must not accept zero arguments",
+                );
+                return None;
+            }
+            let rs_return_type_function: ReturnTypeFunction = {
+                let rs_return_type = Arc::new(rs_return_type.clone());
+                Arc::new(move |_| Ok(rs_return_type.clone()))
+            };
+            let rs_state_type_function: StateTypeFunction = {
+                let rs_state_types = rs_state_types.clone();
+                Arc::new(move |_| Ok(rs_state_types.clone()))
+            };
+            let rs_functions = *functions;
+            let rs_accumulator_function: AccumulatorFunctionImplementation =
+                Arc::new(move |_| {
+                    let mut accumulator: *mut libc::c_void = std::ptr::null_mut();
+                    let mut c_error: *mut DFError = std::ptr::null_mut();
+                    let success = (rs_functions.create)(
+                        &mut accumulator,
+                        rs_user_data.data,
+                        &mut c_error,
+                    );
+                    if !success {
+                        return Err(take_callback_error(
+                            c_error,
+                            "aggregate UDF create accumulator failed",
+                        ));
+                    }
+                    Ok(Box::new(DFCallbackAccumulator {
+                        accumulator,
+                        functions: rs_functions,
+                        user_data: rs_user_data.clone(),
+                        return_type: rs_return_type.clone(),
+                        state_types: rs_state_types.clone(),
+                    }))
+                });
+            let rs_udaf = AggregateUDF::new(
+                rs_name,
+                &signature.signature,
+                &rs_return_type_function,
+                &rs_accumulator_function,
+                &rs_state_type_function,
+            );
+            context.context.register_udaf(rs_udaf);
+            Some(true)
+        }();
+        option.unwrap_or(false)
+    })
+}
+
+/// \struct DFCSVReadOptions
+/// \brief Options for reading CSV files.
+///
+/// DataFusion 21 doesn't provide options for the quote character,
+/// the escape character and newlines in values. Values can be quoted
+/// only by `"`. A `"` in a quoted value must be written as `""`.
+///
+/// You need to free this by `df_csv_read_options_free()` when no
+/// longer needed.
+pub struct DFCSVReadOptions<'a> {
+    options: CsvReadOptions<'a>,
+    schema: Option<Schema>,
+    table_partition_columns: Option<Schema>,
+}
+
+impl<'a> DFCSVReadOptions<'a> {
+    pub fn new() -> Self {
+        let options = CsvReadOptions::default();
+        let schema = None;
+        let table_partition_columns = None;
+        Self {
+            options,
+            schema,
+            table_partition_columns,
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn df_csv_read_options_new<'a>() -> Box<DFCSVReadOptions<'a>> {
+    Box::new(DFCSVReadOptions::new())
+}
+
+#[no_mangle]
+pub extern "C" fn df_csv_read_options_free(_options: Option<Box<DFCSVReadOptions>>) {}
+
+#[no_mangle]
+pub extern "C" fn df_csv_read_options_set_has_header(
+    options: &mut DFCSVReadOptions,
+    has_header: bool,
+) {
+    options.options.has_header = has_header;
+}
+
+#[no_mangle]
+pub extern "C" fn df_csv_read_options_get_has_header(
+    options: &mut DFCSVReadOptions,
+) -> bool {
+    options.options.has_header
+}
+
+#[no_mangle]
+pub extern "C" fn df_csv_read_options_set_delimiter(
+    options: &mut DFCSVReadOptions,
+    delimiter: u8,
+) {
+    options.options.delimiter = delimiter;
+}
+
+#[no_mangle]
+pub extern "C" fn df_csv_read_options_get_delimiter(
+    options: &mut DFCSVReadOptions,
+) -> u8 {
+    options.options.delimiter
+}
+
+#[no_mangle]
+pub extern "C" fn df_csv_read_options_set_schema<'a>(
+    options: &'a mut DFCSVReadOptions<'a>,
+    schema: Option<Box<DFArrowSchema>>,
+    error: *mut *mut DFError,
+) -> bool {
+    df_catch_panic(error, false, || {
+        let option = || -> Option<bool> {
+            match schema {
+                Some(mut s) => {
+                    let rs_ffi_schema =
+                        (s.as_mut() as *mut DFArrowSchema) as *mut FFI_ArrowSchema;
+                    let rs_schema = Schema::try_from(unsafe { &*rs_ffi_schema })
+                        .into_df_error(error, None)?;
+                    options.schema = Some(rs_schema);
+                    options.options.schema = options.schema.as_ref();
+                }
+                None => {
+                    options.schema = None;
+                    options.options.schema = None;
+                }
+            };
+            Some(true)
+        }();
+        option.unwrap_or(false)
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn df_csv_read_options_get_schema(
+    options: &mut DFCSVReadOptions,
+    error: *mut *mut DFError,
+) -> Option<Box<DFArrowSchema>> {
+    df_catch_panic(error, None, || match options.options.schema {
+        Some(rs_schema) => {
+            let rs_ffi_schema =
+                FFI_ArrowSchema::try_from(rs_schema).into_df_error(error, None)?;
+            Some(Box::<DFArrowSchema>::from(rs_ffi_schema))
+        }
+        None => None,
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn df_csv_read_options_set_schema_infer_max_records(
+    options: &mut DFCSVReadOptions,
+    n: usize,
+) {
+    options.options.schema_infer_max_records = n;
+}
+
+#[no_mangle]
+pub extern "C" fn df_csv_read_options_get_schema_infer_max_records(
+    options: &mut DFCSVReadOptions,
+) -> usize {
+    options.options.schema_infer_max_records
+}
+
+#[no_mangle]
+pub extern "C" fn df_csv_read_options_set_file_extension(
+    options: &mut DFCSVReadOptions,
+    file_extension: *const libc::c_char,
+    error: *mut *mut DFError,
+) -> bool {
+    df_catch_panic(error, false, || {
+        let option = || -> Option<bool> {
+            options.options.file_extension =
+                c_string_to_str(file_extension).into_df_error(error, None)?;
+            Some(true)
+        }();
+        option.unwrap_or(false)
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn df_csv_read_options_get_file_extension(
+    options: &mut DFCSVReadOptions,
+) -> *mut libc::c_char {
+    strdup(options.options.file_extension)
+}
+
+#[no_mangle]
+pub extern "C" fn df_csv_read_options_set_table_partition_columns(
+    options: &mut DFCSVReadOptions,
+    schema: Option<Box<DFArrowSchema>>,
+    error: *mut *mut DFError,
+) -> bool {
+    df_catch_panic(error, false, || {
+        set_table_partition_columns(
+            &mut options.options.table_partition_cols,
+            &mut options.table_partition_columns,
+            schema,
+            error,
+        )
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn df_csv_read_options_get_table_partition_columns(
+    options: &mut DFCSVReadOptions,
+    error: *mut *mut DFError,
+) -> Option<Box<DFArrowSchema>> {
+    df_catch_panic(error, None, || {
+        get_table_partition_columns(&options.options.table_partition_cols, error)
+    })
+}
+
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn df_session_context_register_csv(
+    context: &mut DFSessionContext,
+    name: *const libc::c_char,
+    url: *const libc::c_char,
+    options: Option<&mut DFCSVReadOptions>,
+    error: *mut *mut DFError,
+) -> bool {
+    df_catch_panic(error, false, || {
+        let option = || -> Option<bool> {
+            let rs_name = c_string_to_str(name).into_df_error(error, None)?;
+            let rs_url = c_string_to_str(url).into_df_error(error, None)?;
+            let rs_options = match options {
+                Some(o) => o.options.clone(),
+                None => CsvReadOptions::default(),
+            };
+            let executor = context.executor().into_df_error(error, None)?;
+            let result = context.context.register_csv(rs_name, rs_url, rs_options);
+            executor.block_on(result).into_df_error(error, None)?;
+            Some(true)
+        }();
+        option.unwrap_or(false)
+    })
+}
+
+/// \brief Create a `DFDataFrame` that reads the given CSV files
+/// without registering them as a table.
+///
+/// \param context A `DFSessionContext`.
+/// \param url A path or URL of a CSV file or a directory that has
+///   CSV files.
+/// \param options A `DFCSVReadOptions` or `NULL`. If this is `NULL`,
+///   the default options are used.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFDataFrame` on success, `NULL` on error.
+///
+///   It should be freed by `df_data_frame_free()` when no longer
+///   needed.
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn df_session_context_read_csv(
+    context: &mut DFSessionContext,
+    url: *const libc::c_char,
+    options: Option<&mut DFCSVReadOptions>,
+    error: *mut *mut DFError,
+) -> Option<Box<DFDataFrame>> {
+    df_catch_panic(error, None, || {
+        let rs_url = c_string_to_str(url).into_df_error(error, None)?;
+        let rs_options = match options {
+            Some(o) => o.options.clone(),
+            None => CsvReadOptions::default(),
+        };
+        let executor = context.executor().into_df_error(error, None)?;
+        let result = context.context.read_csv(rs_url, rs_options);
+        let data_frame = executor.block_on(result).into_df_error(error, None)?;
+        Some(Box::new(DFDataFrame::new(data_frame, executor)))
+    })
+}
+
+/// \enum DFFileCompressionType
+/// \brief Compression types of data source files.
+///
+/// cbindgen:prefix-with-name
+/// cbindgen:rename-all=ScreamingSnakeCase
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub enum DFFileCompressionType {
+    /// Uncompressed file.
+    #[allow(dead_code)]
+    Uncompressed,
+    /// Gzip-ed file.
+    #[allow(dead_code)]
+    Gzip,
+    /// Bzip2-ed file.
+    #[allow(dead_code)]
+    Bzip2,
+    /// Xz-ed file.
+    #[allow(dead_code)]
+    Xz,
+    /// Zstd-ed file.
+    #[allow(dead_code)]
+    Zstd,
+}
+
+impl From<DFFileCompressionType> for FileCompressionType {
+    fn from(compression_type: DFFileCompressionType) -> Self {
+        match compression_type {
+            DFFileCompressionType::Uncompressed => FileCompressionType::UNCOMPRESSED,
+            DFFileCompressionType::Gzip => FileCompressionType::GZIP,
+            DFFileCompressionType::Bzip2 => FileCompressionType::BZIP2,
+            DFFileCompressionType::Xz => FileCompressionType::XZ,
+            DFFileCompressionType::Zstd => FileCompressionType::ZSTD,
+        }
+    }
+}
+
+impl From<&FileCompressionType> for DFFileCompressionType {
+    fn from(compression_type: &FileCompressionType) -> Self {
+        if *compression_type == FileCompressionType::GZIP {
+            DFFileCompressionType::Gzip
+        } else if *compression_type == FileCompressionType::BZIP2 {
+            DFFileCompressionType::Bzip2
+        } else if *compression_type == FileCompressionType::XZ {
+            DFFileCompressionType::Xz
+        } else if *compression_type == FileCompressionType::ZSTD {
+            DFFileCompressionType::Zstd
+        } else {
+            DFFileCompressionType::Uncompressed
+        }
+    }
+}
+
+/// \brief Set the compression type of CSV files.
+///
+/// The file extension isn't changed automatically. You may need to
+/// change it too by `df_csv_read_options_set_file_extension()` such
+/// as `.csv.gz`.
+///
+/// Schema inference may fail for compressed CSV files because the
+/// compressed data are split by newlines before they are
+/// decompressed. You can avoid it by specifying the schema
+/// explicitly by `df_csv_read_options_set_schema()`.
+///
+/// \param options A `DFCSVReadOptions`.
+/// \param compression_type A compression type.
+#[no_mangle]
+pub extern "C" fn df_csv_read_options_set_file_compression_type(
+    options: &mut DFCSVReadOptions,
+    compression_type: DFFileCompressionType,
+) {
+    options.options.file_compression_type = compression_type.into();
+}
+
+/// \brief Get the compression type of CSV files.
+///
+/// \param options A `DFCSVReadOptions`.
+/// \return The compression type.
+#[no_mangle]
+pub extern "C" fn df_csv_read_options_get_file_compression_type(
+    options: &mut DFCSVReadOptions,
+) -> DFFileCompressionType {
+    (&options.options.file_compression_type).into()
+}
+
+/// \struct DFJSONReadOptions
+/// \brief Options for reading newline-delimited JSON files.
+///
+/// You need to free this by `df_json_read_options_free()` when no
+/// longer needed.
+pub struct DFJSONReadOptions<'a> {
+    options: NdJsonReadOptions<'a>,
+    schema: Option<Schema>,
+    table_partition_columns: Option<Schema>,
+}
+
+impl<'a> DFJSONReadOptions<'a> {
+    pub fn new() -> Self {
+        let options = NdJsonReadOptions::default();
+        let schema = None;
+        let table_partition_columns = None;
+        Self {
+            options,
+            schema,
+            table_partition_columns,
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn df_json_read_options_new<'a>() -> Box<DFJSONReadOptions<'a>> {
+    Box::new(DFJSONReadOptions::new())
+}
+
+#[no_mangle]
+pub extern "C" fn df_json_read_options_free(_options: Option<Box<DFJSONReadOptions>>) {}
+
+#[no_mangle]
+pub extern "C" fn df_json_read_options_set_schema<'a>(
+    options: &'a mut DFJSONReadOptions<'a>,
+    schema: Option<Box<DFArrowSchema>>,
+    error: *mut *mut DFError,
+) -> bool {
+    df_catch_panic(error, false, || {
+        let option = || -> Option<bool> {
+            match schema {
+                Some(mut s) => {
+                    let rs_ffi_schema =
+                        (s.as_mut() as *mut DFArrowSchema) as *mut FFI_ArrowSchema;
+                    let rs_schema = Schema::try_from(unsafe { &*rs_ffi_schema })
+                        .into_df_error(error, None)?;
+                    options.schema = Some(rs_schema);
+                    options.options.schema = options.schema.as_ref();
+                }
+                None => {
+                    options.schema = None;
+                    options.options.schema = None;
+                }
+            };
+            Some(true)
+        }();
+        option.unwrap_or(false)
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn df_json_read_options_get_schema(
+    options: &mut DFJSONReadOptions,
+    error: *mut *mut DFError,
+) -> Option<Box<DFArrowSchema>> {
+    df_catch_panic(error, None, || match options.options.schema {
+        Some(rs_schema) => {
+            let rs_ffi_schema =
+                FFI_ArrowSchema::try_from(rs_schema).into_df_error(error, None)?;
+            Some(Box::<DFArrowSchema>::from(rs_ffi_schema))
+        }
+        None => None,
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn df_json_read_options_set_schema_infer_max_records(
+    options: &mut DFJSONReadOptions,
+    n: usize,
+) {
+    options.options.schema_infer_max_records = n;
+}
+
+#[no_mangle]
+pub extern "C" fn df_json_read_options_get_schema_infer_max_records(
+    options: &mut DFJSONReadOptions,
+) -> usize {
+    options.options.schema_infer_max_records
+}
+
+#[no_mangle]
+pub extern "C" fn df_json_read_options_set_file_extension(
+    options: &mut DFJSONReadOptions,
+    file_extension: *const libc::c_char,
+    error: *mut *mut DFError,
+) -> bool {
+    df_catch_panic(error, false, || {
+        let option = || -> Option<bool> {
+            options.options.file_extension =
+                c_string_to_str(file_extension).into_df_error(error, None)?;
+            Some(true)
+        }();
+        option.unwrap_or(false)
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn df_json_read_options_get_file_extension(
+    options: &mut DFJSONReadOptions,
+) -> *mut libc::c_char {
+    strdup(options.options.file_extension)
+}
+
+#[no_mangle]
+pub extern "C" fn df_json_read_options_set_table_partition_columns(
+    options: &mut DFJSONReadOptions,
+    schema: Option<Box<DFArrowSchema>>,
+    error: *mut *mut DFError,
+) -> bool {
+    df_catch_panic(error, false, || {
+        set_table_partition_columns(
+            &mut options.options.table_partition_cols,
+            &mut options.table_partition_columns,
+            schema,
+            error,
+        )
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn df_json_read_options_get_table_partition_columns(
+    options: &mut DFJSONReadOptions,
+    error: *mut *mut DFError,
+) -> Option<Box<DFArrowSchema>> {
+    df_catch_panic(error, None, || {
+        get_table_partition_columns(&options.options.table_partition_cols, error)
+    })
+}
+
+/// \brief Set the compression type of JSON files.
+///
+/// The file extension isn't changed automatically. You may need to
+/// change it too by `df_json_read_options_set_file_extension()` such
+/// as `.json.gz`.
+///
+/// \param options A `DFJSONReadOptions`.
+/// \param compression_type A compression type.
+#[no_mangle]
+pub extern "C" fn df_json_read_options_set_file_compression_type(
+    options: &mut DFJSONReadOptions,
+    compression_type: DFFileCompressionType,
+) {
+    options.options.file_compression_type = compression_type.into();
+}
+
+#[no_mangle]
+pub extern "C" fn df_json_read_options_get_file_compression_type(
+    options: &mut DFJSONReadOptions,
+) -> DFFileCompressionType {
+    (&options.options.file_compression_type).into()
+}
+
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn df_session_context_register_json(
+    context: &mut DFSessionContext,
+    name: *const libc::c_char,
+    url: *const libc::c_char,
+    options: Option<&mut DFJSONReadOptions>,
+    error: *mut *mut DFError,
+) -> bool {
+    df_catch_panic(error, false, || {
+        let option = || -> Option<bool> {
+            let rs_name = c_string_to_str(name).into_df_error(error, None)?;
+            let rs_url = c_string_to_str(url).into_df_error(error, None)?;
+            let rs_options = match options {
+                Some(o) => o.options.clone(),
+                None => NdJsonReadOptions::default(),
+            };
+            let executor = context.executor().into_df_error(error, None)?;
+            let result = context.context.register_json(rs_name, rs_url, rs_options);
+            executor.block_on(result).into_df_error(error, None)?;
+            Some(true)
+        }();
+        option.unwrap_or(false)
+    })
 }
 
+/// \brief Create a `DFDataFrame` that reads the given newline-delimited
+/// JSON files without registering them as a table.
+///
+/// \param context A `DFSessionContext`.
+/// \param url A path or URL of a JSON file or a directory that has
+///   JSON files.
+/// \param options A `DFJSONReadOptions` or `NULL`. If this is `NULL`,
+///   the default options are used.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFDataFrame` on success, `NULL` on error.
+///
+///   It should be freed by `df_data_frame_free()` when no longer
+///   needed.
 #[no_mangle]
 #[allow(clippy::not_unsafe_ptr_arg_deref)]
-pub extern "C" fn df_session_context_register_record_batches(
+pub extern "C" fn df_session_context_read_json(
     context: &mut DFSessionContext,
-    name: *const libc::c_char,
-    c_abi_schema: &mut DFArrowSchema,
-    c_abi_record_batches: &mut *mut DFArrowArray,
-    n_record_batches: libc::size_t,
+    url: *const libc::c_char,
+    options: Option<&mut DFJSONReadOptions>,
     error: *mut *mut DFError,
-) -> bool {
-    let option = || -> Option<bool> {
-        let cstr_name = unsafe { CStr::from_ptr(name) };
-        let rs_name = cstr_name.to_str().into_df_error(error, None)?;
-        let rs_ffi_schema = unsafe {
-            std::ptr::replace(
-                (c_abi_schema as *mut DFArrowSchema) as *mut FFI_ArrowSchema,
-                FFI_ArrowSchema::empty(),
-            )
+) -> Option<Box<DFDataFrame>> {
+    df_catch_panic(error, None, || {
+        let rs_url = c_string_to_str(url).into_df_error(error, None)?;
+        let rs_options = match options {
+            Some(o) => o.options.clone(),
+            None => NdJsonReadOptions::default(),
         };
-        let rs_schema = Schema::try_from(&rs_ffi_schema).into_df_error(error, None)?;
-        let mut rs_record_batches = Vec::new();
-        let c_abi_record_batch_slice =
-            unsafe { std::slice::from_raw_parts(c_abi_record_batches, n_record_batches) };
-        for c_abi_record_batch in c_abi_record_batch_slice {
-            let rs_ffi_record_batch = unsafe {
-                std::ptr::replace(
-                    *c_abi_record_batch as *mut FFI_ArrowArray,
-                    FFI_ArrowArray::empty(),
-                )
-            };
-            // We want to share rs_ffi_schema by passing
-            // Arc<FFI_ArrowSchema> but arrow-rs's ArrowArray API
-            // doesn't accept it. So we export schema.
-            let rs_ffi_schema = FFI_ArrowSchema::try_from(rs_schema.clone())
-                .into_df_error(error, None)?;
-            let rs_record_batch_array =
-                ArrowArray::new(rs_ffi_record_batch, rs_ffi_schema);
-            let rs_record_batch_data =
-                ArrayData::try_from(rs_record_batch_array).into_df_error(error, None)?;
-            let rs_record_batch =
-                RecordBatch::from(&StructArray::from(rs_record_batch_data));
-            rs_record_batches.push(rs_record_batch);
-        }
-        let rs_table = Arc::new(
-            MemTable::try_new(Arc::new(rs_schema), vec![rs_record_batches])
-                .into_df_error(error, None)?,
-        );
-        context
-            .context
-            .register_table(rs_name, rs_table)
-            .into_df_error(error, None)?;
-        Some(true)
-    }();
-    option.unwrap_or(false)
+        let executor = context.executor().into_df_error(error, None)?;
+        let result = context.context.read_json(rs_url, rs_options);
+        let data_frame = executor.block_on(result).into_df_error(error, None)?;
+        Some(Box::new(DFDataFrame::new(data_frame, executor)))
+    })
 }
 
-pub struct DFCSVReadOptions<'a> {
-    options: CsvReadOptions<'a>,
+/// \struct DFAvroReadOptions
+/// \brief Options for reading Apache Avro files.
+///
+/// Reading Apache Avro files reports a `DF_ERROR_CODE_NOT_IMPLEMENTED`
+/// error when this library is built without the `avro` feature.
+///
+/// You need to free this by `df_avro_read_options_free()` when no
+/// longer needed.
+pub struct DFAvroReadOptions<'a> {
+    options: AvroReadOptions<'a>,
     schema: Option<Schema>,
     table_partition_columns: Option<Schema>,
 }
 
-impl<'a> DFCSVReadOptions<'a> {
+impl<'a> DFAvroReadOptions<'a> {
     pub fn new() -> Self {
-        let options = CsvReadOptions::default();
+        let options = AvroReadOptions::default();
         let schema = None;
         let table_partition_columns = None;
         Self {
@@ -698,179 +5940,191 @@ impl<'a> DFCSVReadOptions<'a> {
 }
 
 #[no_mangle]
-pub extern "C" fn df_csv_read_options_new<'a>() -> Box<DFCSVReadOptions<'a>> {
-    Box::new(DFCSVReadOptions::new())
-}
-
-#[no_mangle]
-pub extern "C" fn df_csv_read_options_free(_options: Option<Box<DFCSVReadOptions>>) {}
-
-#[no_mangle]
-pub extern "C" fn df_csv_read_options_set_has_header(
-    options: &mut DFCSVReadOptions,
-    has_header: bool,
-) {
-    options.options.has_header = has_header;
-}
-
-#[no_mangle]
-pub extern "C" fn df_csv_read_options_get_has_header(
-    options: &mut DFCSVReadOptions,
-) -> bool {
-    options.options.has_header
-}
-
-#[no_mangle]
-pub extern "C" fn df_csv_read_options_set_delimiter(
-    options: &mut DFCSVReadOptions,
-    delimiter: u8,
-) {
-    options.options.delimiter = delimiter;
+pub extern "C" fn df_avro_read_options_new<'a>() -> Box<DFAvroReadOptions<'a>> {
+    Box::new(DFAvroReadOptions::new())
 }
 
 #[no_mangle]
-pub extern "C" fn df_csv_read_options_get_delimiter(
-    options: &mut DFCSVReadOptions,
-) -> u8 {
-    options.options.delimiter
-}
+pub extern "C" fn df_avro_read_options_free(_options: Option<Box<DFAvroReadOptions>>) {}
 
 #[no_mangle]
-pub extern "C" fn df_csv_read_options_set_schema<'a>(
-    options: &'a mut DFCSVReadOptions<'a>,
+pub extern "C" fn df_avro_read_options_set_schema<'a>(
+    options: &'a mut DFAvroReadOptions<'a>,
     schema: Option<Box<DFArrowSchema>>,
     error: *mut *mut DFError,
 ) -> bool {
-    let option = || -> Option<bool> {
-        match schema {
-            Some(mut s) => {
-                let rs_ffi_schema =
-                    (s.as_mut() as *mut DFArrowSchema) as *mut FFI_ArrowSchema;
-                let rs_schema = Schema::try_from(unsafe { &*rs_ffi_schema })
-                    .into_df_error(error, None)?;
-                options.schema = Some(rs_schema);
-                options.options.schema = options.schema.as_ref();
-            }
-            None => {
-                options.schema = None;
-                options.options.schema = None;
-            }
-        };
-        Some(true)
-    }();
-    option.unwrap_or(false)
+    df_catch_panic(error, false, || {
+        let option = || -> Option<bool> {
+            match schema {
+                Some(mut s) => {
+                    let rs_ffi_schema =
+                        (s.as_mut() as *mut DFArrowSchema) as *mut FFI_ArrowSchema;
+                    let rs_schema = Schema::try_from(unsafe { &*rs_ffi_schema })
+                        .into_df_error(error, None)?;
+                    options.schema = Some(rs_schema);
+                    options.options.schema = options.schema.as_ref();
+                }
+                None => {
+                    options.schema = None;
+                    options.options.schema = None;
+                }
+            };
+            Some(true)
+        }();
+        option.unwrap_or(false)
+    })
 }
 
 #[no_mangle]
-pub extern "C" fn df_csv_read_options_get_schema(
-    options: &mut DFCSVReadOptions,
+pub extern "C" fn df_avro_read_options_get_schema(
+    options: &mut DFAvroReadOptions,
     error: *mut *mut DFError,
 ) -> Option<Box<DFArrowSchema>> {
-    match options.options.schema {
+    df_catch_panic(error, None, || match options.options.schema {
         Some(rs_schema) => {
             let rs_ffi_schema =
                 FFI_ArrowSchema::try_from(rs_schema).into_df_error(error, None)?;
             Some(Box::<DFArrowSchema>::from(rs_ffi_schema))
         }
         None => None,
-    }
-}
-
-#[no_mangle]
-pub extern "C" fn df_csv_read_options_set_schema_infer_max_records(
-    options: &mut DFCSVReadOptions,
-    n: usize,
-) {
-    options.options.schema_infer_max_records = n;
-}
-
-#[no_mangle]
-pub extern "C" fn df_csv_read_options_get_schema_infer_max_records(
-    options: &mut DFCSVReadOptions,
-) -> usize {
-    options.options.schema_infer_max_records
+    })
 }
 
 #[no_mangle]
-pub extern "C" fn df_csv_read_options_set_file_extension(
-    options: &mut DFCSVReadOptions,
+pub extern "C" fn df_avro_read_options_set_file_extension(
+    options: &mut DFAvroReadOptions,
     file_extension: *const libc::c_char,
     error: *mut *mut DFError,
 ) -> bool {
-    let option = || -> Option<bool> {
-        options.options.file_extension =
-            c_string_to_str(file_extension).into_df_error(error, None)?;
-        Some(true)
-    }();
-    option.unwrap_or(false)
+    df_catch_panic(error, false, || {
+        let option = || -> Option<bool> {
+            options.options.file_extension =
+                c_string_to_str(file_extension).into_df_error(error, None)?;
+            Some(true)
+        }();
+        option.unwrap_or(false)
+    })
 }
 
 #[no_mangle]
-pub extern "C" fn df_csv_read_options_get_file_extension(
-    options: &mut DFCSVReadOptions,
+pub extern "C" fn df_avro_read_options_get_file_extension(
+    options: &mut DFAvroReadOptions,
 ) -> *mut libc::c_char {
     strdup(options.options.file_extension)
 }
 
 #[no_mangle]
-pub extern "C" fn df_csv_read_options_set_table_partition_columns(
-    options: &mut DFCSVReadOptions,
+pub extern "C" fn df_avro_read_options_set_table_partition_columns(
+    options: &mut DFAvroReadOptions,
     schema: Option<Box<DFArrowSchema>>,
     error: *mut *mut DFError,
 ) -> bool {
-    set_table_partition_columns(
-        &mut options.options.table_partition_cols,
-        &mut options.table_partition_columns,
-        schema,
-        error,
-    )
+    df_catch_panic(error, false, || {
+        set_table_partition_columns(
+            &mut options.options.table_partition_cols,
+            &mut options.table_partition_columns,
+            schema,
+            error,
+        )
+    })
 }
 
 #[no_mangle]
-pub extern "C" fn df_csv_read_options_get_table_partition_columns(
-    options: &mut DFCSVReadOptions,
+pub extern "C" fn df_avro_read_options_get_table_partition_columns(
+    options: &mut DFAvroReadOptions,
     error: *mut *mut DFError,
 ) -> Option<Box<DFArrowSchema>> {
-    get_table_partition_columns(&options.options.table_partition_cols, error)
+    df_catch_panic(error, None, || {
+        get_table_partition_columns(&options.options.table_partition_cols, error)
+    })
 }
 
 #[no_mangle]
 #[allow(clippy::not_unsafe_ptr_arg_deref)]
-pub extern "C" fn df_session_context_register_csv(
+pub extern "C" fn df_session_context_register_avro(
     context: &mut DFSessionContext,
     name: *const libc::c_char,
     url: *const libc::c_char,
-    options: Option<&mut DFCSVReadOptions>,
+    options: Option<&mut DFAvroReadOptions>,
     error: *mut *mut DFError,
 ) -> bool {
-    let option = || -> Option<bool> {
-        let rs_name = c_string_to_str(name).into_df_error(error, None)?;
+    df_catch_panic(error, false, || {
+        let option = || -> Option<bool> {
+            let rs_name = c_string_to_str(name).into_df_error(error, None)?;
+            let rs_url = c_string_to_str(url).into_df_error(error, None)?;
+            let rs_options = match options {
+                Some(o) => o.options.clone(),
+                None => AvroReadOptions::default(),
+            };
+            let executor = context.executor().into_df_error(error, None)?;
+            let result = context.context.register_avro(rs_name, rs_url, rs_options);
+            executor.block_on(result).into_df_error(error, None)?;
+            Some(true)
+        }();
+        option.unwrap_or(false)
+    })
+}
+
+/// \brief Create a `DFDataFrame` that reads the given Apache Avro files
+/// without registering them as a table.
+///
+/// \param context A `DFSessionContext`.
+/// \param url A path or URL of an Apache Avro file or a directory
+///   that has Apache Avro files.
+/// \param options A `DFAvroReadOptions` or `NULL`. If this is `NULL`,
+///   the default options are used.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFDataFrame` on success, `NULL` on error.
+///
+///   It should be freed by `df_data_frame_free()` when no longer
+///   needed.
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn df_session_context_read_avro(
+    context: &mut DFSessionContext,
+    url: *const libc::c_char,
+    options: Option<&mut DFAvroReadOptions>,
+    error: *mut *mut DFError,
+) -> Option<Box<DFDataFrame>> {
+    df_catch_panic(error, None, || {
         let rs_url = c_string_to_str(url).into_df_error(error, None)?;
         let rs_options = match options {
             Some(o) => o.options.clone(),
-            None => CsvReadOptions::default(),
+            None => AvroReadOptions::default(),
         };
-        let result = context.context.register_csv(rs_name, rs_url, rs_options);
-        block_on(result).into_df_error(error, None)?;
-        Some(true)
-    }();
-    option.unwrap_or(false)
+        let executor = context.executor().into_df_error(error, None)?;
+        let result = context.context.read_avro(rs_url, rs_options);
+        let data_frame = executor.block_on(result).into_df_error(error, None)?;
+        Some(Box::new(DFDataFrame::new(data_frame, executor)))
+    })
 }
 
 pub struct DFParquetReadOptions<'a> {
     options: ParquetReadOptions<'a>,
+    schema: Option<Schema>,
     table_partition_columns: Option<Schema>,
+    file_sort_order: Option<Vec<Expr>>,
 }
 
 impl<'a> DFParquetReadOptions<'a> {
     pub fn new() -> Self {
         let options = ParquetReadOptions::default();
+        let schema = None;
         let table_partition_columns = None;
+        let file_sort_order = None;
         Self {
             options,
+            schema,
             table_partition_columns,
+            file_sort_order,
         }
     }
+
+    fn to_listing_options(&self, config: &SessionConfig) -> ListingOptions {
+        self.options
+            .to_listing_options(config)
+            .with_file_sort_order(self.file_sort_order.clone())
+    }
 }
 
 #[no_mangle]
@@ -890,12 +6144,14 @@ pub extern "C" fn df_parquet_read_options_set_file_extension(
     file_extension: *const libc::c_char,
     error: *mut *mut DFError,
 ) -> bool {
-    let option = || -> Option<bool> {
-        options.options.file_extension =
-            c_string_to_str(file_extension).into_df_error(error, None)?;
-        Some(true)
-    }();
-    option.unwrap_or(false)
+    df_catch_panic(error, false, || {
+        let option = || -> Option<bool> {
+            options.options.file_extension =
+                c_string_to_str(file_extension).into_df_error(error, None)?;
+            Some(true)
+        }();
+        option.unwrap_or(false)
+    })
 }
 
 #[no_mangle]
@@ -911,12 +6167,14 @@ pub extern "C" fn df_parquet_read_options_set_table_partition_columns(
     schema: Option<Box<DFArrowSchema>>,
     error: *mut *mut DFError,
 ) -> bool {
-    set_table_partition_columns(
-        &mut options.options.table_partition_cols,
-        &mut options.table_partition_columns,
-        schema,
-        error,
-    )
+    df_catch_panic(error, false, || {
+        set_table_partition_columns(
+            &mut options.options.table_partition_cols,
+            &mut options.table_partition_columns,
+            schema,
+            error,
+        )
+    })
 }
 
 #[no_mangle]
@@ -924,7 +6182,9 @@ pub extern "C" fn df_parquet_read_options_get_table_partition_columns(
     options: &mut DFParquetReadOptions,
     error: *mut *mut DFError,
 ) -> Option<Box<DFArrowSchema>> {
-    get_table_partition_columns(&options.options.table_partition_cols, error)
+    df_catch_panic(error, None, || {
+        get_table_partition_columns(&options.options.table_partition_cols, error)
+    })
 }
 
 #[no_mangle]
@@ -959,6 +6219,186 @@ pub extern "C" fn df_parquet_read_options_get_pruning(
     options.options.parquet_pruning.unwrap_or(false)
 }
 
+/// \brief Set the schema of Apache Parquet files.
+///
+/// The schema isn't inferred from the files when this is specified.
+///
+/// \param options A `DFParquetReadOptions`.
+/// \param schema A schema of the files or `NULL`. If this is `NULL`,
+///   the schema is inferred from the files.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return `true` on success, `false` otherwise.
+#[no_mangle]
+pub extern "C" fn df_parquet_read_options_set_schema(
+    options: &mut DFParquetReadOptions,
+    schema: Option<Box<DFArrowSchema>>,
+    error: *mut *mut DFError,
+) -> bool {
+    df_catch_panic(error, false, || {
+        let option = || -> Option<bool> {
+            match schema {
+                Some(mut s) => {
+                    let rs_ffi_schema =
+                        (s.as_mut() as *mut DFArrowSchema) as *mut FFI_ArrowSchema;
+                    let rs_schema = Schema::try_from(unsafe { &*rs_ffi_schema })
+                        .into_df_error(error, None)?;
+                    options.schema = Some(rs_schema);
+                }
+                None => options.schema = None,
+            };
+            Some(true)
+        }();
+        option.unwrap_or(false)
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn df_parquet_read_options_get_schema(
+    options: &mut DFParquetReadOptions,
+    error: *mut *mut DFError,
+) -> Option<Box<DFArrowSchema>> {
+    df_catch_panic(error, None, || match &options.schema {
+        Some(rs_schema) => {
+            let rs_ffi_schema =
+                FFI_ArrowSchema::try_from(rs_schema).into_df_error(error, None)?;
+            Some(Box::<DFArrowSchema>::from(rs_ffi_schema))
+        }
+        None => None,
+    })
+}
+
+/// \brief Set whether metadata embedded in the schema of Apache
+/// Parquet files is skipped or not.
+///
+/// Skipping metadata helps to merge schemas of files that have
+/// different metadata. If this isn't set, the
+/// `datafusion.execution.parquet.skip_metadata` configuration is used.
+///
+/// \param options A `DFParquetReadOptions`.
+/// \param skip_metadata Whether metadata is skipped or not.
+#[no_mangle]
+pub extern "C" fn df_parquet_read_options_set_skip_metadata(
+    options: &mut DFParquetReadOptions,
+    skip_metadata: bool,
+) {
+    options.options.skip_metadata = Some(skip_metadata);
+}
+
+#[no_mangle]
+pub extern "C" fn df_parquet_read_options_unset_skip_metadata(
+    options: &mut DFParquetReadOptions,
+) {
+    options.options.skip_metadata = None;
+}
+
+#[no_mangle]
+pub extern "C" fn df_parquet_read_options_is_set_skip_metadata(
+    options: &mut DFParquetReadOptions,
+) -> bool {
+    options.options.skip_metadata.is_some()
+}
+
+/// \brief Get whether metadata embedded in the schema of Apache
+/// Parquet files is skipped or not.
+///
+/// \param options A `DFParquetReadOptions`.
+/// \return The set value. If this isn't set, the default value of
+///   the `datafusion.execution.parquet.skip_metadata` configuration.
+///   It's `true`. The configuration of a session context isn't
+///   considered.
+#[no_mangle]
+pub extern "C" fn df_parquet_read_options_get_skip_metadata(
+    options: &mut DFParquetReadOptions,
+) -> bool {
+    df_abort_on_panic(|| {
+        options
+            .options
+            .skip_metadata
+            .unwrap_or_else(|| ConfigOptions::new().execution.parquet.skip_metadata)
+    })
+}
+
+fn file_sort_order_from_raw(
+    sort_exprs: *const *const DFExpr,
+    n_sort_exprs: usize,
+) -> Result<Vec<Expr>, DataFusionError> {
+    if n_sort_exprs == 0 {
+        return Ok(vec![]);
+    }
+    if sort_exprs.is_null() {
+        return Err(DataFusionError::Plan(
+            "sort_exprs must not be NULL".to_string(),
+        ));
+    }
+    let sort_expr_slice = unsafe { std::slice::from_raw_parts(sort_exprs, n_sort_exprs) };
+    sort_expr_slice
+        .iter()
+        .map(|sort_expr| match unsafe { sort_expr.as_ref() } {
+            Some(sort_expr) => match &sort_expr.expr {
+                Expr::Sort(expr::Sort { expr, .. }) => match expr.as_ref() {
+                    Expr::Column(_) => Ok(sort_expr.expr.clone()),
+                    _ => Err(DataFusionError::Plan(format!(
+                        "sort key must be a column reference: {expr}"
+                    ))),
+                },
+                rs_expr => Err(DataFusionError::Plan(format!(
+                    "sort expression must be created by df_expr_new_sort(): {rs_expr}"
+                ))),
+            },
+            None => Err(DataFusionError::Plan(
+                "sort expression must not be NULL".to_string(),
+            )),
+        })
+        .collect()
+}
+
+/// \brief Declare the sort order of Apache Parquet files.
+///
+/// DataFusion uses this to avoid redundant sorts. Each sort key must
+/// be a column reference.
+///
+/// \param options A `DFParquetReadOptions`.
+/// \param sort_exprs Sort keys created by `df_expr_new_sort()`.
+/// \param n_sort_exprs The number of `sort_exprs`. If this is `0`,
+///   the files aren't treated as sorted.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return `true` on success, `false` otherwise. The current sort
+///   order isn't changed on error.
+#[no_mangle]
+pub extern "C" fn df_parquet_read_options_set_file_sort_order(
+    options: &mut DFParquetReadOptions,
+    sort_exprs: *const *const DFExpr,
+    n_sort_exprs: usize,
+    error: *mut *mut DFError,
+) -> bool {
+    df_catch_panic(error, false, || {
+        let option = || -> Option<bool> {
+            let rs_sort_exprs = file_sort_order_from_raw(sort_exprs, n_sort_exprs)
+                .into_df_error(error, None)?;
+            options.file_sort_order = if rs_sort_exprs.is_empty() {
+                None
+            } else {
+                Some(rs_sort_exprs)
+            };
+            Some(true)
+        }();
+        option.unwrap_or(false)
+    })
+}
+
+fn parquet_listing_options(
+    options: Option<&mut DFParquetReadOptions>,
+    config: &SessionConfig,
+) -> (ListingOptions, Option<SchemaRef>) {
+    match options {
+        Some(o) => (o.to_listing_options(config), o.schema.clone().map(Arc::new)),
+        None => (
+            ParquetReadOptions::default().to_listing_options(config),
+            None,
+        ),
+    }
+}
+
 #[no_mangle]
 #[allow(clippy::not_unsafe_ptr_arg_deref)]
 pub extern "C" fn df_session_context_register_parquet(
@@ -968,18 +6408,70 @@ pub extern "C" fn df_session_context_register_parquet(
     options: Option<&mut DFParquetReadOptions>,
     error: *mut *mut DFError,
 ) -> bool {
-    let option = || -> Option<bool> {
-        let rs_name = c_string_to_str(name).into_df_error(error, None)?;
+    df_catch_panic(error, false, || {
+        let option = || -> Option<bool> {
+            let rs_name = c_string_to_str(name).into_df_error(error, None)?;
+            let rs_url = c_string_to_str(url).into_df_error(error, None)?;
+            let (listing_options, schema) =
+                parquet_listing_options(options, &context.context.copied_config());
+            let executor = context.executor().into_df_error(error, None)?;
+            let result = context.context.register_listing_table(
+                rs_name,
+                rs_url,
+                listing_options,
+                schema,
+                None,
+            );
+            executor.block_on(result).into_df_error(error, None)?;
+            Some(true)
+        }();
+        option.unwrap_or(false)
+    })
+}
+
+/// \brief Create a `DFDataFrame` that reads the given Apache Parquet files
+/// without registering them as a table.
+///
+/// \param context A `DFSessionContext`.
+/// \param url A path or URL of an Apache Parquet file or a directory
+///   that has Apache Parquet files.
+/// \param options A `DFParquetReadOptions` or `NULL`. If this is `NULL`,
+///   the default options are used.
+/// \param error Return location for a `DFError` or `NULL`.
+/// \return A newly created `DFDataFrame` on success, `NULL` on error.
+///
+///   It should be freed by `df_data_frame_free()` when no longer
+///   needed.
+#[no_mangle]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub extern "C" fn df_session_context_read_parquet(
+    context: &mut DFSessionContext,
+    url: *const libc::c_char,
+    options: Option<&mut DFParquetReadOptions>,
+    error: *mut *mut DFError,
+) -> Option<Box<DFDataFrame>> {
+    df_catch_panic(error, None, || {
         let rs_url = c_string_to_str(url).into_df_error(error, None)?;
-        let rs_options = match options {
-            Some(o) => o.options.clone(),
-            None => ParquetReadOptions::default(),
+        let (listing_options, schema) =
+            parquet_listing_options(options, &context.context.copied_config());
+        let state = context.context.state();
+        let executor = context.executor().into_df_error(error, None)?;
+        let result = async move {
+            let table_path = ListingTableUrl::parse(rs_url)?;
+            let resolved_schema = match schema {
+                Some(s) => s,
+                None => listing_options.infer_schema(&state, &table_path).await?,
+            };
+            let config = ListingTableConfig::new(table_path)
+                .with_listing_options(listing_options)
+                .with_schema(resolved_schema);
+            ListingTable::try_new(config)
         };
-        let result = context
+        let provider = executor.block_on(result).into_df_error(error, None)?;
+        let data_frame = context
             .context
-            .register_parquet(rs_name, rs_url, rs_options);
-        block_on(result).into_df_error(error, None)?;
-        Some(true)
-    }();
-    option.unwrap_or(false)
+            .read_table(Arc::new(provider))
+            .into_df_error(error, None)?;
+        Some(Box::new(DFDataFrame::new(data_frame, executor)))
+    })
 }
@@ -12,8 +12,10 @@
 # See the License for the specific language governing permissions and
 # limitations under the License.
 
+require "json"
 require "tempfile"
 require "tmpdir"
+require "zlib"
 
 require "test-unit"
 
@@ -22,3 +24,49 @@ require "parquet"
 require "gi"
 
 DataFusion = GI.load("DataFusion")
+
+module Helper
+  module Avro
+    # Writes a minimal Apache Avro object container file with a
+    # {a: long, b: string} record schema.
+    def write_avro(output, rows)
+      encode_long = lambda do |value|
+        value = (value << 1) ^ (value >> 63)
+        bytes = []
+        while value >= 0x80
+          bytes << ((value & 0x7f) | 0x80)
+          value >>= 7
+        end
+        bytes << value
+        bytes.pack("C*")
+      end
+      encode_string = lambda do |value|
+        encode_long.call(value.bytesize) + value.b
+      end
+      schema = {
+        type: "record",
+        name: "data",
+        fields: [
+          {name: "a", type: "long"},
+          {name: "b", type: "string"},
+        ],
+      }
+      sync_marker = "0123456789abcdef"
+      output.write("Obj\x01".b)
+      output.write(encode_long.call(2))
+      output.write(encode_string.call("avro.schema"))
+      output.write(encode_string.call(JSON.generate(schema)))
+      output.write(encode_string.call("avro.codec"))
+      output.write(encode_string.call("null"))
+      output.write(encode_long.call(0))
+      output.write(sync_marker)
+      block = rows.collect do |a, b|
+        encode_long.call(a) + encode_string.call(b)
+      end.join
+      output.write(encode_long.call(rows.size))
+      output.write(encode_long.call(block.bytesize))
+      output.write(block)
+      output.write(sync_marker)
+    end
+  end
+end
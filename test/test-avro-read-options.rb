@@ -0,0 +1,41 @@
+# Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+class AvroReadOptionsTest < Test::Unit::TestCase
+  def setup
+    @options = DataFusion::AvroReadOptions.new
+  end
+
+  def test_schema
+    assert_nil(@options.schema)
+    schema = Arrow::Schema.new("a" => :int64)
+    @options.schema = schema
+    assert_equal(schema, @options.schema)
+  end
+
+  def test_file_extension
+    assert_equal(".avro", @options.file_extension)
+    @options.file_extension = ".data"
+    assert_equal(".data", @options.file_extension)
+  end
+
+  def test_table_partition_columns
+    assert_nil(@options.table_partition_columns)
+    table_partition_columns = Arrow::Schema.new(a: :int8,
+                                                b: :boolean)
+    @options.table_partition_columns = table_partition_columns
+    assert_equal(table_partition_columns,
+                 @options.table_partition_columns)
+  end
+end
@@ -0,0 +1,113 @@
+# Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+class CancellationTokenTest < Test::Unit::TestCase
+  def setup
+    @context = DataFusion::SessionContext.new
+    table = Arrow::Table.new(number: Arrow::Int64Array.new([1, 2, 3]))
+    @context.register_table("data", table)
+    @token = DataFusion::CancellationToken.new
+  end
+
+  def teardown
+    @runtime.shutdown(1000) if @runtime
+  end
+
+  def test_cancel
+    assert_false(@token.cancelled?)
+    @token.cancel
+    assert_true(@token.cancelled?)
+  end
+
+  def test_session_context
+    @context.cancellation_token = @token
+    data_frame = @context.sql("SELECT * FROM data")
+    @token.cancel
+    error = assert_raise(GLib::Error) do
+      data_frame.to_table
+    end
+    assert_equal(DataFusion::Error::CANCELLED, error.code)
+  end
+
+  def test_data_frame
+    data_frame = @context.sql("SELECT * FROM data")
+    data_frame.cancellation_token = @token
+    @token.cancel
+    error = assert_raise(GLib::Error) do
+      data_frame.to_table
+    end
+    assert_equal(DataFusion::Error::CANCELLED, error.code)
+    data_frame.cancellation_token = nil
+    assert_equal(Arrow::Table.new(number: Arrow::Int64Array.new([1, 2, 3])),
+                 data_frame.to_table)
+  end
+
+  def test_running
+    # Use a dedicated runtime to not leave the query running on the
+    # default runtime.
+    @runtime = DataFusion::TokioRuntime.new(:multi_thread, 2)
+    @context.tokio_runtime = @runtime
+    @context.sql("SET datafusion.execution.target_partitions = 4")
+    values = "(VALUES " + (0...1000).collect {|i| "(#{i})"}.join(", ") + ")"
+    data_frame = @context.sql(<<-SQL)
+SELECT sum(a.column1 * b.column1 + c.column1)
+  FROM #{values} a, #{values} b, #{values} c
+    SQL
+    data_frame.cancellation_token = @token
+    loop = GLib::MainLoop.new
+    error = nil
+    data_frame.to_table_async do |_, result|
+      begin
+        data_frame.to_table_finish(result)
+      rescue GLib::Error => e
+        error = e
+      end
+      loop.quit
+    end
+    GLib::Timeout.add(100) do
+      @token.cancel
+      GLib::SOURCE_REMOVE
+    end
+    loop.run
+    assert_equal(DataFusion::Error::CANCELLED, error&.code)
+  end
+
+  def test_cancellable
+    @runtime = DataFusion::TokioRuntime.new(:multi_thread, 2)
+    @context.tokio_runtime = @runtime
+    @context.sql("SET datafusion.execution.target_partitions = 4")
+    values = "(VALUES " + (0...1000).collect {|i| "(#{i})"}.join(", ") + ")"
+    data_frame = @context.sql(<<-SQL)
+SELECT sum(a.column1 * b.column1 + c.column1)
+  FROM #{values} a, #{values} b, #{values} c
+    SQL
+    cancellable = Gio::Cancellable.new
+    loop = GLib::MainLoop.new
+    error = nil
+    data_frame.to_table_async(cancellable) do |_, result|
+      begin
+        data_frame.to_table_finish(result)
+      rescue GLib::Error => e
+        error = e
+      end
+      loop.quit
+    end
+    GLib::Timeout.add(100) do
+      cancellable.cancel
+      GLib::SOURCE_REMOVE
+    end
+    loop.run
+    assert_equal(DataFusion::Error::CANCELLED, error&.code)
+  end
+end
@@ -60,4 +60,12 @@ class CSVReadOptionsTest < Test::Unit::TestCase
     assert_equal(table_partition_columns,
                  @options.table_partition_columns)
   end
+
+  def test_file_compression_type
+    assert_equal(DataFusion::FileCompressionType::UNCOMPRESSED,
+                 @options.file_compression_type)
+    @options.file_compression_type = :gzip
+    assert_equal(DataFusion::FileCompressionType::GZIP,
+                 @options.file_compression_type)
+  end
 end
@@ -29,6 +29,310 @@ class DataFrameTest < Test::Unit::TestCase
                  @data_frame.to_table)
   end
 
+  def test_to_table_empty
+    assert_equal(Arrow::Table.new(number: Arrow::UInt8Array.new([])),
+                 @data_frame.limit(0, 0).to_table)
+  end
+
+  def test_to_table_async
+    loop = GLib::MainLoop.new
+    table = nil
+    @data_frame.to_table_async do |data_frame, result|
+      table = data_frame.to_table_finish(result)
+      loop.quit
+    end
+    loop.run
+    assert_equal(Arrow::Table.new(number: Arrow::UInt8Array.new([1, 2, 3])),
+                 table)
+  end
+
+  def test_execute_stream
+    reader = @data_frame.execute_stream
+    assert_equal(@data_frame.to_table, reader.read_all)
+  end
+
+  def test_schema
+    assert_equal(Arrow::Schema.new(number: :uint8),
+                 @data_frame.schema)
+  end
+
+  def test_schema_nullable_and_metadata
+    point_fields = [
+      Arrow::Field.new("x", :int64).with_metadata("unit" => "cm"),
+    ]
+    fields = [
+      Arrow::Field.new(name: "id", type: :int32, nullable: false),
+      Arrow::Field.new("point", Arrow::StructDataType.new(point_fields)),
+    ]
+    schema = Arrow::Schema.new(fields).with_metadata("source" => "test")
+    record_batch = Arrow::RecordBatch.new(schema, [[1, {"x" => 10}]])
+    reader = Arrow::RecordBatchReader.new([record_batch], schema)
+    context = DataFusion::SessionContext.new
+    context.register_record_batch_reader("data", reader)
+    data_frame = context.sql("SELECT * FROM data")
+    # Nested children are released with their parent. Exporting them
+    # many times must not leak or double-free them.
+    10.times do
+      data_frame.schema
+      GC.start
+    end
+    actual = data_frame.schema
+    # DataFusion 21 doesn't keep metadata of top-level fields in
+    # projections. Metadata of nested fields is kept because they are
+    # parts of data types.
+    assert_equal([
+                   {"source" => "test"},
+                   false,
+                   true,
+                   {"unit" => "cm"},
+                 ],
+                 [
+                   actual.metadata,
+                   actual["id"].nullable?,
+                   actual["point"].nullable?,
+                   actual["point"].data_type.fields[0].metadata,
+                 ])
+  end
+
+  def test_select_columns
+    assert_equal(@data_frame.to_table,
+                 @data_frame.select_columns(["number"]).to_table)
+  end
+
+  def test_select
+    assert_equal(Arrow::Table.new(plus: Arrow::Int64Array.new([2, 3, 4])),
+                 @data_frame.select(["number + 1 AS plus"]).to_table)
+  end
+
+  def test_filter
+    assert_equal(Arrow::Table.new(number: Arrow::UInt8Array.new([2, 3])),
+                 @data_frame.filter("number >= 2").to_table)
+  end
+
+  def test_sort
+    assert_equal(Arrow::Table.new(number: Arrow::UInt8Array.new([3, 2, 1])),
+                 @data_frame.sort(["number DESC"]).to_table)
+  end
+
+  def test_limit
+    assert_equal(Arrow::Table.new(number: Arrow::UInt8Array.new([2])),
+                 @data_frame.limit(1, 1).to_table)
+  end
+
+  def test_explain
+    explained = @data_frame.explain(false, false)
+    assert_equal(["logical_plan", "physical_plan"],
+                 explained.to_table.plan_type.data.to_a)
+  end
+
+  def test_explain_analyze
+    explained = @data_frame.filter("number > 1").explain(true, true)
+    table = explained.to_table
+    assert_equal([
+                   "Plan with Metrics",
+                   "Plan with Full Metrics",
+                   "Output Rows",
+                   "Duration",
+                 ],
+                 table.plan_type.data.to_a)
+    plans = table.plan.data.to_a
+    filter = plans[0].lines.find {|line| line.include?("FilterExec:")}
+    assert_equal(["metrics=[output_rows=2", "2"],
+                 [filter[/metrics=\[output_rows=\d+/], plans[2]])
+  end
+
+  def test_format_logical_plan
+    assert_equal(<<-PLAN.chomp, @data_frame.format_logical_plan(:indent))
+Projection: data.number
+  TableScan: data
+    PLAN
+  end
+
+  def test_format_optimized_logical_plan
+    assert_equal(<<-PLAN.chomp,
+TableScan: data projection=[number]
+    PLAN
+                 @data_frame.format_optimized_logical_plan(:indent))
+  end
+
+  def test_format_physical_plan
+    assert_equal(<<-PLAN,
+MemoryExec: partitions=1, partition_sizes=[1]
+    PLAN
+                 @data_frame.format_physical_plan(:indent))
+  end
+
+  def test_format_physical_plan_graphviz
+    assert_equal(<<-PLAN,
+// Begin DataFusion GraphViz Plan (see https://graphviz.org)
+digraph {
+  subgraph cluster_1
+  {
+    graph[label="ExecutionPlan"]
+    2[shape=box label="MemoryExec: partitions=1, partition_sizes=[1]"]
+  }
+}
+// End DataFusion GraphViz Plan
+    PLAN
+                 @data_frame.format_physical_plan(:graphviz))
+  end
+
+  def test_with_column
+    number = DataFusion::Expr.new_column("number")
+    doubled = DataFusion::Expr.new_binary(number, :plus, number)
+    assert_equal(Arrow::Table.new(number: Arrow::UInt8Array.new([1, 2, 3]),
+                                  doubled: Arrow::UInt8Array.new([2, 4, 6])),
+                 @data_frame.with_column("doubled", doubled).to_table)
+  end
+
+  def test_with_column_renamed
+    assert_equal(Arrow::Table.new(renamed: Arrow::UInt8Array.new([1, 2, 3])),
+                 @data_frame.with_column_renamed("number", "renamed").to_table)
+  end
+
+  def test_with_column_renamed_unknown
+    error = assert_raise(GLib::Error) do
+      @data_frame.with_column_renamed("nonexistent", "renamed")
+    end
+    assert_equal(DataFusion::Error::SCHEMA, error.code)
+  end
+
+  def test_drop_columns
+    number = DataFusion::Expr.new_column("number")
+    data_frame = @data_frame.with_column("copied", number)
+    assert_equal(Arrow::Table.new(copied: Arrow::UInt8Array.new([1, 2, 3])),
+                 data_frame.drop_columns(["number"]).to_table)
+  end
+
+  sub_test_case("#aggregate") do
+    def setup
+      context = DataFusion::SessionContext.new
+      table = Arrow::Table.new(group: ["a", "a", "b"],
+                               value: Arrow::Int64Array.new([1, 2, 3]))
+      context.register_table("data", table)
+      @data_frame = context.sql("SELECT * FROM data")
+      @group = DataFusion::Expr.new_column("group")
+      value = DataFusion::Expr.new_column("value")
+      sum = DataFusion::Expr.new_function(nil, "sum", [value])
+      @sum = DataFusion::Expr.new_alias(sum, "sum")
+    end
+
+    def test_group
+      aggregated = @data_frame.aggregate([@group], [@sum])
+      assert_equal(Arrow::Table.new(group: ["a", "b"],
+                                    sum: Arrow::Int64Array.new([3, 3])),
+                   aggregated.sort(["\"group\""]).to_table)
+    end
+
+    def test_no_group
+      aggregated = @data_frame.aggregate([], [@sum])
+      assert_equal(Arrow::Table.new(sum: Arrow::Int64Array.new([6])),
+                   aggregated.to_table)
+    end
+
+    def test_rollup
+      rollup = DataFusion::Expr.new_rollup([@group])
+      aggregated = @data_frame.aggregate([rollup], [@sum])
+      assert_equal(Arrow::Table.new(group: ["a", "b", nil],
+                                    sum: Arrow::Int64Array.new([3, 3, 6])),
+                   aggregated.sort(["\"group\" NULLS LAST"]).to_table)
+    end
+  end
+
+  sub_test_case("#join") do
+    def setup
+      context = DataFusion::SessionContext.new
+      left = Arrow::Table.new(id: Arrow::Int64Array.new([1, 2, 3]),
+                              name: ["a", "b", "c"])
+      context.register_table("left", left)
+      right = Arrow::Table.new(rid: Arrow::Int64Array.new([1, 3, 4]),
+                               score: Arrow::Int64Array.new([10, 30, 40]))
+      context.register_table("right", right)
+      @left = context.sql("SELECT * FROM left")
+      @right = context.sql("SELECT * FROM right")
+    end
+
+    def test_keys
+      joined = @left.join(@right, :inner, ["id"], ["rid"], nil)
+      assert_equal(Arrow::Table.new(id: Arrow::Int64Array.new([1, 3]),
+                                    name: ["a", "c"],
+                                    rid: Arrow::Int64Array.new([1, 3]),
+                                    score: Arrow::Int64Array.new([10, 30])),
+                   joined.sort(["id"]).to_table)
+    end
+
+    def test_left_anti
+      joined = @left.join(@right, :left_anti, ["id"], ["rid"], nil)
+      assert_equal(Arrow::Table.new(id: Arrow::Int64Array.new([2]),
+                                    name: ["b"]),
+                   joined.to_table)
+    end
+
+    def test_on
+      id = DataFusion::Expr.new_column("id")
+      rid = DataFusion::Expr.new_column("rid")
+      greater = DataFusion::Expr.new_binary(id, :gt, rid)
+      joined = @left.join_on(@right, :inner, [greater])
+      assert_equal(Arrow::Table.new(id: Arrow::Int64Array.new([2, 3]),
+                                    name: ["b", "c"],
+                                    rid: Arrow::Int64Array.new([1, 1]),
+                                    score: Arrow::Int64Array.new([10, 10])),
+                   joined.sort(["id"]).to_table)
+    end
+  end
+
+  sub_test_case("set operations") do
+    def setup
+      context = DataFusion::SessionContext.new
+      context.register_table("left",
+                             Arrow::Table.new(number: Arrow::Int64Array.new([1, 1, 2])))
+      context.register_table("right",
+                             Arrow::Table.new(number: Arrow::Int64Array.new([1, 3])))
+      context.register_table("other",
+                             Arrow::Table.new(a: Arrow::Int64Array.new([1]),
+                                              b: Arrow::Int64Array.new([2])))
+      @left = context.sql("SELECT * FROM left")
+      @right = context.sql("SELECT * FROM right")
+      @other = context.sql("SELECT * FROM other")
+    end
+
+    def sorted_numbers(data_frame)
+      data_frame.sort(["number"]).to_table.number.data.to_a
+    end
+
+    def test_union
+      assert_equal([1, 1, 1, 2, 3],
+                   sorted_numbers(@left.union(@right)))
+    end
+
+    def test_union_distinct
+      assert_equal([1, 2, 3],
+                   sorted_numbers(@left.union_distinct(@right)))
+    end
+
+    def test_intersect
+      assert_equal([1, 1],
+                   sorted_numbers(@left.intersect(@right)))
+    end
+
+    def test_except
+      assert_equal([2],
+                   sorted_numbers(@left.except(@right)))
+    end
+
+    def test_distinct
+      assert_equal([1, 2],
+                   sorted_numbers(@left.distinct))
+    end
+
+    def test_incompatible
+      error = assert_raise(GLib::Error) do
+        @left.intersect(@other)
+      end
+      assert_equal(DataFusion::Error::PLAN, error.code)
+    end
+  end
+
   sub_test_case("#write_parquet") do
     def test_no_properties
       path = File.join(@tmp_dir, "parquet")
@@ -31,4 +31,34 @@ class ErrorTest < Test::Unit::TestCase
                  "No field named \"nonexistent\".",
                  @error.message)
   end
+
+  def test_panic
+    runtime = DataFusion::TokioRuntime.new(:current_thread, 0)
+    context = DataFusion::SessionContext.new
+    context.tokio_runtime = runtime
+    signature = DataFusion::Signature.new_any(1, :volatile)
+    int64 = Arrow::Int64DataType.new
+    nested_error = nil
+    context.register_scalar_udf("nested", signature, int64) do |args|
+      # Blocking API can't be used in a thread of the runtime. Tokio
+      # panics for it and the panic is reported as an error.
+      begin
+        context.sql("SELECT 1")
+      rescue GLib::Error => error
+        nested_error = error
+      end
+      Arrow::Int64Array.new([0] * args.n_rows)
+    end
+    context.sql("SELECT nested(1)").to_table
+    runtime.shutdown(1000)
+    assert_equal([
+                   DataFusion::Error::PANIC,
+                   "[session-context][sql] Panic: " +
+                   "Cannot start a runtime from within a runtime.",
+                 ],
+                 [
+                   nested_error.code,
+                   nested_error.message[/\A.+?runtime\./],
+                 ])
+  end
 end
@@ -0,0 +1,95 @@
+# Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+class ExprTest < Test::Unit::TestCase
+  def setup
+    @context = DataFusion::SessionContext.new
+    table = Arrow::Table.new(number: Arrow::Int64Array.new([1, nil, 3]))
+    @context.register_table("data", table)
+    @data_frame = @context.sql("SELECT * FROM data")
+    @number = DataFusion::Expr.new_column("number")
+  end
+
+  def literal(value)
+    DataFusion::Expr.new_literal(Arrow::Int64Array.new([value]), 0)
+  end
+
+  def select(*exprs)
+    @data_frame.select_exprs(exprs).to_table
+  end
+
+  def test_column
+    assert_equal("number", @number.to_s)
+  end
+
+  def test_literal
+    assert_equal("Int64(29)", literal(29).to_s)
+  end
+
+  def test_binary
+    plus = DataFusion::Expr.new_binary(@number, :plus, literal(1))
+    assert_equal(Arrow::Table.new(plus: Arrow::Int64Array.new([2, nil, 4])),
+                 select(DataFusion::Expr.new_alias(plus, "plus")))
+  end
+
+  def test_unary
+    is_null = DataFusion::Expr.new_unary(:is_null, @number)
+    assert_equal(Arrow::Table.new(is_null: [false, true, false]),
+                 select(DataFusion::Expr.new_alias(is_null, "is_null")))
+  end
+
+  def test_function
+    negative = DataFusion::Expr.new_unary(:negative, @number)
+    abs = DataFusion::Expr.new_function(@context, "abs", [negative])
+    # abs() returns Float64 for all numeric types in DataFusion 21.
+    assert_equal(Arrow::Table.new(abs: Arrow::DoubleArray.new([1.0, nil, 3.0])),
+                 select(DataFusion::Expr.new_alias(abs, "abs")))
+  end
+
+  def test_function_unknown
+    assert_raise(GLib::Error) do
+      DataFusion::Expr.new_function(nil, "nonexistent", [])
+    end
+  end
+
+  def test_case
+    is_null = DataFusion::Expr.new_unary(:is_null, @number)
+    case_expr = DataFusion::Expr.new_case(nil, [is_null], [literal(0)], @number)
+    alias_expr = DataFusion::Expr.new_alias(case_expr, "filled")
+    assert_equal(Arrow::Table.new(filled: Arrow::Int64Array.new([1, 0, 3])),
+                 select(alias_expr))
+  end
+
+  def test_cast
+    cast = DataFusion::Expr.new_cast(@number, Arrow::StringDataType.new)
+    assert_equal(Arrow::Table.new(string: Arrow::StringArray.new(["1", nil, "3"])),
+                 select(DataFusion::Expr.new_alias(cast, "string")))
+  end
+
+  def test_in_list
+    in_list = DataFusion::Expr.new_in_list(@number,
+                                           [literal(1), literal(2)],
+                                           false)
+    filtered = @data_frame.filter_expr(in_list)
+    assert_equal(Arrow::Table.new(number: Arrow::Int64Array.new([1])),
+                 filtered.to_table)
+  end
+
+  def test_sort
+    sort = DataFusion::Expr.new_sort(@number, false, true)
+    sorted = @data_frame.sort_exprs([sort])
+    assert_equal(Arrow::Table.new(number: Arrow::Int64Array.new([nil, 3, 1])),
+                 sorted.to_table)
+  end
+end
@@ -48,4 +48,34 @@ class ParquetReadOptionsTest < Test::Unit::TestCase
       not @options.set_pruning?
     end
   end
+
+  def test_schema
+    assert_nil(@options.schema)
+    schema = Arrow::Schema.new("a" => :int64)
+    @options.schema = schema
+    assert_equal(schema, @options.schema)
+  end
+
+  def test_skip_metadata
+    assert do
+      not @options.set_skip_metadata?
+    end
+    assert do
+      @options.skip_metadata?
+    end
+    @options.skip_metadata = false
+    assert do
+      @options.set_skip_metadata?
+    end
+    assert do
+      not @options.skip_metadata?
+    end
+    @options.unset_skip_metadata
+    assert do
+      not @options.set_skip_metadata?
+    end
+    assert do
+      @options.skip_metadata?
+    end
+  end
 end
@@ -0,0 +1,38 @@
+# Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+class PhysicalPlanTest < Test::Unit::TestCase
+  def setup
+    context = DataFusion::SessionContext.new
+    table = Arrow::Table.new(number: Arrow::Int64Array.new([1, 2, 3]))
+    context.register_table("data", table)
+    data_frame = context.sql("SELECT * FROM data WHERE number >= 2")
+    @plan = data_frame.create_physical_plan
+  end
+
+  def test_execute_stream
+    reader = @plan.execute_stream
+    assert_equal(Arrow::Table.new(number: Arrow::Int64Array.new([2, 3])),
+                 reader.read_all)
+  end
+
+  def test_metrics
+    @plan.execute_stream.read_all
+    metrics = @plan.metrics
+    root_output_rows = metrics.to_table.each_record.select do |record|
+      record["parent_id"].nil? and record["name"] == "output_rows"
+    end
+    assert_equal(2, root_output_rows.sum {|record| record["value"]})
+  end
+end
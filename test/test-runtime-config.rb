@@ -0,0 +1,63 @@
+# Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+class RuntimeConfigTest < Test::Unit::TestCase
+  def setup
+    @config = DataFusion::RuntimeConfig.new
+  end
+
+  def sort(runtime_config)
+    context =
+      DataFusion::SessionContext.new_with_runtime_config(nil, runtime_config)
+    yield if block_given?
+    data_frame = context.sql("SELECT column1 AS a " +
+                             "FROM (VALUES (3), (1), (2)) " +
+                             "ORDER BY a")
+    data_frame.to_table
+  end
+
+  def test_default
+    assert_equal(Arrow::Table.new(a: Arrow::Int64Array.new([1, 2, 3])),
+                 sort(@config))
+  end
+
+  def test_greedy_memory_pool
+    @config.set_greedy_memory_pool(16)
+    error = assert_raise(GLib::Error) do
+      sort(@config)
+    end
+    assert_equal(DataFusion::Error::RESOURCES_EXHAUSTED, error.code)
+  end
+
+  def test_temporary_directory
+    Dir.mktmpdir do |dir|
+      @config.set_fair_spill_memory_pool(1024 * 1024)
+      @config.temporary_directory = dir
+      entries = nil
+      table = sort(@config) do
+        # DiskManager creates a working directory in the given
+        # directory while the session context is alive.
+        entries = Dir.children(dir)
+      end
+      assert_equal([
+                     Arrow::Table.new(a: Arrow::Int64Array.new([1, 2, 3])),
+                     ["datafusion-"],
+                   ],
+                   [
+                     table,
+                     entries.collect {|entry| entry[0, 11]},
+                   ])
+    end
+  end
+end
@@ -0,0 +1,122 @@
+# Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+class SessionConfigTest < Test::Unit::TestCase
+  def setup
+    @config = DataFusion::SessionConfig.new
+  end
+
+  def test_target_partitions
+    @config.target_partitions = 3
+    assert_equal(3, @config.target_partitions)
+  end
+
+  def test_batch_size
+    assert_equal(8192, @config.batch_size)
+    @config.batch_size = 100
+    assert_equal(100, @config.batch_size)
+  end
+
+  def test_information_schema
+    assert do
+      not @config.information_schema?
+    end
+    @config.information_schema = true
+    assert do
+      @config.information_schema?
+    end
+  end
+
+  def test_default_catalog_and_schema
+    @config.set_default_catalog_and_schema("my_catalog", "my_schema")
+    assert_equal(["my_catalog", "my_schema"],
+                 [@config.default_catalog, @config.default_schema])
+  end
+
+  def test_repartition_joins
+    assert do
+      @config.repartition_joins?
+    end
+    @config.repartition_joins = false
+    assert_equal([false, "false"],
+                 [
+                   @config.repartition_joins?,
+                   @config.get("datafusion.optimizer.repartition_joins"),
+                 ])
+  end
+
+  def test_repartition_aggregations
+    assert do
+      @config.repartition_aggregations?
+    end
+    @config.repartition_aggregations = false
+    assert_equal([false, "false"],
+                 [
+                   @config.repartition_aggregations?,
+                   @config.get("datafusion.optimizer.repartition_aggregations"),
+                 ])
+  end
+
+  def test_repartition_windows
+    assert do
+      @config.repartition_windows?
+    end
+    @config.repartition_windows = false
+    assert_equal([false, "false"],
+                 [
+                   @config.repartition_windows?,
+                   @config.get("datafusion.optimizer.repartition_windows"),
+                 ])
+  end
+
+  def test_repartition_sorts
+    assert do
+      @config.repartition_sorts?
+    end
+    @config.repartition_sorts = false
+    assert_equal([false, "false"],
+                 [
+                   @config.repartition_sorts?,
+                   @config.get("datafusion.optimizer.repartition_sorts"),
+                 ])
+  end
+
+  def test_repartition_file_scans
+    assert do
+      @config.repartition_file_scans?
+    end
+    @config.repartition_file_scans = false
+    assert_equal([false, "false"],
+                 [
+                   @config.repartition_file_scans?,
+                   @config.get("datafusion.optimizer.repartition_file_scans"),
+                 ])
+  end
+
+  def test_set
+    @config.set("datafusion.execution.batch_size", "100")
+    assert_equal(["100", 100],
+                 [@config.get("datafusion.execution.batch_size"),
+                  @config.batch_size])
+  end
+
+  def test_session_context
+    @config.information_schema = true
+    context = DataFusion::SessionContext.new_with_config(@config)
+    data_frame = context.sql("SELECT COUNT(*) > 0 AS have_tables " +
+                             "FROM information_schema.tables")
+    assert_equal(Arrow::Table.new(have_tables: [true]),
+                 data_frame.to_table)
+  end
+end
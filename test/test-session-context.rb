@@ -23,6 +23,80 @@ class SessionContextTest < Test::Unit::TestCase
                  @context.sql("SELECT 1").to_table)
   end
 
+  sub_test_case("timeout") do
+    def setup
+      super
+      # The timed out query is stopped by shutting down this runtime
+      # even if it doesn't yield.
+      @runtime = DataFusion::TokioRuntime.new(:multi_thread, 2)
+      @context.tokio_runtime = @runtime
+      @context.sql("SET datafusion.execution.target_partitions = 4")
+      values = "(VALUES " + (0...1000).collect {|i| "(#{i})"}.join(", ") + ")"
+      @sql = <<-SQL
+SELECT sum(a.column1 * b.column1 + c.column1)
+  FROM #{values} a, #{values} b, #{values} c
+      SQL
+    end
+
+    def teardown
+      @runtime.shutdown(1000)
+    end
+
+    def test_execute
+      @context.timeout = 100
+      data_frame = @context.sql(@sql)
+      error = assert_raise(GLib::Error) do
+        data_frame.to_table
+      end
+      assert_equal(DataFusion::Error::TIMEOUT, error.code)
+    end
+
+    def test_sql
+      @context.timeout = 100
+      # CREATE TABLE AS executes the query in #sql.
+      error = assert_raise(GLib::Error) do
+        @context.sql("CREATE TABLE result AS #{@sql}")
+      end
+      assert_equal(DataFusion::Error::TIMEOUT, error.code)
+    end
+
+    def test_data_frame
+      data_frame = @context.sql(@sql)
+      data_frame.timeout = 100
+      error = assert_raise(GLib::Error) do
+        data_frame.to_table
+      end
+      assert_equal(DataFusion::Error::TIMEOUT, error.code)
+    end
+  end
+
+  def test_sql_async
+    loop = GLib::MainLoop.new
+    data_frame = nil
+    @context.sql_async("SELECT 1") do |context, result|
+      data_frame = context.sql_finish(result)
+      loop.quit
+    end
+    loop.run
+    schema = Arrow::Schema.new([Arrow::Field.new("Int64(1)", :int64, false)])
+    assert_equal(Arrow::Table.new(schema, [Arrow::Int64Array.new([1])]),
+                 data_frame.to_table)
+  end
+
+  def test_sql_async_error
+    loop = GLib::MainLoop.new
+    error = nil
+    @context.sql_async("SELECT * FROM nonexistent") do |context, result|
+      begin
+        context.sql_finish(result)
+      rescue GLib::Error => error
+      end
+      loop.quit
+    end
+    loop.run
+    assert_equal(DataFusion::Error::PLAN, error.code)
+  end
+
   def test_deregister
     record_batch = Arrow::RecordBatch.new(boolean: [true, false, nil],
                                           integer: [1, nil, 3])
@@ -67,6 +141,286 @@ class SessionContextTest < Test::Unit::TestCase
     assert_equal(table, data_frame.to_table)
   end
 
+  def test_register_record_batch_reader
+    record_batch = Arrow::RecordBatch.new(boolean: [true, false, nil],
+                                          integer: [1, nil, 3])
+    reader = Arrow::RecordBatchReader.new([record_batch], record_batch.schema)
+    assert do
+      @context.register_record_batch_reader("data", reader)
+    end
+    data_frame = @context.sql("SELECT * FROM data")
+    assert_equal(record_batch.to_table, data_frame.to_table)
+  end
+
+  def test_register_record_batch_reader_scan_twice
+    record_batch = Arrow::RecordBatch.new(integer: [1, nil, 3])
+    reader = Arrow::RecordBatchReader.new([record_batch], record_batch.schema)
+    assert do
+      @context.register_record_batch_reader("data", reader)
+    end
+    data_frame = @context.sql("SELECT * FROM data")
+    assert_equal(record_batch.to_table, data_frame.to_table)
+    message = "[data-frame][to-table] Execution error: " +
+              "record batch stream is already consumed"
+    assert_raise(DataFusion::Error::Execution.new(message)) do
+      data_frame.to_table
+    end
+  end
+
+  def test_register_record_batch_reader_metadata
+    record_batch = Arrow::RecordBatch.new(integer: [1, nil, 3])
+    schema = record_batch.schema.with_metadata("key" => "value")
+    reader = Arrow::RecordBatchReader.new([record_batch], schema)
+    assert do
+      @context.register_record_batch_reader("data", reader)
+    end
+    data_frame = @context.sql("SELECT * FROM data")
+    assert_equal({"key" => "value"}, data_frame.schema.metadata)
+    assert_equal(record_batch.to_table, data_frame.to_table)
+  end
+
+  sub_test_case("#register_scalar_udf") do
+    def setup
+      super
+      # Blocks are called in the thread that runs the query with the
+      # current thread runtime.
+      @runtime = DataFusion::TokioRuntime.new(:current_thread, 0)
+      @context.tokio_runtime = @runtime
+      table = Arrow::Table.new(a: Arrow::Int32Array.new([1, 2, 3]),
+                               b: Arrow::Int64Array.new([10, 20, 30]))
+      @context.register_table("data", table)
+      @int64 = Arrow::Int64DataType.new
+    end
+
+    def teardown
+      @runtime.shutdown(1000)
+    end
+
+    def test_exact
+      signature = DataFusion::Signature.new_exact([@int64, @int64], :immutable)
+      received_args = []
+      @context.register_scalar_udf("add", signature, @int64) do |args|
+        received_args << args
+        sums = args.raw_records.collect {|a, b| a + b}
+        Arrow::Int64Array.new(sums)
+      end
+      assert_equal(Arrow::Table.new(sum: Arrow::Int64Array.new([11, 22, 33])),
+                   @context.sql("SELECT add(a, b) AS sum FROM data").to_table)
+      assert_equal([
+                     [
+                       ["int64", "int64"],
+                       [[1, 10], [2, 20], [3, 30]],
+                     ],
+                   ],
+                   received_args.collect {|args|
+                     [
+                       args.schema.fields.collect {|field| field.data_type.to_s},
+                       args.raw_records,
+                     ]
+                   })
+    end
+
+    def test_one_of
+      utf8 = Arrow::StringDataType.new
+      signature = DataFusion::Signature.new_one_of([
+        DataFusion::Signature.new_exact([@int64], :immutable),
+        DataFusion::Signature.new_exact([utf8], :immutable),
+      ], :immutable)
+      @context.register_scalar_udf("type_name", signature, utf8) do |args|
+        type_name = args.schema.fields[0].data_type.to_s
+        Arrow::StringArray.new([type_name] * args.n_rows)
+      end
+      data_frame = @context.sql(<<-SQL)
+SELECT type_name(a) AS a, type_name(CAST(b AS VARCHAR)) AS b FROM data
+      SQL
+      assert_equal(Arrow::Table.new(a: Arrow::StringArray.new(["int64"] * 3),
+                                    b: Arrow::StringArray.new(["string"] * 3)),
+                   data_frame.to_table)
+    end
+
+    def test_volatility
+      signature = DataFusion::Signature.new_exact([@int64], :immutable)
+      @context.register_scalar_udf("immutable_double", signature, @int64) do |args|
+        Arrow::Int64Array.new(args.raw_records.collect {|a,| a * 2})
+      end
+      signature = DataFusion::Signature.new_exact([@int64], :volatile)
+      @context.register_scalar_udf("volatile_double", signature, @int64) do |args|
+        Arrow::Int64Array.new(args.raw_records.collect {|a,| a * 2})
+      end
+      data_frame = @context.sql(<<-SQL)
+SELECT immutable_double(2) AS i, volatile_double(2) AS v
+      SQL
+      # Only immutable functions are evaluated on planning.
+      assert_equal(<<-PLAN.chomp, data_frame.format_optimized_logical_plan(:indent))
+Projection: Int64(4) AS i, volatile_double(Int64(2)) AS v
+  EmptyRelation
+      PLAN
+    end
+
+    def test_error
+      signature = DataFusion::Signature.new_any(1, :immutable)
+      @context.register_scalar_udf("fail", signature, @int64) do |args|
+        raise DataFusion::Error::NotImplemented.new("not implemented yet")
+      end
+      error = assert_raise(GLib::Error) do
+        @context.sql("SELECT fail(a) FROM data").to_table
+      end
+      assert_equal([
+                     DataFusion::Error::NOT_IMPLEMENTED,
+                     "[data-frame][to-table] External error: not implemented yet",
+                   ],
+                   [error.code, error.message])
+    end
+  end
+
+  sub_test_case("#register_aggregate_udf") do
+    class WeightedSum < DataFusion::Accumulator
+      type_register
+
+      class << self
+        attr_accessor :calls
+        attr_accessor :size
+      end
+
+      # Accumulators are created by g_object_new() in C. #initialize
+      # isn't called for them.
+      def sum
+        @sum ||= 0.0
+      end
+
+      def virtual_do_update_batch(values)
+        self.class.calls << [:update_batch, values.raw_records]
+        values.raw_records.each do |value, weight|
+          @sum = sum + value * weight
+        end
+        true
+      end
+
+      def virtual_do_merge_batch(states)
+        self.class.calls << [:merge_batch, states.raw_records]
+        states.raw_records.each do |state_sum,|
+          @sum = sum + state_sum
+        end
+        true
+      end
+
+      def virtual_do_get_state
+        self.class.calls << [:get_state, sum]
+        Arrow::RecordBatch.new(Arrow::Schema.new(sum: :double),
+                               1,
+                               [Arrow::DoubleArray.new([sum])])
+      end
+
+      def virtual_do_evaluate
+        self.class.calls << [:evaluate, sum]
+        if sum < 0
+          raise DataFusion::Error::Execution.new("negative sum")
+        end
+        Arrow::DoubleArray.new([sum])
+      end
+
+      def virtual_do_get_size
+        self.class.size || 0
+      end
+    end
+
+    def setup
+      super
+      # Accumulators are called in the thread that runs the query with
+      # the current thread runtime.
+      @runtime = DataFusion::TokioRuntime.new(:current_thread, 0)
+      @context.tokio_runtime = @runtime
+      @table = Arrow::Table.new(g: Arrow::Int32Array.new([1, 2, 1, 2]),
+                                v: Arrow::DoubleArray.new([1.0, 2.0, 3.0, 4.0]),
+                                w: Arrow::DoubleArray.new([10.0, 20.0, 30.0, 40.0]))
+      @context.register_table("data", @table)
+      @double = Arrow::DoubleDataType.new
+      WeightedSum.calls = []
+      WeightedSum.size = nil
+    end
+
+    def teardown
+      @runtime.shutdown(1000)
+    end
+
+    def register_weighted_sum(context=@context)
+      signature = DataFusion::Signature.new_exact([@double, @double], :immutable)
+      context.register_aggregate_udf("weighted_sum",
+                                     signature,
+                                     @double,
+                                     [@double],
+                                     WeightedSum.gtype)
+    end
+
+    def test_group_by
+      register_weighted_sum
+      data_frame = @context.sql(<<-SQL)
+SELECT g, weighted_sum(v, w) AS s FROM data GROUP BY g ORDER BY g
+      SQL
+      assert_equal(Arrow::Table.new(g: Arrow::Int32Array.new([1, 2]),
+                                    s: Arrow::DoubleArray.new([100.0, 200.0])),
+                   data_frame.to_table)
+      calls = WeightedSum.calls.group_by(&:first).transform_values do |group|
+        group.collect(&:last).sort
+      end
+      # Partial aggregation computes states by update_batch and
+      # get_state. Final aggregation merges them by merge_batch and
+      # evaluate.
+      assert_equal({
+                     update_batch: [
+                       [[1.0, 10.0], [3.0, 30.0]],
+                       [[2.0, 20.0], [4.0, 40.0]],
+                     ],
+                     get_state: [100.0, 200.0],
+                     merge_batch: [[[100.0]], [[200.0]]],
+                     evaluate: [100.0, 200.0],
+                   },
+                   calls)
+    end
+
+    def test_error
+      register_weighted_sum
+      error = assert_raise(GLib::Error) do
+        @context.sql("SELECT weighted_sum(v, -w) FROM data").to_table
+      end
+      assert_equal([
+                     DataFusion::Error::EXECUTION,
+                     "[data-frame][to-table] External error: negative sum",
+                   ],
+                   [error.code, error.message])
+    end
+
+    def test_size
+      runtime_config = DataFusion::RuntimeConfig.new
+      runtime_config.set_greedy_memory_pool(10 * 1024 * 1024)
+      context =
+        DataFusion::SessionContext.new_with_runtime_config(nil, runtime_config)
+      context.tokio_runtime = @runtime
+      context.register_table("data", @table)
+      register_weighted_sum(context)
+      WeightedSum.size = 100 * 1024 * 1024
+      error = assert_raise(GLib::Error) do
+        context.sql(<<-SQL).to_table
+SELECT g, weighted_sum(v, w) FROM data GROUP BY g
+        SQL
+      end
+      assert_equal(DataFusion::Error::RESOURCES_EXHAUSTED, error.code)
+    end
+
+    def test_no_argument
+      signature = DataFusion::Signature.new_any(0, :immutable)
+      message = "[session-context][register-aggregate-udf] " +
+                "aggregate UDF must not accept zero arguments"
+      assert_raise(DataFusion::Error::Plan.new(message)) do
+        @context.register_aggregate_udf("weighted_sum",
+                                        signature,
+                                        @double,
+                                        [@double],
+                                        WeightedSum.gtype)
+      end
+    end
+  end
+
   sub_test_case("#register_csv") do
     def setup
       super
@@ -122,4 +476,273 @@ a,b,c
       assert_equal(table, data_frame.to_table)
     end
   end
+
+  sub_test_case("#read_csv") do
+    def setup
+      super
+      Tempfile.open(["datafusion", ".csv"]) do |csv_file|
+        @csv_file = csv_file
+        @csv_file.puts(<<-CSV)
+a,b
+1,x
+10,y
+        CSV
+        @csv_file.close
+        yield
+      end
+    end
+
+    def test_read_csv
+      schema = Arrow::Schema.new([
+                                   Arrow::Field.new("a", :int64),
+                                   Arrow::Field.new("b", :string),
+                                 ])
+      table = Arrow::Table.new(schema,
+                               [
+                                 Arrow::Int64Array.new([1, 10]),
+                                 Arrow::StringArray.new(["x", "y"]),
+                               ])
+      assert_equal(table, @context.read_csv(@csv_file.path).to_table)
+    end
+
+    def test_read_csv_not_registered
+      config = DataFusion::SessionConfig.new
+      config.information_schema = true
+      context = DataFusion::SessionContext.new_with_config(config)
+      list_tables = lambda do
+        data_frame = context.sql(<<-SQL)
+SELECT table_name FROM information_schema.tables
+  WHERE table_schema <> 'information_schema'
+        SQL
+        data_frame.to_table.table_name.data.to_a
+      end
+      tables_before = list_tables.call
+      context.read_csv(@csv_file.path)
+      tables_after_read = list_tables.call
+      context.register_csv("data", @csv_file.path)
+      assert_equal([[], [], ["data"]],
+                   [tables_before, tables_after_read, list_tables.call])
+    end
+
+    def test_read_csv_gzip
+      schema = Arrow::Schema.new([
+                                   Arrow::Field.new("a", :int64),
+                                   Arrow::Field.new("b", :string),
+                                 ])
+      table = Arrow::Table.new(schema,
+                               [
+                                 Arrow::Int64Array.new([1, 10]),
+                                 Arrow::StringArray.new(["x", "y"]),
+                               ])
+      Tempfile.open(["datafusion", ".csv.gz"]) do |gzip_file|
+        Zlib::GzipWriter.wrap(gzip_file) do |gzip|
+          gzip.write(File.read(@csv_file.path))
+        end
+        options = DataFusion::CSVReadOptions.new
+        options.schema = schema
+        options.file_compression_type = :gzip
+        options.file_extension = ".csv.gz"
+        assert_equal(table,
+                     @context.read_csv(gzip_file.path, options).to_table)
+      end
+    end
+  end
+
+  sub_test_case("#read_parquet") do
+    def setup
+      super
+      Dir.mktmpdir do |dir|
+        @parquet_path = File.join(dir, "data.parquet")
+        @table = Arrow::Table.new("a" => Arrow::Int64Array.new([1, 10]),
+                                  "b" => Arrow::StringArray.new(["x", "y"]))
+        @table.save(@parquet_path)
+        yield
+      end
+    end
+
+    def test_read_parquet
+      assert_equal(@table, @context.read_parquet(@parquet_path).to_table)
+    end
+
+    def test_read_parquet_schema
+      # Inference never reorders columns nor adds missing columns.
+      schema = Arrow::Schema.new([
+                                   Arrow::Field.new("b", :string),
+                                   Arrow::Field.new("a", :int64),
+                                   Arrow::Field.new("c", :int32),
+                                 ])
+      options = DataFusion::ParquetReadOptions.new
+      options.schema = schema
+      table = Arrow::Table.new(schema,
+                               [
+                                 Arrow::StringArray.new(["x", "y"]),
+                                 Arrow::Int64Array.new([1, 10]),
+                                 Arrow::Int32Array.new([nil, nil]),
+                               ])
+      assert_equal(table,
+                   @context.read_parquet(@parquet_path, options).to_table)
+    end
+
+    def test_register_parquet_skip_metadata
+      Dir.mktmpdir do |dir|
+        ["x", "y"].each do |source|
+          schema = Arrow::Schema.new(a: :int64)
+          schema = schema.with_metadata("source" => source)
+          table = Arrow::Table.new(schema, [Arrow::Int64Array.new([1])])
+          table.save(File.join(dir, "#{source}.parquet"))
+        end
+        options = DataFusion::ParquetReadOptions.new
+        options.skip_metadata = false
+        error = assert_raise(GLib::Error) do
+          @context.register_parquet("not_skipped", dir, options)
+        end
+        assert_equal([
+                       DataFusion::Error::ARROW,
+                       "Fail to merge schema due to conflicting metadata. " +
+                       "Key 'source' has different values",
+                     ],
+                     [
+                       error.code,
+                       error.message[/Fail to merge.*different values/],
+                     ])
+        options.skip_metadata = true
+        assert do
+          @context.register_parquet("skipped", dir, options)
+        end
+        data_frame = @context.sql("SELECT * FROM skipped")
+        assert_equal(Arrow::Table.new(a: Arrow::Int64Array.new([1, 1])),
+                     data_frame.to_table)
+      end
+    end
+
+    def test_register_parquet_file_sort_order
+      options = DataFusion::ParquetReadOptions.new
+      a = DataFusion::Expr.new_column("a")
+      assert do
+        options.set_file_sort_order([DataFusion::Expr.new_sort(a, true, false)])
+      end
+      assert do
+        @context.register_parquet("data", @parquet_path, options)
+      end
+      data_frame = @context.sql("SELECT * FROM data ORDER BY a")
+      assert_not_match(/SortExec/,
+                       data_frame.format_physical_plan(:indent))
+    end
+
+    def test_register_parquet_file_sort_order_not_column
+      options = DataFusion::ParquetReadOptions.new
+      a = DataFusion::Expr.new_column("a")
+      doubled = DataFusion::Expr.new_binary(a, :plus, a)
+      message = "[parquet-read-options][set-file-sort-order] " +
+                "Error during planning: " +
+                "sort key must be a column reference: a + a"
+      assert_raise(DataFusion::Error::Plan.new(message)) do
+        options.set_file_sort_order([
+                                      DataFusion::Expr.new_sort(doubled,
+                                                                true,
+                                                                false),
+                                    ])
+      end
+    end
+  end
+
+  sub_test_case("JSON") do
+    def setup
+      super
+      Tempfile.open(["datafusion", ".json"]) do |json_file|
+        @json_file = json_file
+        @json_file.puts(<<-JSON)
+{"a": 1, "b": "x"}
+{"a": 10, "b": "y"}
+        JSON
+        @json_file.close
+        schema = Arrow::Schema.new([
+                                     Arrow::Field.new("a", :int64),
+                                     Arrow::Field.new("b", :string),
+                                   ])
+        @table = Arrow::Table.new(schema,
+                                  [
+                                    Arrow::Int64Array.new([1, 10]),
+                                    Arrow::StringArray.new(["x", "y"]),
+                                  ])
+        yield
+      end
+    end
+
+    def test_register_json
+      assert do
+        @context.register_json("data", @json_file.path)
+      end
+      data_frame = @context.sql("SELECT * FROM data")
+      assert_equal(@table, data_frame.to_table)
+    end
+
+    def test_read_json
+      assert_equal(@table, @context.read_json(@json_file.path).to_table)
+    end
+
+    def test_register_json_gzip
+      Tempfile.open(["datafusion", ".json.gz"]) do |gzip_file|
+        Zlib::GzipWriter.wrap(gzip_file) do |gzip|
+          gzip.write(File.read(@json_file.path))
+        end
+        options = DataFusion::JSONReadOptions.new
+        options.file_compression_type = :gzip
+        options.file_extension = ".json.gz"
+        assert do
+          @context.register_json("data", gzip_file.path, options)
+        end
+        data_frame = @context.sql("SELECT * FROM data")
+        assert_equal(@table, data_frame.to_table)
+      end
+    end
+  end
+
+  sub_test_case("Avro") do
+    include Helper::Avro
+
+    def setup
+      super
+      Tempfile.open(["datafusion", ".avro"]) do |avro_file|
+        @avro_file = avro_file
+        @avro_file.binmode
+        write_avro(@avro_file, [[1, "x"], [10, "y"]])
+        @avro_file.close
+        schema = Arrow::Schema.new([
+                                     Arrow::Field.new("a", :int64),
+                                     Arrow::Field.new("b", :string),
+                                   ])
+        @table = Arrow::Table.new(schema,
+                                  [
+                                    Arrow::Int64Array.new([1, 10]),
+                                    Arrow::StringArray.new(["x", "y"]),
+                                  ])
+        yield
+      end
+    end
+
+    def test_register_avro
+      assert do
+        @context.register_avro("data", @avro_file.path)
+      end
+      data_frame = @context.sql("SELECT * FROM data")
+      assert_equal(@table, data_frame.to_table)
+    end
+
+    def test_read_avro
+      assert_equal(@table, @context.read_avro(@avro_file.path).to_table)
+    end
+
+    def test_read_avro_corrupt
+      Tempfile.open(["datafusion", ".avro"]) do |avro_file|
+        avro_file.write("not avro")
+        avro_file.close
+        message = "[session-context][read-avro] Avro error: " +
+                  "wrong magic in header"
+        assert_raise(DataFusion::Error::Avro.new(message)) do
+          @context.read_avro(avro_file.path)
+        end
+      end
+    end
+  end
 end
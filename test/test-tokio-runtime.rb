@@ -0,0 +1,94 @@
+# Copyright 2023 Sutou Kouhei <kou@clear-code.com>
+#
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+class TokioRuntimeTest < Test::Unit::TestCase
+  def setup
+    @context = DataFusion::SessionContext.new
+    table = Arrow::Table.new(number: Arrow::Int64Array.new([1, 2, 3]))
+    @context.register_table("data", table)
+  end
+
+  def expected_table
+    Arrow::Table.new(number: Arrow::Int64Array.new([1, 2, 3]))
+  end
+
+  def test_default
+    runtime = DataFusion::TokioRuntime.default
+    assert_false(runtime.shutdown?)
+    @context.tokio_runtime = runtime
+    assert_equal(expected_table,
+                 @context.sql("SELECT * FROM data").to_table)
+  end
+
+  def test_multi_thread
+    runtime = DataFusion::TokioRuntime.new(:multi_thread, 2)
+    @context.tokio_runtime = runtime
+    assert_equal(expected_table,
+                 @context.sql("SELECT * FROM data").to_table)
+  ensure
+    runtime.shutdown(1000) if runtime
+  end
+
+  def test_current_thread
+    runtime = DataFusion::TokioRuntime.new(:current_thread, 0)
+    @context.tokio_runtime = runtime
+    assert_equal(expected_table,
+                 @context.sql("SELECT * FROM data").to_table)
+  ensure
+    runtime.shutdown(1000) if runtime
+  end
+
+  def test_n_workers
+    omit("Linux only") unless File.directory?("/proc/self/task")
+    n_threads = Dir.glob("/proc/self/task/*").size
+    runtime = DataFusion::TokioRuntime.new(:multi_thread, 3)
+    assert_equal(n_threads + 3, Dir.glob("/proc/self/task/*").size)
+  ensure
+    runtime.shutdown(1000) if runtime
+  end
+
+  def test_shutdown
+    runtime = DataFusion::TokioRuntime.new(:multi_thread, 1)
+    @context.tokio_runtime = runtime
+    data_frame = @context.sql("SELECT * FROM data")
+    assert_false(runtime.shutdown?)
+    runtime.shutdown(1000)
+    assert_true(runtime.shutdown?)
+    message = "[data-frame][to-table] Execution error: " +
+              "Tokio runtime is already shut down"
+    assert_raise(DataFusion::Error::Execution.new(message)) do
+      data_frame.to_table
+    end
+  end
+
+  def test_shutdown_sql
+    runtime = DataFusion::TokioRuntime.new(:current_thread, 0)
+    @context.tokio_runtime = runtime
+    runtime.shutdown(1000)
+    message = "[session-context][sql] Execution error: " +
+              "Tokio runtime is already shut down"
+    assert_raise(DataFusion::Error::Execution.new(message)) do
+      @context.sql("SELECT * FROM data")
+    end
+  end
+
+  def test_reset
+    runtime = DataFusion::TokioRuntime.new(:current_thread, 0)
+    @context.tokio_runtime = runtime
+    runtime.shutdown(1000)
+    @context.tokio_runtime = nil
+    assert_equal(expected_table,
+                 @context.sql("SELECT * FROM data").to_table)
+  end
+end